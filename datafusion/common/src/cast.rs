@@ -28,11 +28,11 @@ use arrow::{
         Float64Array, GenericBinaryArray, GenericListArray, GenericStringArray,
         Int32Array, Int64Array, IntervalDayTimeArray, IntervalMonthDayNanoArray,
         IntervalYearMonthArray, LargeListArray, ListArray, MapArray, NullArray,
-        OffsetSizeTrait, PrimitiveArray, StringArray, StructArray,
+        OffsetSizeTrait, PrimitiveArray, RunArray, StringArray, StructArray,
         TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
         TimestampSecondArray, UInt32Array, UInt64Array, UInt8Array, UnionArray,
     },
-    datatypes::{ArrowDictionaryKeyType, ArrowPrimitiveType},
+    datatypes::{ArrowDictionaryKeyType, ArrowPrimitiveType, RunEndIndexType},
 };
 use arrow_array::Decimal256Array;
 
@@ -113,6 +113,11 @@ pub fn as_dictionary_array<T: ArrowDictionaryKeyType>(
     Ok(downcast_value!(array, DictionaryArray, T))
 }
 
+// Downcast ArrayRef to RunArray
+pub fn as_run_array<T: RunEndIndexType>(array: &dyn Array) -> Result<&RunArray<T>> {
+    Ok(downcast_value!(array, RunArray, T))
+}
+
 // Downcast ArrayRef to GenericBinaryArray
 pub fn as_generic_binary_array<T: OffsetSizeTrait>(
     array: &dyn Array,
@@ -525,6 +525,27 @@ pub fn list_ndims(data_type: &DataType) -> u64 {
     }
 }
 
+/// Returns true for the list types that `array_union`, `array_intersect` and
+/// `array_except` treat as untyped: `NULL`, and lists of `Null` such as `[]`.
+pub fn is_untyped_list(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Null => true,
+        DataType::List(field) | DataType::LargeList(field) => field.data_type().is_null(),
+        _ => false,
+    }
+}
+
+/// Returns the common type of the arguments of `array_union`, `array_intersect`
+/// and `array_except`: an untyped argument (see [`is_untyped_list`]) takes the type
+/// of the other argument, so the result is only untyped when both arguments are.
+pub fn set_op_common_type(lhs: &DataType, rhs: &DataType) -> DataType {
+    if is_untyped_list(lhs) && !rhs.is_null() {
+        rhs.clone()
+    } else {
+        lhs.clone()
+    }
+}
+
 /// An extension trait for smart pointers. Provides an interface to get a
 /// raw pointer to the data (with metadata stripped away).
 ///
@@ -573,9 +594,13 @@ pub mod datafusion_strsim {
 
     /// Calculates the minimum number of insertions, deletions, and substitutions
     /// required to change one sequence into the other.
+    ///
+    /// Only one row of the distance matrix, over the elements of `b`, is kept in
+    /// `cache`, which is reused rather than reallocated.
     fn generic_levenshtein<'a, 'b, Iter1, Iter2, Elem1, Elem2>(
         a: &'a Iter1,
         b: &'b Iter2,
+        cache: &mut Vec<usize>,
     ) -> usize
     where
         &'a Iter1: IntoIterator<Item = Elem1>,
@@ -588,7 +613,8 @@ pub mod datafusion_strsim {
             return b_len;
         }
 
-        let mut cache: Vec<usize> = (1..b_len + 1).collect();
+        cache.clear();
+        cache.extend(1..b_len + 1);
 
         let mut result = 0;
 
@@ -617,7 +643,30 @@ pub mod datafusion_strsim {
     /// assert_eq!(3, levenshtein("kitten", "sitting"));
     /// ```
     pub fn levenshtein(a: &str, b: &str) -> usize {
-        generic_levenshtein(&StringWrapper(a), &StringWrapper(b))
+        levenshtein_with_buffer(a, b, &mut vec![])
+    }
+
+    /// Same as [`levenshtein`], but keeps the single row of the distance matrix it
+    /// needs in `cache`, so that computing many distances reuses one allocation.
+    ///
+    /// The row spans the shorter of the two strings, so the memory used is
+    /// O(min(n, m)) for strings of n and m characters.
+    ///
+    /// ```
+    /// use datafusion_common::utils::datafusion_strsim::levenshtein_with_buffer;
+    ///
+    /// let mut cache = vec![];
+    /// assert_eq!(3, levenshtein_with_buffer("kitten", "sitting", &mut cache));
+    /// assert_eq!(2, levenshtein_with_buffer("flaw", "lawn", &mut cache));
+    /// ```
+    pub fn levenshtein_with_buffer(a: &str, b: &str, cache: &mut Vec<usize>) -> usize {
+        // the distance is symmetric, so iterate the row over the shorter string
+        let (a, b) = if a.chars().count() < b.chars().count() {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        generic_levenshtein(&StringWrapper(a), &StringWrapper(b), cache)
     }
 }
 
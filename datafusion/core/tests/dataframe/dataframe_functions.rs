@@ -15,9 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow::datatypes::{DataType, Field, Schema};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::{DataType, Field, Fields, Schema};
 use arrow::{
-    array::{Int32Array, StringArray},
+    array::{Int32Array, StringArray, StructArray},
     record_batch::RecordBatch,
 };
 use arrow_array::types::Int32Type;
@@ -444,6 +445,27 @@ async fn test_fn_regexp_like() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[cfg(feature = "unicode_expressions")]
+async fn test_fn_regexp_instr() -> Result<()> {
+    let expr = regexp_instr(col("a"), lit("[A-Z]"));
+
+    let expected = [
+        "+------------------------------------+",
+        "| regexp_instr(test.a,Utf8(\"[A-Z]\")) |",
+        "+------------------------------------+",
+        "| 4                                  |",
+        "| 0                                  |",
+        "| 1                                  |",
+        "| 4                                  |",
+        "+------------------------------------+",
+    ];
+
+    assert_fn_batches!(expr, expected);
+
+    Ok(())
+}
+
 #[tokio::test]
 #[cfg(feature = "unicode_expressions")]
 async fn test_fn_regexp_match() -> Result<()> {
@@ -870,3 +892,62 @@ async fn test_fn_array_to_string() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_fn_array_element_and_slice_struct_list() -> Result<()> {
+    let fields = Fields::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let structs = StructArray::new(
+        fields.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            Arc::new(StringArray::from(vec!["w", "x", "y", "z"])),
+        ],
+        None,
+    );
+    // a field that is not the default nullable "item" field of `make_array`
+    let field = Arc::new(Field::new(
+        "element",
+        DataType::Struct(fields.clone()),
+        false,
+    ));
+    let list = ListArray::new(
+        field.clone(),
+        OffsetBuffer::new(vec![0, 3, 4].into()),
+        Arc::new(structs),
+        None,
+    );
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "s",
+        DataType::List(field.clone()),
+        true,
+    )]));
+    let batch = RecordBatch::try_new(schema, vec![Arc::new(list)])?;
+
+    let ctx = SessionContext::new();
+    ctx.register_batch("structs", batch)?;
+    let df = ctx.table("structs").await?.select(vec![
+        array_element(col("s"), lit(2)).alias("element"),
+        array_slice(col("s"), lit(2), lit(3), lit(1)).alias("slice"),
+    ])?;
+    let batches = df.collect().await?;
+
+    let expected = [
+        "+--------------+------------------------------+",
+        "| element      | slice                        |",
+        "+--------------+------------------------------+",
+        "| {a: 2, b: x} | [{a: 2, b: x}, {a: 3, b: y}] |",
+        "|              | []                           |",
+        "+--------------+------------------------------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+
+    // the element is the struct, and the slice keeps the field of the list
+    let schema = batches[0].schema();
+    assert_eq!(schema.field(0).data_type(), &DataType::Struct(fields));
+    assert_eq!(schema.field(1).data_type(), &DataType::List(field));
+
+    Ok(())
+}
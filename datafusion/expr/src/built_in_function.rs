@@ -22,12 +22,16 @@ use std::fmt;
 use std::str::FromStr;
 use std::sync::{Arc, OnceLock};
 
-use crate::signature::TIMEZONE_WILDCARD;
+use crate::signature::{
+    ArrayFunctionSignature, DECIMAL128_WILDCARD, FIXED_SIZE_BINARY_WILDCARD,
+    TIMEZONE_WILDCARD,
+};
 use crate::type_coercion::functions::data_types;
 use crate::{FuncMonotonicity, Signature, TypeSignature, Volatility};
 
-use arrow::datatypes::{DataType, Field, TimeUnit};
-use datafusion_common::{plan_err, DataFusionError, Result};
+use arrow::datatypes::{DataType, Field, TimeUnit, DECIMAL128_MAX_PRECISION};
+use datafusion_common::utils::set_op_common_type;
+use datafusion_common::{internal_err, plan_err, DataFusionError, Result};
 
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
@@ -48,6 +52,8 @@ pub enum BuiltinScalarFunction {
     Asinh,
     /// atanh
     Atanh,
+    /// bit_count, number of set bits
+    BitCount,
     /// cbrt
     Cbrt,
     /// ceil
@@ -66,22 +72,30 @@ pub enum BuiltinScalarFunction {
     Factorial,
     /// floor
     Floor,
+    /// gamma
+    Gamma,
     /// gcd, Greatest common divisor
     Gcd,
     /// lcm, Least common multiple
     Lcm,
     /// iszero
     Iszero,
+    /// lgamma, Natural logarithm of the absolute value of the gamma function
+    Lgamma,
     /// ln, Natural logarithm
     Ln,
-    /// log, same as log10
+    /// log(x), same as log10, or log(base, x), the logarithm of x in base
     Log,
     /// log10
     Log10,
     /// log2
     Log2,
+    /// mod, remainder of dividing x by y, with the sign of x
+    Mod,
     /// nanvl
     Nanvl,
+    /// coalesce_nan
+    CoalesceNan,
     /// pi
     Pi,
     /// power
@@ -138,6 +152,10 @@ pub enum BuiltinScalarFunction {
     ArrayExcept,
     /// array_resize
     ArrayResize,
+    /// array_flatten_distinct
+    ArrayFlattenDistinct,
+    /// element_at
+    ElementAt,
 
     // string functions
     /// ascii
@@ -260,6 +278,35 @@ impl BuiltinScalarFunction {
         function_to_name().get(self).unwrap()
     }
 
+    /// Returns the preferred name to display for this function when called
+    /// with arguments of `input_types`.
+    ///
+    /// Array functions whose first argument is a list display their `list_*`
+    /// alias (e.g. `list_element` rather than `array_element`) when they have
+    /// one. All other functions display their canonical [`Self::name`].
+    pub fn display_name_for_input(&self, input_types: &[DataType]) -> &'static str {
+        let name = *function_to_name().get(self).unwrap();
+        let is_list_input = matches!(
+            input_types.first(),
+            Some(
+                DataType::List(_)
+                    | DataType::LargeList(_)
+                    | DataType::FixedSizeList(_, _)
+            )
+        );
+        if !is_list_input {
+            return name;
+        }
+        let Some(suffix) = name.strip_prefix("array_") else {
+            return name;
+        };
+        self.aliases()
+            .iter()
+            .find(|alias| alias.strip_prefix("list_") == Some(suffix))
+            .copied()
+            .unwrap_or(name)
+    }
+
     /// Returns the [Volatility] of the builtin function.
     pub fn volatility(&self) -> Volatility {
         match self {
@@ -277,14 +324,18 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::Exp => Volatility::Immutable,
             BuiltinScalarFunction::Factorial => Volatility::Immutable,
             BuiltinScalarFunction::Floor => Volatility::Immutable,
+            BuiltinScalarFunction::Gamma => Volatility::Immutable,
             BuiltinScalarFunction::Gcd => Volatility::Immutable,
             BuiltinScalarFunction::Iszero => Volatility::Immutable,
             BuiltinScalarFunction::Lcm => Volatility::Immutable,
+            BuiltinScalarFunction::Lgamma => Volatility::Immutable,
             BuiltinScalarFunction::Ln => Volatility::Immutable,
             BuiltinScalarFunction::Log => Volatility::Immutable,
             BuiltinScalarFunction::Log10 => Volatility::Immutable,
             BuiltinScalarFunction::Log2 => Volatility::Immutable,
+            BuiltinScalarFunction::Mod => Volatility::Immutable,
             BuiltinScalarFunction::Nanvl => Volatility::Immutable,
+            BuiltinScalarFunction::CoalesceNan => Volatility::Immutable,
             BuiltinScalarFunction::Pi => Volatility::Immutable,
             BuiltinScalarFunction::Power => Volatility::Immutable,
             BuiltinScalarFunction::Round => Volatility::Immutable,
@@ -292,6 +343,7 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::Sin => Volatility::Immutable,
             BuiltinScalarFunction::Sinh => Volatility::Immutable,
             BuiltinScalarFunction::Sqrt => Volatility::Immutable,
+            BuiltinScalarFunction::BitCount => Volatility::Immutable,
             BuiltinScalarFunction::Cbrt => Volatility::Immutable,
             BuiltinScalarFunction::Cot => Volatility::Immutable,
             BuiltinScalarFunction::Trunc => Volatility::Immutable,
@@ -312,6 +364,8 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArrayIntersect => Volatility::Immutable,
             BuiltinScalarFunction::ArrayUnion => Volatility::Immutable,
             BuiltinScalarFunction::ArrayResize => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayFlattenDistinct => Volatility::Immutable,
+            BuiltinScalarFunction::ElementAt => Volatility::Immutable,
             BuiltinScalarFunction::Ascii => Volatility::Immutable,
             BuiltinScalarFunction::BitLength => Volatility::Immutable,
             BuiltinScalarFunction::Btrim => Volatility::Immutable,
@@ -354,6 +408,22 @@ impl BuiltinScalarFunction {
         }
     }
 
+    /// Returns true if this function always returns the same output for the
+    /// same input within a query, that is, if its [Volatility] is
+    /// [`Volatility::Immutable`] or [`Volatility::Stable`].
+    pub fn is_deterministic(&self) -> bool {
+        match self.volatility() {
+            Volatility::Immutable | Volatility::Stable => true,
+            Volatility::Volatile => false,
+        }
+    }
+
+    /// Returns true if `n` arguments is a valid number of arguments for this
+    /// function, regardless of their types. See [`TypeSignature::supports_arg_count`].
+    pub fn supports_arg_count(&self, n: usize) -> bool {
+        self.signature().type_signature.supports_arg_count(n)
+    }
+
     /// Returns the output [`DataType`] of this function
     ///
     /// This method should be invoked only after `input_expr_types` have been validated
@@ -375,6 +445,8 @@ impl BuiltinScalarFunction {
                 List(field)
                 | LargeList(field)
                 | FixedSizeList(field, _) => Ok(field.data_type().clone()),
+                // an element of a NULL array is NULL
+                Null => Ok(Null),
                 _ => plan_err!(
                     "The {self} function can only accept List, LargeList or FixedSizeList as the first argument"
                 ),
@@ -394,47 +466,110 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArrayReverse => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArraySlice => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArrayResize => Ok(input_expr_types[0].clone()),
-            BuiltinScalarFunction::ArrayIntersect => {
-                match (input_expr_types[0].clone(), input_expr_types[1].clone()) {
-                    (DataType::Null, DataType::Null) | (DataType::Null, _) => {
-                        Ok(DataType::Null)
-                    }
-                    (_, DataType::Null) => {
-                        Ok(List(Arc::new(Field::new("item", Null, true))))
+            BuiltinScalarFunction::ArrayFlattenDistinct => match &input_expr_types[0] {
+                List(field) => match field.data_type() {
+                    List(inner) | LargeList(inner) => Ok(List(inner.clone())),
+                    _ => plan_err!(
+                        "The {self} function can only accept a list of lists, but got {:?}",
+                        input_expr_types[0]
+                    ),
+                },
+                LargeList(field) => match field.data_type() {
+                    List(inner) | LargeList(inner) => Ok(LargeList(inner.clone())),
+                    _ => plan_err!(
+                        "The {self} function can only accept a list of lists, but got {:?}",
+                        input_expr_types[0]
+                    ),
+                },
+                _ => plan_err!(
+                    "The {self} function can only accept List or LargeList as the first argument"
+                ),
+            },
+            BuiltinScalarFunction::ElementAt => {
+                match (&input_expr_types[0], &input_expr_types[1]) {
+                    (Null, _) => Ok(Null),
+                    (List(field) | LargeList(field) | FixedSizeList(field, _), index) => {
+                        if !index.is_integer() {
+                            return plan_err!(
+                                "The {self} function requires an integer index for {:?}, but got {index:?}",
+                                input_expr_types[0]
+                            );
+                        }
+                        Ok(field.data_type().clone())
                     }
-                    (dt, _) => Ok(dt),
-                }
-            }
-            BuiltinScalarFunction::ArrayUnion => {
-                match (input_expr_types[0].clone(), input_expr_types[1].clone()) {
-                    (DataType::Null, dt) => Ok(dt),
-                    (dt, DataType::Null) => Ok(dt),
-                    (dt, _) => Ok(dt),
-                }
-            }
-            BuiltinScalarFunction::ArrayExcept => {
-                match (input_expr_types[0].clone(), input_expr_types[1].clone()) {
-                    (DataType::Null, _) | (_, DataType::Null) => {
-                        Ok(input_expr_types[0].clone())
+                    (Map(entries, _), key) => match entries.data_type() {
+                        Struct(fields) if fields.len() == 2 => {
+                            let map_key = fields[0].data_type();
+                            if !is_map_key_compatible(key, map_key) {
+                                return plan_err!(
+                                    "The {self} function requires a key of type {map_key:?} for {:?}, but got {key:?}",
+                                    input_expr_types[0]
+                                );
+                            }
+                            Ok(fields[1].data_type().clone())
+                        }
+                        other => internal_err!("Unexpected map entries type {other:?}"),
+                    },
+                    (Struct(fields), name) => {
+                        if !matches!(name, Utf8 | LargeUtf8 | Null) {
+                            return plan_err!(
+                                "The {self} function requires a string field name for {:?}, but got {name:?}",
+                                input_expr_types[0]
+                            );
+                        }
+                        // the field named by a constant is looked up when the expression is
+                        // planned, any other name requires all fields to have the same type
+                        match fields.first() {
+                            Some(first)
+                                if fields
+                                    .iter()
+                                    .all(|f| f.data_type() == first.data_type()) =>
+                            {
+                                Ok(first.data_type().clone())
+                            }
+                            _ => plan_err!(
+                                "The {self} function requires a constant field name for {:?}, whose fields have different types",
+                                input_expr_types[0]
+                            ),
+                        }
                     }
-                    (dt, _) => Ok(dt),
+                    (other, _) => plan_err!(
+                        "The {self} function can only accept List, LargeList, FixedSizeList, Map or Struct as the first argument, but got {other:?}"
+                    ),
                 }
             }
+            BuiltinScalarFunction::ArrayIntersect
+            | BuiltinScalarFunction::ArrayUnion
+            | BuiltinScalarFunction::ArrayExcept => Ok(set_op_common_type(
+                &input_expr_types[0],
+                &input_expr_types[1],
+            )),
             BuiltinScalarFunction::Ascii => Ok(Int32),
-            BuiltinScalarFunction::BitLength => {
-                utf8_to_int_type(&input_expr_types[0], "bit_length")
-            }
+            BuiltinScalarFunction::BitLength => match &input_expr_types[0] {
+                FixedSizeBinary(_) => Ok(Int32),
+                data_type => utf8_to_int_type(data_type, "bit_length"),
+            },
             BuiltinScalarFunction::Btrim => {
                 utf8_to_str_type(&input_expr_types[0], "btrim")
             }
             BuiltinScalarFunction::CharacterLength => {
                 utf8_to_int_type(&input_expr_types[0], "character_length")
             }
-            BuiltinScalarFunction::Chr => Ok(Utf8),
+            // with an encoding, the character is returned as the bytes encoding it
+            BuiltinScalarFunction::Chr => match input_expr_types.len() {
+                1 => Ok(Utf8),
+                _ => Ok(Binary),
+            },
             BuiltinScalarFunction::Coalesce => {
                 // COALESCE has multiple args and they might get coerced, get a preview of this
-                let coerced_types = data_types(input_expr_types, &self.signature());
-                coerced_types.map(|types| types[0].clone())
+                match data_types(input_expr_types, &self.signature()) {
+                    Ok(types) => Ok(types[0].clone()),
+                    Err(_) if !input_expr_types.is_empty() => plan_err!(
+                        "coalesce arguments must share a common type; got [{}]",
+                        TypeSignature::join_types(input_expr_types, ", ")
+                    ),
+                    Err(e) => Err(e),
+                }
             }
             BuiltinScalarFunction::Concat => Ok(Utf8),
             BuiltinScalarFunction::ConcatWithSeparator => Ok(Utf8),
@@ -442,16 +577,19 @@ impl BuiltinScalarFunction {
                 utf8_to_str_type(&input_expr_types[0], "initcap")
             }
             BuiltinScalarFunction::Left => utf8_to_str_type(&input_expr_types[0], "left"),
-            BuiltinScalarFunction::Lower => {
-                utf8_to_str_type(&input_expr_types[0], "lower")
-            }
+            BuiltinScalarFunction::Lower => match &input_expr_types[0] {
+                // run-end encoded inputs keep their runs
+                data_type @ RunEndEncoded(_, _) => Ok(data_type.clone()),
+                data_type => utf8_to_str_type(data_type, "lower"),
+            },
             BuiltinScalarFunction::Lpad => utf8_to_str_type(&input_expr_types[0], "lpad"),
             BuiltinScalarFunction::Ltrim => {
                 utf8_to_str_type(&input_expr_types[0], "ltrim")
             }
-            BuiltinScalarFunction::OctetLength => {
-                utf8_to_int_type(&input_expr_types[0], "octet_length")
-            }
+            BuiltinScalarFunction::OctetLength => match &input_expr_types[0] {
+                FixedSizeBinary(_) => Ok(Int32),
+                data_type => utf8_to_int_type(data_type, "octet_length"),
+            },
             BuiltinScalarFunction::Pi => Ok(Float64),
             BuiltinScalarFunction::Random => Ok(Float64),
             BuiltinScalarFunction::Uuid => Ok(Utf8),
@@ -495,19 +633,40 @@ impl BuiltinScalarFunction {
                 utf8_to_int_type(&input_expr_types[0], "find_in_set")
             }
             BuiltinScalarFunction::ToChar => Ok(Utf8),
-            BuiltinScalarFunction::MakeDate => Ok(Date32),
+            BuiltinScalarFunction::MakeDate => match input_expr_types {
+                [Struct(fields)] => {
+                    for name in ["year", "month", "day"] {
+                        if fields.find(name).is_none() {
+                            return plan_err!(
+                                "make_date struct argument is missing the '{name}' field"
+                            );
+                        }
+                    }
+                    Ok(Date32)
+                }
+                [other] => plan_err!(
+                    "make_date expects a struct with year, month and day fields, got {other}"
+                ),
+                _ => Ok(Date32),
+            },
             BuiltinScalarFunction::Translate => {
                 utf8_to_str_type(&input_expr_types[0], "translate")
             }
             BuiltinScalarFunction::Trim => utf8_to_str_type(&input_expr_types[0], "trim"),
-            BuiltinScalarFunction::Upper => {
-                utf8_to_str_type(&input_expr_types[0], "upper")
-            }
+            BuiltinScalarFunction::Upper => match &input_expr_types[0] {
+                // run-end encoded inputs keep their runs
+                data_type @ RunEndEncoded(_, _) => Ok(data_type.clone()),
+                data_type => utf8_to_str_type(data_type, "upper"),
+            },
 
             BuiltinScalarFunction::Factorial
             | BuiltinScalarFunction::Gcd
             | BuiltinScalarFunction::Lcm => Ok(Int64),
 
+            BuiltinScalarFunction::Gamma | BuiltinScalarFunction::Lgamma => Ok(Float64),
+
+            BuiltinScalarFunction::BitCount => Ok(Int32),
+
             BuiltinScalarFunction::Power => match &input_expr_types[0] {
                 Int64 => Ok(Int64),
                 _ => Ok(Float64),
@@ -525,13 +684,41 @@ impl BuiltinScalarFunction {
                 _ => Ok(Float64),
             },
 
-            BuiltinScalarFunction::Nanvl => match &input_expr_types[0] {
+            BuiltinScalarFunction::Mod => {
+                // the wider of the two argument types
+                let coerced_types = data_types(input_expr_types, &self.signature());
+                coerced_types.map(|types| types[0].clone())
+            }
+
+            BuiltinScalarFunction::Nanvl => {
+                // Float32 only if both arguments are Float32, otherwise Float64
+                let coerced_types = data_types(input_expr_types, &self.signature());
+                coerced_types.map(|types| types[0].clone())
+            }
+
+            BuiltinScalarFunction::CoalesceNan => {
+                // Float32 only if all the arguments are Float32, otherwise Float64
+                let coerced_types = data_types(input_expr_types, &self.signature());
+                coerced_types.map(|types| types[0].clone())
+            }
+
+            BuiltinScalarFunction::Iszero => Ok(Boolean),
+
+            // decimals keep their scale, as the number of decimal places to round
+            // to is only known when the function is evaluated, and gain a digit
+            // of precision for values such as 999.5 that round up to 1000.0
+            BuiltinScalarFunction::Ceil
+            | BuiltinScalarFunction::Floor
+            | BuiltinScalarFunction::Round
+            | BuiltinScalarFunction::Trunc => match &input_expr_types[0] {
                 Float32 => Ok(Float32),
+                Decimal128(precision, scale) => Ok(Decimal128(
+                    (*precision + 1).min(DECIMAL128_MAX_PRECISION),
+                    *scale,
+                )),
                 _ => Ok(Float64),
             },
 
-            BuiltinScalarFunction::Iszero => Ok(Boolean),
-
             BuiltinScalarFunction::OverLay => {
                 utf8_to_str_type(&input_expr_types[0], "overlay")
             }
@@ -544,23 +731,19 @@ impl BuiltinScalarFunction {
             | BuiltinScalarFunction::Acosh
             | BuiltinScalarFunction::Asinh
             | BuiltinScalarFunction::Atanh
-            | BuiltinScalarFunction::Ceil
             | BuiltinScalarFunction::Cos
             | BuiltinScalarFunction::Cosh
             | BuiltinScalarFunction::Degrees
             | BuiltinScalarFunction::Exp
-            | BuiltinScalarFunction::Floor
             | BuiltinScalarFunction::Ln
             | BuiltinScalarFunction::Log10
             | BuiltinScalarFunction::Log2
             | BuiltinScalarFunction::Radians
-            | BuiltinScalarFunction::Round
             | BuiltinScalarFunction::Signum
             | BuiltinScalarFunction::Sin
             | BuiltinScalarFunction::Sinh
             | BuiltinScalarFunction::Sqrt
             | BuiltinScalarFunction::Cbrt
-            | BuiltinScalarFunction::Trunc
             | BuiltinScalarFunction::Cot => match input_expr_types[0] {
                 Float32 => Ok(Float32),
                 _ => Ok(Float64),
@@ -611,24 +794,61 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArrayResize => {
                 Signature::variadic_any(self.volatility())
             }
+            BuiltinScalarFunction::ArrayFlattenDistinct => {
+                Signature::array(self.volatility())
+            }
+            BuiltinScalarFunction::ElementAt => Signature::one_of(
+                vec![
+                    TypeSignature::ArraySignature(ArrayFunctionSignature::ArrayAndIndex),
+                    TypeSignature::Any(2),
+                ],
+                self.volatility(),
+            ),
 
-            BuiltinScalarFunction::Concat
-            | BuiltinScalarFunction::ConcatWithSeparator => {
+            BuiltinScalarFunction::Concat => Signature::one_of(
+                vec![Variadic(vec![run_end_encoded(Utf8)]), Variadic(vec![Utf8])],
+                self.volatility(),
+            ),
+            BuiltinScalarFunction::ConcatWithSeparator => {
                 Signature::variadic(vec![Utf8], self.volatility())
             }
             BuiltinScalarFunction::Coalesce => {
                 Signature::variadic_equal(self.volatility())
             }
-            BuiltinScalarFunction::Ascii
-            | BuiltinScalarFunction::BitLength
-            | BuiltinScalarFunction::CharacterLength
+            BuiltinScalarFunction::BitLength | BuiltinScalarFunction::OctetLength => {
+                Signature::uniform(
+                    1,
+                    vec![FixedSizeBinary(FIXED_SIZE_BINARY_WILDCARD), Utf8, LargeUtf8],
+                    self.volatility(),
+                )
+            }
+            BuiltinScalarFunction::Ascii => Signature::one_of(
+                vec![
+                    Exact(vec![Utf8]),
+                    Exact(vec![LargeUtf8]),
+                    Exact(vec![Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, Utf8]),
+                    Exact(vec![Binary, Utf8]),
+                ],
+                self.volatility(),
+            ),
+            BuiltinScalarFunction::CharacterLength
             | BuiltinScalarFunction::InitCap
-            | BuiltinScalarFunction::Lower
-            | BuiltinScalarFunction::OctetLength
-            | BuiltinScalarFunction::Reverse
-            | BuiltinScalarFunction::Upper => {
+            | BuiltinScalarFunction::Reverse => {
                 Signature::uniform(1, vec![Utf8, LargeUtf8], self.volatility())
             }
+            BuiltinScalarFunction::Lower | BuiltinScalarFunction::Upper => {
+                Signature::uniform(
+                    1,
+                    vec![
+                        run_end_encoded(Utf8),
+                        run_end_encoded(LargeUtf8),
+                        Utf8,
+                        LargeUtf8,
+                    ],
+                    self.volatility(),
+                )
+            }
             BuiltinScalarFunction::Btrim
             | BuiltinScalarFunction::Ltrim
             | BuiltinScalarFunction::Rtrim
@@ -636,9 +856,14 @@ impl BuiltinScalarFunction {
                 vec![Exact(vec![Utf8]), Exact(vec![Utf8, Utf8])],
                 self.volatility(),
             ),
-            BuiltinScalarFunction::Chr | BuiltinScalarFunction::ToHex => {
-                Signature::uniform(1, vec![Int64], self.volatility())
-            }
+            BuiltinScalarFunction::Chr => Signature::one_of(
+                vec![Exact(vec![Int64]), Exact(vec![Int64, Utf8])],
+                self.volatility(),
+            ),
+            BuiltinScalarFunction::ToHex => Signature::one_of(
+                vec![Exact(vec![Int64]), Exact(vec![Int64, Utf8])],
+                self.volatility(),
+            ),
             BuiltinScalarFunction::Lpad | BuiltinScalarFunction::Rpad => {
                 Signature::one_of(
                     vec![
@@ -690,6 +915,7 @@ impl BuiltinScalarFunction {
                     Exact(vec![Duration(Millisecond), Utf8]),
                     Exact(vec![Duration(Microsecond), Utf8]),
                     Exact(vec![Duration(Nanosecond), Utf8]),
+                    Exact(vec![Int64, Utf8]),
                 ],
                 self.volatility(),
             ),
@@ -699,6 +925,10 @@ impl BuiltinScalarFunction {
                     Exact(vec![LargeUtf8, Utf8, Int64]),
                     Exact(vec![Utf8, LargeUtf8, Int64]),
                     Exact(vec![LargeUtf8, LargeUtf8, Int64]),
+                    Exact(vec![Utf8, Utf8, Int64, Int64]),
+                    Exact(vec![LargeUtf8, Utf8, Int64, Int64]),
+                    Exact(vec![Utf8, LargeUtf8, Int64, Int64]),
+                    Exact(vec![LargeUtf8, LargeUtf8, Int64, Int64]),
                 ],
                 self.volatility(),
             ),
@@ -741,16 +971,25 @@ impl BuiltinScalarFunction {
                 Signature::one_of(vec![Exact(vec![Utf8, Utf8, Utf8])], self.volatility())
             }
             BuiltinScalarFunction::Pi => Signature::exact(vec![], self.volatility()),
-            BuiltinScalarFunction::Random => Signature::exact(vec![], self.volatility()),
+            // an optional seed makes the generated values reproducible
+            BuiltinScalarFunction::Random => Signature::one_of(
+                vec![Exact(vec![]), Exact(vec![Int64])],
+                self.volatility(),
+            ),
             BuiltinScalarFunction::Uuid => Signature::exact(vec![], self.volatility()),
             BuiltinScalarFunction::Power => Signature::one_of(
                 vec![Exact(vec![Int64, Int64]), Exact(vec![Float64, Float64])],
                 self.volatility(),
             ),
+            // decimals are listed first so that they are not coerced to floats
             BuiltinScalarFunction::Round => Signature::one_of(
                 vec![
+                    Exact(vec![Decimal128(DECIMAL128_WILDCARD, 0), Int64]),
+                    Exact(vec![Decimal128(DECIMAL128_WILDCARD, 0)]),
                     Exact(vec![Float64, Int64]),
                     Exact(vec![Float32, Int64]),
+                    Exact(vec![Float64, Int32]),
+                    Exact(vec![Float32, Int32]),
                     Exact(vec![Float64]),
                     Exact(vec![Float32]),
                 ],
@@ -758,8 +997,12 @@ impl BuiltinScalarFunction {
             ),
             BuiltinScalarFunction::Trunc => Signature::one_of(
                 vec![
+                    Exact(vec![Decimal128(DECIMAL128_WILDCARD, 0), Int64]),
+                    Exact(vec![Decimal128(DECIMAL128_WILDCARD, 0)]),
                     Exact(vec![Float32, Int64]),
                     Exact(vec![Float64, Int64]),
+                    Exact(vec![Float32, Int32]),
+                    Exact(vec![Float64, Int32]),
                     Exact(vec![Float64]),
                     Exact(vec![Float32]),
                 ],
@@ -769,6 +1012,8 @@ impl BuiltinScalarFunction {
                 vec![Exact(vec![Float32, Float32]), Exact(vec![Float64, Float64])],
                 self.volatility(),
             ),
+            // `log(x)` or `log(base, x)`: with two arguments the base comes first,
+            // as in PostgreSQL, so `log(2, 8)` is 3
             BuiltinScalarFunction::Log => Signature::one_of(
                 vec![
                     Exact(vec![Float32]),
@@ -778,16 +1023,44 @@ impl BuiltinScalarFunction {
                 ],
                 self.volatility(),
             ),
+            // Both arguments are coerced to the narrowest type that holds either of
+            // them. Integers without a common integer type, like (Int64, UInt64), and
+            // floats other than two Float32 arguments are coerced to Float64
+            BuiltinScalarFunction::Mod => Signature::one_of(
+                vec![
+                    Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64, Float64,
+                    Float32,
+                ]
+                .into_iter()
+                .map(|t| Exact(vec![t.clone(), t]))
+                .collect(),
+                self.volatility(),
+            ),
+            // Two Float32 arguments match exactly, anything else, including integer
+            // arguments, is coerced to Float64
             BuiltinScalarFunction::Nanvl => Signature::one_of(
-                vec![Exact(vec![Float32, Float32]), Exact(vec![Float64, Float64])],
+                vec![Exact(vec![Float64, Float64]), Exact(vec![Float32, Float32])],
                 self.volatility(),
             ),
+            // Float32 arguments match exactly, anything else is coerced to Float64
+            BuiltinScalarFunction::CoalesceNan => {
+                Signature::variadic(vec![Float64, Float32], self.volatility())
+            }
+            // narrower integer arguments, such as Int32 columns, are coerced to Int64
             BuiltinScalarFunction::Factorial => {
                 Signature::uniform(1, vec![Int64], self.volatility())
             }
             BuiltinScalarFunction::Gcd | BuiltinScalarFunction::Lcm => {
                 Signature::uniform(2, vec![Int64], self.volatility())
             }
+            BuiltinScalarFunction::Gamma | BuiltinScalarFunction::Lgamma => {
+                Signature::uniform(1, vec![Float64], self.volatility())
+            }
+            BuiltinScalarFunction::BitCount => Signature::uniform(
+                1,
+                vec![Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64],
+                self.volatility(),
+            ),
             BuiltinScalarFunction::OverLay => Signature::one_of(
                 vec![
                     Exact(vec![Utf8, Utf8, Int64, Int64]),
@@ -806,12 +1079,10 @@ impl BuiltinScalarFunction {
             | BuiltinScalarFunction::Asinh
             | BuiltinScalarFunction::Atanh
             | BuiltinScalarFunction::Cbrt
-            | BuiltinScalarFunction::Ceil
             | BuiltinScalarFunction::Cos
             | BuiltinScalarFunction::Cosh
             | BuiltinScalarFunction::Degrees
             | BuiltinScalarFunction::Exp
-            | BuiltinScalarFunction::Floor
             | BuiltinScalarFunction::Ln
             | BuiltinScalarFunction::Log10
             | BuiltinScalarFunction::Log2
@@ -828,9 +1099,21 @@ impl BuiltinScalarFunction {
                 // will be as good as the number of digits in the number
                 Signature::uniform(1, vec![Float64, Float32], self.volatility())
             }
-            BuiltinScalarFunction::MakeDate => Signature::uniform(
-                3,
-                vec![Int32, Int64, UInt32, UInt64, Utf8],
+            BuiltinScalarFunction::Ceil | BuiltinScalarFunction::Floor => {
+                Signature::one_of(
+                    vec![
+                        Exact(vec![Decimal128(DECIMAL128_WILDCARD, 0)]),
+                        Uniform(1, vec![Float64, Float32]),
+                    ],
+                    self.volatility(),
+                )
+            }
+            BuiltinScalarFunction::MakeDate => Signature::one_of(
+                vec![
+                    Uniform(3, vec![Int32, Int64, UInt32, UInt64, Utf8]),
+                    // a struct of year, month and day fields, checked by `return_type`
+                    Any(1),
+                ],
                 self.volatility(),
             ),
             BuiltinScalarFunction::Iszero => Signature::one_of(
@@ -871,10 +1154,33 @@ impl BuiltinScalarFunction {
         } else if *self == BuiltinScalarFunction::Log {
             Some(vec![Some(true), Some(false)])
         } else {
+            // Periodic functions such as `sin`, `cos` and `cot` are not listed
+            // above on purpose: `cot` decreases within each period `(k*pi,
+            // (k+1)*pi)` but jumps from -inf to +inf at every multiple of pi,
+            // so it is not monotonic over its whole domain.
             None
         }
     }
 
+    /// Returns the built-in function that undoes this one, if any, such that
+    /// `f.inverse()(f(x)) = x` for every `x` in the domain of `f`. This can be
+    /// used to move a function to the other side of a comparison when
+    /// rewriting predicates.
+    ///
+    /// Returns `None` when no built-in function is a safe inverse. For example
+    /// `sin` is not invertible over its whole domain (and `asin` is provided by
+    /// `datafusion-functions` rather than as a built-in), and `sqrt` has no
+    /// built-in `square`.
+    pub fn inverse(&self) -> Option<BuiltinScalarFunction> {
+        match self {
+            BuiltinScalarFunction::Exp => Some(BuiltinScalarFunction::Ln),
+            BuiltinScalarFunction::Ln => Some(BuiltinScalarFunction::Exp),
+            BuiltinScalarFunction::Degrees => Some(BuiltinScalarFunction::Radians),
+            BuiltinScalarFunction::Radians => Some(BuiltinScalarFunction::Degrees),
+            _ => None,
+        }
+    }
+
     /// Returns all names that can be used to call this function
     pub fn aliases(&self) -> &'static [&'static str] {
         match self {
@@ -883,6 +1189,7 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::Atan => &["atan"],
             BuiltinScalarFunction::Atanh => &["atanh"],
             BuiltinScalarFunction::Atan2 => &["atan2"],
+            BuiltinScalarFunction::BitCount => &["bit_count"],
             BuiltinScalarFunction::Cbrt => &["cbrt"],
             BuiltinScalarFunction::Ceil => &["ceil"],
             BuiltinScalarFunction::Cos => &["cos"],
@@ -892,14 +1199,18 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::Exp => &["exp"],
             BuiltinScalarFunction::Factorial => &["factorial"],
             BuiltinScalarFunction::Floor => &["floor"],
+            BuiltinScalarFunction::Gamma => &["gamma"],
             BuiltinScalarFunction::Gcd => &["gcd"],
             BuiltinScalarFunction::Iszero => &["iszero"],
             BuiltinScalarFunction::Lcm => &["lcm"],
+            BuiltinScalarFunction::Lgamma => &["lgamma"],
             BuiltinScalarFunction::Ln => &["ln"],
             BuiltinScalarFunction::Log => &["log"],
             BuiltinScalarFunction::Log10 => &["log10"],
             BuiltinScalarFunction::Log2 => &["log2"],
+            BuiltinScalarFunction::Mod => &["mod"],
             BuiltinScalarFunction::Nanvl => &["nanvl"],
+            BuiltinScalarFunction::CoalesceNan => &["coalesce_nan"],
             BuiltinScalarFunction::Pi => &["pi"],
             BuiltinScalarFunction::Power => &["power", "pow"],
             BuiltinScalarFunction::Radians => &["radians"],
@@ -919,7 +1230,7 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::BitLength => &["bit_length"],
             BuiltinScalarFunction::Btrim => &["btrim"],
             BuiltinScalarFunction::CharacterLength => {
-                &["character_length", "char_length", "length"]
+                &["character_length", "char_length", "length", "len"]
             }
             BuiltinScalarFunction::Concat => &["concat"],
             BuiltinScalarFunction::ConcatWithSeparator => &["concat_ws"],
@@ -991,6 +1302,10 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArraySlice => &["array_slice", "list_slice"],
             BuiltinScalarFunction::ArrayUnion => &["array_union", "list_union"],
             BuiltinScalarFunction::ArrayResize => &["array_resize", "list_resize"],
+            BuiltinScalarFunction::ArrayFlattenDistinct => {
+                &["array_flatten_distinct", "list_flatten_distinct"]
+            }
+            BuiltinScalarFunction::ElementAt => &["element_at"],
             BuiltinScalarFunction::ArrayIntersect => {
                 &["array_intersect", "list_intersect"]
             }
@@ -999,6 +1314,16 @@ impl BuiltinScalarFunction {
     }
 }
 
+/// Returns true if a value of type `key` can be used to look up entries of a map
+/// whose keys are of type `map_key`
+fn is_map_key_compatible(key: &DataType, map_key: &DataType) -> bool {
+    use DataType::*;
+    key == map_key
+        || matches!(key, Null)
+        || (key.is_numeric() && map_key.is_numeric())
+        || (matches!(key, Utf8 | LargeUtf8) && matches!(map_key, Utf8 | LargeUtf8))
+}
+
 impl fmt::Display for BuiltinScalarFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.name())
@@ -1056,6 +1381,15 @@ macro_rules! get_optimal_return_type {
     };
 }
 
+/// Returns a run-end encoded type with values of type `values`. In a [`Signature`] it
+/// accepts run-end encoded arrays of `values` with any run end type.
+fn run_end_encoded(values: DataType) -> DataType {
+    DataType::RunEndEncoded(
+        Arc::new(Field::new("run_ends", DataType::Int32, false)),
+        Arc::new(Field::new("values", values, true)),
+    )
+}
+
 // `utf8_to_str_type`: returns either a Utf8 or LargeUtf8 based on the input type size.
 get_optimal_return_type!(utf8_to_str_type, DataType::LargeUtf8, DataType::Utf8);
 
@@ -1080,6 +1414,166 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_display_name_for_input() {
+        let list = DataType::new_list(DataType::Int64, true);
+        let large_list =
+            DataType::LargeList(Arc::new(Field::new("item", DataType::Int64, true)));
+        let element = BuiltinScalarFunction::ArrayElement;
+        assert_eq!(
+            element.display_name_for_input(&[list.clone(), DataType::Int64]),
+            "list_element"
+        );
+        assert_eq!(
+            element.display_name_for_input(&[large_list, DataType::Int64]),
+            "list_element"
+        );
+        // without list input, the canonical name is displayed
+        assert_eq!(element.display_name_for_input(&[]), "array_element");
+        assert_eq!(element.to_string(), "array_element");
+        // functions without a `list_*` alias keep their name
+        assert_eq!(
+            BuiltinScalarFunction::ElementAt
+                .display_name_for_input(&[list, DataType::Int64]),
+            "element_at"
+        );
+        assert_eq!(
+            BuiltinScalarFunction::Sqrt.display_name_for_input(&[DataType::Float64]),
+            "sqrt"
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        for (fun, inverse) in [
+            (BuiltinScalarFunction::Exp, BuiltinScalarFunction::Ln),
+            (
+                BuiltinScalarFunction::Degrees,
+                BuiltinScalarFunction::Radians,
+            ),
+        ] {
+            assert_eq!(fun.inverse(), Some(inverse));
+            assert_eq!(inverse.inverse(), Some(fun));
+        }
+
+        for fun in [
+            BuiltinScalarFunction::Sin,
+            BuiltinScalarFunction::Sqrt,
+            BuiltinScalarFunction::Cbrt,
+            BuiltinScalarFunction::Round,
+            BuiltinScalarFunction::Random,
+        ] {
+            assert_eq!(fun.inverse(), None, "{fun}");
+        }
+
+        // every inverse is itself invertible back to the original function
+        for fun in BuiltinScalarFunction::iter() {
+            if let Some(inverse) = fun.inverse() {
+                assert_eq!(inverse.inverse(), Some(fun), "{fun}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_deterministic() {
+        for fun in [BuiltinScalarFunction::Random, BuiltinScalarFunction::Uuid] {
+            assert!(!fun.is_deterministic(), "{fun}");
+        }
+        for fun in [
+            BuiltinScalarFunction::Sin,
+            BuiltinScalarFunction::Concat,
+            BuiltinScalarFunction::ToChar,
+        ] {
+            assert!(fun.is_deterministic(), "{fun}");
+        }
+
+        for fun in BuiltinScalarFunction::iter() {
+            assert_eq!(
+                fun.is_deterministic(),
+                fun.volatility() != Volatility::Volatile,
+                "{fun}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_supports_arg_count() {
+        let cases = [
+            (BuiltinScalarFunction::Substr, vec![2, 3]),
+            (BuiltinScalarFunction::Pi, vec![0]),
+            (BuiltinScalarFunction::Concat, (1..=10).collect()),
+            (BuiltinScalarFunction::Atan2, vec![2]),
+        ];
+        for (fun, supported) in cases {
+            for n in 0..=10 {
+                assert_eq!(
+                    fun.supports_arg_count(n),
+                    supported.contains(&n),
+                    "{fun} with {n} arguments"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rounding_functions_keep_decimal_type() {
+        for fun in [
+            BuiltinScalarFunction::Ceil,
+            BuiltinScalarFunction::Floor,
+            BuiltinScalarFunction::Round,
+            BuiltinScalarFunction::Trunc,
+        ] {
+            for (decimal, rounded) in [
+                (DataType::Decimal128(10, 3), DataType::Decimal128(11, 3)),
+                (DataType::Decimal128(5, -2), DataType::Decimal128(6, -2)),
+                (DataType::Decimal128(38, 2), DataType::Decimal128(38, 2)),
+            ] {
+                let arg_types = data_types(&[decimal.clone()], &fun.signature()).unwrap();
+                assert_eq!(arg_types, vec![decimal], "{fun}");
+                assert_eq!(fun.return_type(&arg_types).unwrap(), rounded, "{fun}");
+            }
+            assert_eq!(
+                fun.return_type(&[DataType::Float32]).unwrap(),
+                DataType::Float32
+            );
+            assert_eq!(
+                fun.return_type(&[DataType::Int64]).unwrap(),
+                DataType::Float64
+            );
+        }
+
+        for fun in [BuiltinScalarFunction::Round, BuiltinScalarFunction::Trunc] {
+            let arg_types = data_types(
+                &[DataType::Decimal128(10, 3), DataType::Int32],
+                &fun.signature(),
+            )
+            .unwrap();
+            assert_eq!(
+                arg_types,
+                vec![DataType::Decimal128(10, 3), DataType::Int64],
+                "{fun}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_nanvl_coerces_to_float64() {
+        use DataType::{Float32, Float64, Int32, Int64};
+
+        let nanvl = BuiltinScalarFunction::Nanvl;
+        for (arg_types, expected) in [
+            (vec![Int32, Int32], Float64),
+            (vec![Int64, Float32], Float64),
+            (vec![Float32, Float64], Float64),
+            (vec![Float64, Float64], Float64),
+            (vec![Float32, Float32], Float32),
+        ] {
+            let coerced = data_types(&arg_types, &nanvl.signature()).unwrap();
+            assert_eq!(coerced, vec![expected.clone(), expected.clone()]);
+            assert_eq!(nanvl.return_type(&arg_types).unwrap(), expected);
+        }
+    }
+
     #[test]
     fn test_coalesce_return_types() {
         let coalesce = BuiltinScalarFunction::Coalesce;
@@ -1088,4 +1582,16 @@ mod tests {
             .unwrap();
         assert_eq!(return_type, DataType::Date32);
     }
+
+    #[test]
+    fn test_coalesce_return_type_without_common_type() {
+        let coalesce = BuiltinScalarFunction::Coalesce;
+        let err = coalesce
+            .return_type(&[DataType::Int64, DataType::Binary])
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: coalesce arguments must share a common type; got [Int64, Binary]"
+        );
+    }
 }
@@ -37,6 +37,9 @@ pub struct ExecutionProps {
     pub alias_generator: Arc<AliasGenerator>,
     /// Providers for scalar variables
     pub var_providers: Option<HashMap<VarType, Arc<dyn VarProvider + Send + Sync>>>,
+    /// If true, `array_element` and `array_slice` return an error for an
+    /// out of bounds index instead of NULL (or an empty array)
+    pub array_out_of_bounds_error: bool,
 }
 
 impl Default for ExecutionProps {
@@ -54,6 +57,7 @@ impl ExecutionProps {
             query_execution_start_time: Utc.timestamp_nanos(0),
             alias_generator: Arc::new(AliasGenerator::new()),
             var_providers: None,
+            array_out_of_bounds_error: false,
         }
     }
 
@@ -66,6 +70,15 @@ impl ExecutionProps {
         self
     }
 
+    /// Set whether out of bounds array indexes are an error rather than NULL
+    pub fn with_array_out_of_bounds_error(
+        mut self,
+        array_out_of_bounds_error: bool,
+    ) -> Self {
+        self.array_out_of_bounds_error = array_out_of_bounds_error;
+        self
+    }
+
     /// Marks the execution of query started timestamp.
     /// This also instantiates a new alias generator.
     pub fn start_execution(&mut self) -> &Self {
@@ -107,6 +120,6 @@ mod test {
     #[test]
     fn debug() {
         let props = ExecutionProps::new();
-        assert_eq!("ExecutionProps { query_execution_start_time: 1970-01-01T00:00:00Z, alias_generator: AliasGenerator { next_id: 1 }, var_providers: None }", format!("{props:?}"));
+        assert_eq!("ExecutionProps { query_execution_start_time: 1970-01-01T00:00:00Z, alias_generator: AliasGenerator { next_id: 1 }, var_providers: None, array_out_of_bounds_error: false }", format!("{props:?}"));
     }
 }
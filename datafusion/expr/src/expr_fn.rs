@@ -546,6 +546,14 @@ scalar_expr!(Asinh, asinh, num, "inverse hyperbolic sine");
 scalar_expr!(Acosh, acosh, num, "inverse hyperbolic cosine");
 scalar_expr!(Atanh, atanh, num, "inverse hyperbolic tangent");
 scalar_expr!(Factorial, factorial, num, "factorial");
+scalar_expr!(Gamma, gamma, num, "gamma function");
+scalar_expr!(BitCount, bit_count, num, "number of set bits in an integer");
+scalar_expr!(
+    Lgamma,
+    lgamma,
+    num,
+    "natural logarithm of the absolute value of the gamma function"
+);
 scalar_expr!(
     Floor,
     floor,
@@ -686,6 +694,20 @@ scalar_expr!(
     "Returns an array of the elements in the intersection of array1 and array2."
 );
 
+scalar_expr!(
+    ArrayFlattenDistinct,
+    array_flatten_distinct,
+    array,
+    "flattens one level of nesting of an array of arrays and removes duplicate elements."
+);
+
+scalar_expr!(
+    ElementAt,
+    element_at,
+    container key_or_index,
+    "extracts the element with the 1-indexed index from a list, the value for the key from a map, or the named field from a struct."
+);
+
 // string functions
 scalar_expr!(Ascii, ascii, chr, "ASCII code value of the character");
 scalar_expr!(
@@ -784,6 +806,12 @@ scalar_expr!(
 );
 scalar_expr!(MakeDate, make_date, year month day, "make a date from year, month and day component parts");
 scalar_expr!(Nanvl, nanvl, x y, "returns x if x is not NaN otherwise returns y");
+scalar_expr!(Mod, modulo, x y, "remainder of dividing x by y, with the sign of x");
+nary_scalar_expr!(
+    CoalesceNan,
+    coalesce_nan,
+    "returns the value of the first argument which is neither NULL nor NaN"
+);
 scalar_expr!(
     Iszero,
     iszero,
@@ -1187,6 +1215,9 @@ mod test {
         test_unary_scalar_expr!(Acosh, acosh);
         test_unary_scalar_expr!(Atanh, atanh);
         test_unary_scalar_expr!(Factorial, factorial);
+        test_unary_scalar_expr!(Gamma, gamma);
+        test_unary_scalar_expr!(BitCount, bit_count);
+        test_unary_scalar_expr!(Lgamma, lgamma);
         test_unary_scalar_expr!(Floor, floor);
         test_unary_scalar_expr!(Ceil, ceil);
         test_unary_scalar_expr!(Degrees, degrees);
@@ -1202,6 +1233,8 @@ mod test {
         test_unary_scalar_expr!(Ln, ln);
         test_scalar_expr!(Atan2, atan2, y, x);
         test_scalar_expr!(Nanvl, nanvl, x, y);
+        test_scalar_expr!(Mod, modulo, x, y);
+        test_nary_scalar_expr!(CoalesceNan, coalesce_nan, x, y, z);
         test_scalar_expr!(Iszero, iszero, input);
 
         test_scalar_expr!(Ascii, ascii, input);
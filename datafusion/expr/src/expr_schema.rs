@@ -24,7 +24,7 @@ use crate::expr::{
 use crate::field_util::GetFieldAccessSchema;
 use crate::type_coercion::binary::get_result_type;
 use crate::type_coercion::functions::data_types;
-use crate::{utils, LogicalPlan, Projection, Subquery};
+use crate::{utils, BuiltinScalarFunction, LogicalPlan, Projection, Subquery};
 use arrow::compute::can_cast_types;
 use arrow::datatypes::{DataType, Field};
 use datafusion_common::{
@@ -122,17 +122,39 @@ impl ExprSchemable for Expr {
                     .collect::<Result<Vec<_>>>()?;
                 match func_def {
                     ScalarFunctionDefinition::BuiltIn(fun) => {
-                        // verify that function is invoked with correct number and type of arguments as defined in `TypeSignature`
-                        data_types(&arg_data_types, &fun.signature()).map_err(|_| {
-                            plan_datafusion_err!(
-                                "{}",
-                                utils::generate_signature_error_msg(
-                                    &format!("{fun}"),
-                                    fun.signature(),
-                                    &arg_data_types,
-                                )
-                            )
-                        })?;
+                        // verify that function is invoked with correct number and type of arguments as defined in `TypeSignature`,
+                        // except for coalesce, whose return type reports arguments without a common type more clearly
+                        if *fun != BuiltinScalarFunction::Coalesce
+                            || arg_data_types.is_empty()
+                        {
+                            data_types(&arg_data_types, &fun.signature()).map_err(
+                                |_| {
+                                    plan_datafusion_err!(
+                                        "{}",
+                                        utils::generate_signature_error_msg(
+                                            &format!("{fun}"),
+                                            fun.signature(),
+                                            &arg_data_types,
+                                        )
+                                    )
+                                },
+                            )?;
+                        }
+
+                        // a struct field named by a constant has the type of that field
+                        if let (
+                            BuiltinScalarFunction::ElementAt,
+                            Some(struct_type @ DataType::Struct(_)),
+                            Some(Expr::Literal(name)),
+                        ) = (fun, arg_data_types.first(), args.get(1))
+                        {
+                            let access = GetFieldAccessSchema::NamedStructField {
+                                name: name.clone(),
+                            };
+                            return access
+                                .get_accessed_field(struct_type)
+                                .map(|field| field.data_type().clone());
+                        }
 
                         // perform additional function arguments validation (due to limited
                         // expressiveness of `TypeSignature`), then infer return type
@@ -399,6 +421,24 @@ impl ExprSchemable for Expr {
         // like all of the binary expressions below. Perhaps Expr should track the
         // type of the expression?
 
+        // arrow only casts a FixedSizeList to a list with the same child type, so
+        // e.g. `FixedSizeList(Int32, 3)` -> `List(Int64)` goes via `List(Int32)`
+        if let (
+            DataType::FixedSizeList(from_field, _),
+            DataType::List(to_field) | DataType::LargeList(to_field),
+        ) = (&this_type, cast_to_type)
+        {
+            if from_field.data_type() != to_field.data_type() {
+                let intermediate_type = match cast_to_type {
+                    DataType::List(_) => DataType::List(from_field.clone()),
+                    _ => DataType::LargeList(from_field.clone()),
+                };
+                return self
+                    .cast_to(&intermediate_type, schema)?
+                    .cast_to(cast_to_type, schema);
+            }
+        }
+
         if can_cast_types(&this_type, cast_to_type) {
             match self {
                 Expr::ScalarSubquery(subquery) => {
@@ -578,6 +618,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_fixed_size_list_to_list() -> Result<()> {
+        let item = |data_type| Arc::new(Field::new("item", data_type, true));
+        let schema = MockExprSchema::new()
+            .with_data_type(DataType::FixedSizeList(item(DataType::Int32), 3));
+
+        // same child type: a single cast
+        let expr = col("foo").cast_to(&DataType::List(item(DataType::Int32)), &schema)?;
+        assert_eq!(
+            expr,
+            Expr::Cast(Cast::new(
+                Box::new(col("foo")),
+                DataType::List(item(DataType::Int32))
+            ))
+        );
+
+        // different child type: goes through a list with the original child type
+        for to_type in [
+            DataType::List(item(DataType::Int64)),
+            DataType::LargeList(item(DataType::Int64)),
+        ] {
+            let intermediate_type = match &to_type {
+                DataType::List(_) => DataType::List(item(DataType::Int32)),
+                _ => DataType::LargeList(item(DataType::Int32)),
+            };
+            let expr = col("foo").cast_to(&to_type, &schema)?;
+            let expected = Expr::Cast(Cast::new(
+                Box::new(Expr::Cast(Cast::new(
+                    Box::new(col("foo")),
+                    intermediate_type,
+                ))),
+                to_type,
+            ));
+            assert_eq!(expr, expected);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_expr_metadata() {
         let mut meta = HashMap::new();
@@ -79,6 +79,8 @@ impl GetFieldAccessSchema {
                 match (data_type, key_dt) {
                     (DataType::List(lt), DataType::Int64) => Ok(Field::new("list", lt.data_type().clone(), true)),
                     (DataType::LargeList(lt), DataType::Int64) => Ok(Field::new("large_list", lt.data_type().clone(), true)),
+                    // an element of a NULL list is NULL
+                    (DataType::Null, DataType::Int64) => Ok(Field::new("list", DataType::Null, true)),
                     (DataType::List(_), _) | (DataType::LargeList(_), _) => plan_err!(
                         "Only ints are valid as an indexed field in a List/LargeList"
                     ),
@@ -35,6 +35,16 @@ pub const TIMEZONE_WILDCARD: &str = "+TZ";
 /// valid length. It exists to avoid the need to enumerate all possible fixed size list lengths.
 pub const FIXED_SIZE_LIST_WILDCARD: i32 = i32::MIN;
 
+/// Constant that is used as a placeholder for any valid fixed size binary.
+/// This is used where a function can accept a fixed size binary type with any
+/// valid width. It exists to avoid the need to enumerate all possible widths.
+pub const FIXED_SIZE_BINARY_WILDCARD: i32 = i32::MIN;
+
+/// Constant that is used as a placeholder for the precision of any valid decimal.
+/// `Decimal128(DECIMAL128_WILDCARD, _)` accepts a `Decimal128` with any
+/// precision and scale as is, instead of coercing it to a fixed precision and scale.
+pub const DECIMAL128_WILDCARD: u8 = 0;
+
 ///A function's volatility, which defines the functions eligibility for certain optimizations
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 pub enum Volatility {
@@ -227,6 +237,32 @@ impl TypeSignature {
             _ => false,
         }
     }
+
+    /// Check whether `n` input arguments is a valid number of arguments for
+    /// given `TypeSignature`, without checking their types
+    pub fn supports_arg_count(&self, n: usize) -> bool {
+        match self {
+            TypeSignature::Variadic(_)
+            | TypeSignature::VariadicEqual
+            | TypeSignature::VariadicAny => n >= 1,
+            TypeSignature::Uniform(arg_count, _) | TypeSignature::Any(arg_count) => {
+                n == *arg_count
+            }
+            TypeSignature::Exact(types) => n == types.len(),
+            TypeSignature::OneOf(types) => {
+                types.iter().any(|type_sig| type_sig.supports_arg_count(n))
+            }
+            TypeSignature::ArraySignature(array_signature) => match array_signature {
+                ArrayFunctionSignature::ArrayAndElement
+                | ArrayFunctionSignature::ElementAndArray
+                | ArrayFunctionSignature::ArrayAndIndex => n == 2,
+                ArrayFunctionSignature::ArrayAndElementAndOptionalIndex => {
+                    n == 2 || n == 3
+                }
+                ArrayFunctionSignature::Array => n == 1,
+            },
+        }
+    }
 }
 
 /// Defines the supported argument types ([`TypeSignature`]) and [`Volatility`] for a function.
@@ -401,4 +437,41 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn supports_arg_count_tests() {
+        let cases = vec![
+            (TypeSignature::Exact(vec![]), vec![0]),
+            (TypeSignature::Uniform(2, vec![DataType::Float64]), vec![2]),
+            (TypeSignature::Any(1), vec![1]),
+            (
+                TypeSignature::Variadic(vec![DataType::Utf8]),
+                (1..=10).collect(),
+            ),
+            (TypeSignature::VariadicAny, (1..=10).collect()),
+            (
+                TypeSignature::OneOf(vec![
+                    TypeSignature::Exact(vec![DataType::Int8]),
+                    TypeSignature::Any(3),
+                ]),
+                vec![1, 3],
+            ),
+            (
+                TypeSignature::ArraySignature(
+                    ArrayFunctionSignature::ArrayAndElementAndOptionalIndex,
+                ),
+                vec![2, 3],
+            ),
+        ];
+
+        for (case, supported) in cases {
+            for n in 0..=10 {
+                assert_eq!(
+                    case.supports_arg_count(n),
+                    supported.contains(&n),
+                    "{case:?} with {n} arguments"
+                );
+            }
+        }
+    }
 }
@@ -18,12 +18,16 @@
 use std::sync::Arc;
 
 use crate::signature::{
-    ArrayFunctionSignature, FIXED_SIZE_LIST_WILDCARD, TIMEZONE_WILDCARD,
+    ArrayFunctionSignature, DECIMAL128_WILDCARD, FIXED_SIZE_BINARY_WILDCARD,
+    FIXED_SIZE_LIST_WILDCARD, TIMEZONE_WILDCARD,
 };
 use crate::{Signature, TypeSignature};
 use arrow::{
     compute::can_cast_types,
-    datatypes::{DataType, TimeUnit},
+    datatypes::{
+        DataType, Field, FieldRef, Fields, TimeUnit, DECIMAL128_MAX_PRECISION,
+        DECIMAL256_MAX_PRECISION,
+    },
 };
 use datafusion_common::utils::{coerced_fixed_size_list_to_list, list_ndims};
 use datafusion_common::{internal_datafusion_err, internal_err, plan_err, Result};
@@ -126,9 +130,16 @@ fn get_valid_types(
             (&current_types[1], &current_types[0])
         };
 
-        // We follow Postgres on `array_append(Null, T)`, which is not valid.
+        // An untyped `NULL` list is a null list of the element type, so that
+        // `array_append(NULL, T)` is a single element list like for a typed null list
         if array_type.eq(&DataType::Null) {
-            return Ok(vec![vec![]]);
+            let array_type =
+                DataType::List(Arc::new(Field::new("item", elem_type.clone(), true)));
+            return if is_append {
+                Ok(vec![vec![array_type, elem_type.clone()]])
+            } else {
+                Ok(vec![vec![elem_type.clone(), array_type]])
+            };
         }
 
         // We need to find the coerced base type, mainly for cases like:
@@ -191,7 +202,7 @@ fn get_valid_types(
                     // coercible for the arguments. `comparison_coercion` returns more loose
                     // types that can be coerced to both `acc` and `x` for comparison purpose.
                     // See `maybe_data_types` for the actual coercion.
-                    let coerced_type = comparison_coercion(&acc, x);
+                    let coerced_type = variadic_equal_coercion(&acc, x);
                     if let Some(coerced_type) = coerced_type {
                         Ok(coerced_type)
                     } else {
@@ -222,6 +233,15 @@ fn get_valid_types(
                 if current_types.len() != 2 {
                     return Ok(vec![vec![]]);
                 }
+                // a NULL array is an untyped null list, so that the result is NULL
+                if current_types[0].is_null() {
+                    let array_type = DataType::List(Arc::new(Field::new(
+                        "item",
+                        DataType::Null,
+                        true,
+                    )));
+                    return Ok(vec![vec![array_type, DataType::Int64]]);
+                }
                 array(&current_types[0]).map_or_else(
                     || vec![vec![]],
                     |array_type| vec![vec![array_type, DataType::Int64]],
@@ -292,6 +312,87 @@ fn maybe_data_types(
     Some(new_type)
 }
 
+/// Coerces `lhs_type` and `rhs_type` to a common type for [`TypeSignature::VariadicEqual`].
+///
+/// Nested types are coerced field by field, so that `List(Int64)` and `List(Float64)`
+/// are coerced to `List(Float64)`. Decimals are coerced with [`wider_decimal_coercion`].
+/// Other types use [`comparison_coercion`].
+fn variadic_equal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataType> {
+    use self::DataType::*;
+
+    match (lhs_type, rhs_type) {
+        _ if lhs_type == rhs_type => Some(lhs_type.clone()),
+        (Null, other) | (other, Null) => Some(other.clone()),
+        (Decimal128(_, _) | Decimal256(_, _), Decimal128(_, _) | Decimal256(_, _)) => {
+            wider_decimal_coercion(lhs_type, rhs_type)
+        }
+        (
+            List(l) | LargeList(l) | FixedSizeList(l, _),
+            List(r) | LargeList(r) | FixedSizeList(r, _),
+        ) => {
+            let field = coerce_fields(l, r)?;
+            match (lhs_type, rhs_type) {
+                (FixedSizeList(_, l_size), FixedSizeList(_, r_size))
+                    if l_size == r_size =>
+                {
+                    Some(FixedSizeList(field, *l_size))
+                }
+                (LargeList(_), _) | (_, LargeList(_)) => Some(LargeList(field)),
+                _ => Some(List(field)),
+            }
+        }
+        (Struct(l), Struct(r)) if l.len() == r.len() => l
+            .iter()
+            .zip(r.iter())
+            .map(|(l, r)| coerce_fields(l, r))
+            .collect::<Option<Fields>>()
+            .map(Struct),
+        _ => comparison_coercion(lhs_type, rhs_type),
+    }
+}
+
+/// Returns a decimal type that can store any value of both `lhs_type` and
+/// `rhs_type` without losing integer digits or scale:
+/// `(max(s1, s2) + max(p1 - s1, p2 - s2), max(s1, s2))`.
+///
+/// Unlike the comparison coercion, the result is promoted to `Decimal256`
+/// instead of being clamped when `Decimal128` does not have enough precision.
+fn wider_decimal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataType> {
+    use self::DataType::*;
+
+    let (
+        Decimal128(p1, s1) | Decimal256(p1, s1),
+        Decimal128(p2, s2) | Decimal256(p2, s2),
+    ) = (lhs_type, rhs_type)
+    else {
+        return None;
+    };
+    let scale = *s1.max(s2);
+    let range = (*p1 as i16 - *s1 as i16).max(*p2 as i16 - *s2 as i16);
+    let precision = u8::try_from(range + scale as i16).ok()?;
+
+    match (lhs_type, rhs_type) {
+        (Decimal128(_, _), Decimal128(_, _)) if precision <= DECIMAL128_MAX_PRECISION => {
+            Some(Decimal128(precision, scale))
+        }
+        _ if precision <= DECIMAL256_MAX_PRECISION => Some(Decimal256(precision, scale)),
+        _ => None,
+    }
+}
+
+/// Coerces two fields of the same name to a field of their common type
+fn coerce_fields(lhs: &FieldRef, rhs: &FieldRef) -> Option<FieldRef> {
+    if lhs.name() != rhs.name() {
+        return None;
+    }
+    let data_type = variadic_equal_coercion(lhs.data_type(), rhs.data_type())?;
+    Some(Arc::new(Field::new(
+        lhs.name(),
+        data_type,
+        lhs.is_nullable() || rhs.is_nullable(),
+    )))
+}
+
 /// Return true if a value of type `type_from` can be coerced
 /// (losslessly converted) into a value of `type_to`
 ///
@@ -379,12 +480,32 @@ fn coerced_from<'a>(
         {
             Some(type_into.clone())
         }
+        // any decimal is accepted as is by the wildcard decimal
+        Decimal128(DECIMAL128_WILDCARD, _) => match type_from {
+            Decimal128(_, _) => Some(type_from.clone()),
+            _ => None,
+        },
+        // Decimals can be coerced into a decimal that is at least as wide
+        Decimal128(_, _) | Decimal256(_, _)
+            if type_from.is_null()
+                || wider_decimal_coercion(type_into, type_from).as_ref()
+                    == Some(type_into) =>
+        {
+            Some(type_into.clone())
+        }
         Interval(_) if matches!(type_from, Utf8 | LargeUtf8) => Some(type_into.clone()),
         // Any type can be coerced into strings
         Utf8 | LargeUtf8 => Some(type_into.clone()),
         Null if can_cast_types(type_from, type_into) => Some(type_into.clone()),
 
         List(_) if matches!(type_from, FixedSizeList(_, _)) => Some(type_into.clone()),
+        Map(_, _) if type_from.is_null() => Some(type_into.clone()),
+        Struct(_)
+            if matches!(type_from, Null | Struct(_))
+                && can_cast_types(type_from, type_into) =>
+        {
+            Some(type_into.clone())
+        }
 
         // Only accept list and largelist with the same number of dimensions unless the type is Null.
         // List or LargeList with different dimensions should be handled in TypeSignature or other places before this
@@ -410,6 +531,27 @@ fn coerced_from<'a>(
             _ => None,
         },
 
+        // should be able to coerce any fixed size binary to the wildcard fixed size binary
+        FixedSizeBinary(FIXED_SIZE_BINARY_WILDCARD)
+            if matches!(type_from, FixedSizeBinary(_)) =>
+        {
+            Some(type_from.clone())
+        }
+
+        // a run-end encoded array is accepted as is when its values have the expected
+        // type, whatever its run end type. Arrays of the value type itself are also
+        // accepted, so that functions can mix run-end encoded and plain arguments
+        RunEndEncoded(_, values_into) => match type_from {
+            RunEndEncoded(_, values_from)
+                if values_from.data_type() == values_into.data_type() =>
+            {
+                Some(type_from.clone())
+            }
+            Null => Some(values_into.data_type().clone()),
+            _ if type_from == values_into.data_type() => Some(type_from.clone()),
+            _ => None,
+        },
+
         Timestamp(unit, Some(tz)) if tz.as_ref() == TIMEZONE_WILDCARD => {
             match type_from {
                 Timestamp(_, Some(from_tz)) => {
@@ -507,6 +649,112 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_variadic_equal_nested_coercion() -> Result<()> {
+        let list =
+            |data_type| DataType::List(Arc::new(Field::new("item", data_type, true)));
+        let large_list = |data_type| {
+            DataType::LargeList(Arc::new(Field::new("item", data_type, true)))
+        };
+        let signature = Signature::variadic_equal(Volatility::Immutable);
+
+        // lists are coerced to a common element type
+        let current_types = vec![list(DataType::Int64), list(DataType::Float64)];
+        assert_eq!(
+            data_types(&current_types, &signature)?,
+            vec![list(DataType::Float64); 2]
+        );
+
+        let current_types = vec![
+            DataType::Null,
+            list(DataType::Int32),
+            large_list(DataType::Int64),
+        ];
+        assert_eq!(
+            data_types(&current_types, &signature)?,
+            vec![large_list(DataType::Int64); 3]
+        );
+
+        // structs are coerced field by field
+        let current_types = vec![
+            DataType::Struct(vec![Field::new("a", DataType::Int32, false)].into()),
+            DataType::Struct(vec![Field::new("a", DataType::Float64, true)].into()),
+        ];
+        assert_eq!(
+            data_types(&current_types, &signature)?,
+            vec![
+                DataType::Struct(vec![Field::new("a", DataType::Float64, true)].into());
+                2
+            ]
+        );
+
+        // structs with different field names can't be coerced
+        let current_types = vec![
+            DataType::Struct(vec![Field::new("a", DataType::Int32, true)].into()),
+            DataType::Struct(vec![Field::new("b", DataType::Int32, true)].into()),
+        ];
+        assert!(data_types(&current_types, &signature).is_err());
+
+        // an untyped NULL is coerced to a map
+        let entries = DataType::Struct(
+            vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]
+            .into(),
+        );
+        let map = DataType::Map(Arc::new(Field::new("entries", entries, false)), false);
+        let current_types = vec![DataType::Null, map.clone()];
+        assert_eq!(data_types(&current_types, &signature)?, vec![map; 2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_variadic_equal_decimal_coercion() -> Result<()> {
+        let signature = Signature::variadic_equal(Volatility::Immutable);
+        let cases = [
+            (
+                vec![DataType::Decimal128(10, 2), DataType::Decimal128(12, 4)],
+                DataType::Decimal128(12, 4),
+            ),
+            (
+                vec![DataType::Decimal128(12, 2), DataType::Decimal128(6, 4)],
+                DataType::Decimal128(14, 4),
+            ),
+            (
+                vec![
+                    DataType::Null,
+                    DataType::Decimal128(5, 0),
+                    DataType::Decimal128(5, 5),
+                ],
+                DataType::Decimal128(10, 5),
+            ),
+            // integer digits are not lost when Decimal128 runs out of precision
+            (
+                vec![DataType::Decimal128(38, 0), DataType::Decimal128(38, 10)],
+                DataType::Decimal256(48, 10),
+            ),
+            (
+                vec![DataType::Decimal128(10, 2), DataType::Decimal256(20, 0)],
+                DataType::Decimal256(22, 2),
+            ),
+        ];
+        for (current_types, expected) in cases {
+            assert_eq!(
+                data_types(&current_types, &signature)?,
+                vec![expected; current_types.len()]
+            );
+        }
+
+        // no decimal type can represent both
+        let current_types =
+            vec![DataType::Decimal256(76, 0), DataType::Decimal256(76, 10)];
+        assert!(data_types(&current_types, &signature).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_valid_types_one_of() -> Result<()> {
         let signature =
@@ -568,6 +816,100 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fixed_size_binary_wildcard_coerce() -> Result<()> {
+        let signature = Signature::uniform(
+            1,
+            vec![
+                DataType::FixedSizeBinary(FIXED_SIZE_BINARY_WILDCARD),
+                DataType::Utf8,
+            ],
+            Volatility::Immutable,
+        );
+
+        // any width is accepted as is
+        for width in [1, 16, 32] {
+            let current_types = vec![DataType::FixedSizeBinary(width)];
+            assert_eq!(data_types(&current_types, &signature)?, current_types);
+        }
+
+        // other types are still coerced to the remaining types
+        assert_eq!(
+            data_types(&[DataType::Int64], &signature)?,
+            vec![DataType::Utf8]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal128_wildcard_coerce() -> Result<()> {
+        let signature = Signature::one_of(
+            vec![
+                TypeSignature::Exact(vec![DataType::Decimal128(DECIMAL128_WILDCARD, 0)]),
+                TypeSignature::Exact(vec![DataType::Float64]),
+            ],
+            Volatility::Immutable,
+        );
+
+        // any precision and scale, including a negative scale, is accepted as is
+        for (precision, scale) in [(1, 0), (10, 3), (38, 10), (5, -2)] {
+            let current_types = vec![DataType::Decimal128(precision, scale)];
+            assert_eq!(data_types(&current_types, &signature)?, current_types);
+        }
+
+        // other types are still coerced to the remaining types
+        for data_type in [DataType::Int64, DataType::Null] {
+            assert_eq!(
+                data_types(&[data_type], &signature)?,
+                vec![DataType::Float64]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_end_encoded_coerce() -> Result<()> {
+        let run_end_encoded = |run_ends: DataType, values: DataType| {
+            DataType::RunEndEncoded(
+                Arc::new(Field::new("run_ends", run_ends, false)),
+                Arc::new(Field::new("values", values, true)),
+            )
+        };
+        let signature = Signature::one_of(
+            vec![
+                TypeSignature::Variadic(vec![run_end_encoded(
+                    DataType::Int32,
+                    DataType::Utf8,
+                )]),
+                TypeSignature::Variadic(vec![DataType::Utf8]),
+            ],
+            Volatility::Immutable,
+        );
+
+        // any run end type is accepted as is, alongside plain strings
+        for run_ends in [DataType::Int16, DataType::Int32, DataType::Int64] {
+            let current_types = vec![
+                run_end_encoded(run_ends, DataType::Utf8),
+                DataType::Utf8,
+                DataType::Null,
+            ];
+            let mut expected = current_types.clone();
+            expected[2] = DataType::Utf8;
+            assert_eq!(data_types(&current_types, &signature)?, expected);
+        }
+
+        // other types are still coerced to the remaining types
+        let current_types = vec![DataType::Int64, DataType::Utf8];
+        assert_eq!(
+            data_types(&current_types, &signature)?,
+            vec![DataType::Utf8, DataType::Utf8]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_nested_wildcard_fixed_size_lists() -> Result<()> {
         let type_into = DataType::FixedSizeList(
@@ -0,0 +1,98 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array, ListArray, StringArray, StructArray};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::{DataType, Field, Fields};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+use datafusion_expr::{col, ColumnarValue, Expr, ScalarFunctionDefinition, ScalarUDF};
+use datafusion_functions_array::expr_fn::array_distinct;
+
+const NUM_ROWS: usize = 50;
+const LIST_LEN: usize = 2000;
+
+type Element = (Option<i64>, String);
+
+/// Random struct elements drawn from a small domain, so every list has many duplicates
+fn lists(rng: &mut ThreadRng) -> Vec<Vec<Element>> {
+    (0..NUM_ROWS)
+        .map(|_| {
+            (0..LIST_LEN)
+                .map(|_| {
+                    let key = rng.gen_bool(0.99).then(|| rng.gen_range(0..50));
+                    (key, format!("value_{}", rng.gen_range(0..10)))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn to_list_array(lists: &[Vec<Element>]) -> ArrayRef {
+    let fields = Fields::from(vec![
+        Field::new("k", DataType::Int64, true),
+        Field::new("v", DataType::Utf8, false),
+    ]);
+    let elements = lists.iter().flatten();
+    let keys: Int64Array = elements.clone().map(|(k, _)| *k).collect();
+    let values: StringArray = elements.map(|(_, v)| Some(v.as_str())).collect();
+    let structs =
+        StructArray::new(fields.clone(), vec![Arc::new(keys), Arc::new(values)], None);
+    Arc::new(ListArray::new(
+        Arc::new(Field::new("item", DataType::Struct(fields), true)),
+        OffsetBuffer::from_lengths(lists.iter().map(|list| list.len())),
+        Arc::new(structs),
+        None,
+    ))
+}
+
+fn array_distinct_udf() -> Arc<ScalarUDF> {
+    match array_distinct(col("a")) {
+        Expr::ScalarFunction(func) => match func.func_def {
+            ScalarFunctionDefinition::UDF(udf) => udf,
+            _ => unreachable!("array_distinct is a UDF"),
+        },
+        _ => unreachable!("array_distinct is a scalar function"),
+    }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let args = vec![ColumnarValue::Array(to_list_array(&lists(&mut rng)))];
+    let udf = array_distinct_udf();
+
+    c.bench_function(
+        &format!("array_distinct_struct_{NUM_ROWS}x{LIST_LEN}"),
+        |b| {
+            b.iter(|| {
+                black_box(
+                    udf.invoke(&args)
+                        .expect("array_distinct should work on valid values"),
+                )
+            })
+        },
+    );
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
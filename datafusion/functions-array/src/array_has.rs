@@ -17,7 +17,8 @@
 
 //! [`ScalarUDFImpl`] definitions for array functions.
 
-use arrow::array::{Array, ArrayRef, BooleanArray, OffsetSizeTrait};
+use arrow::array::{new_null_array, Array, ArrayRef, BooleanArray, OffsetSizeTrait};
+use arrow::compute::cast;
 use arrow::datatypes::DataType;
 use arrow::row::{RowConverter, SortField};
 use datafusion_common::cast::as_generic_list_array;
@@ -28,7 +29,7 @@ use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 
 use itertools::Itertools;
 
-use crate::utils::check_datatypes;
+use crate::utils::{check_datatypes, fixed_size_list_to_list};
 
 use std::any::Any;
 use std::sync::Arc;
@@ -157,6 +158,13 @@ impl ScalarUDFImpl for ArrayHasAll {
             return exec_err!("array_has_all needs two arguments");
         }
 
+        let Some(args) = set_comparison_args(&args)? else {
+            return Ok(ColumnarValue::Array(new_null_array(
+                &DataType::Boolean,
+                args[0].len(),
+            )));
+        };
+
         let array_type = args[0].data_type();
 
         match array_type {
@@ -215,6 +223,13 @@ impl ScalarUDFImpl for ArrayHasAny {
             return exec_err!("array_has_any needs two arguments");
         }
 
+        let Some(args) = set_comparison_args(&args)? else {
+            return Ok(ColumnarValue::Array(new_null_array(
+                &DataType::Boolean,
+                args[0].len(),
+            )));
+        };
+
         let array_type = args[0].data_type();
 
         match array_type {
@@ -235,6 +250,34 @@ impl ScalarUDFImpl for ArrayHasAny {
     }
 }
 
+/// Prepares the arguments of `array_has_all` and `array_has_any`, or returns
+/// `None` if either is an untyped `NULL`, which makes every row null. An untyped
+/// list such as `[]` takes the type of the other argument, so that an empty
+/// second array is contained in every array and overlaps none.
+fn set_comparison_args(args: &[ArrayRef]) -> Result<Option<Vec<ArrayRef>>> {
+    if args.iter().any(|arg| arg.data_type() == &DataType::Null) {
+        return Ok(None);
+    }
+
+    let args = args
+        .iter()
+        .map(fixed_size_list_to_list)
+        .collect::<Result<Vec<_>>>()?;
+
+    let is_untyped = |arg: &ArrayRef| match arg.data_type() {
+        DataType::List(field) | DataType::LargeList(field) => {
+            field.data_type() == &DataType::Null
+        }
+        _ => false,
+    };
+    let args = match (is_untyped(&args[0]), is_untyped(&args[1])) {
+        (true, false) => vec![cast(&args[0], args[1].data_type())?, args[1].clone()],
+        (false, true) => vec![args[0].clone(), cast(&args[1], args[0].data_type())?],
+        _ => args,
+    };
+    Ok(Some(args))
+}
+
 /// Represents the type of comparison for array_has.
 #[derive(Debug, PartialEq)]
 enum ComparisonType {
@@ -273,6 +316,18 @@ fn general_array_has_dispatch<O: OffsetSizeTrait>(
 
     for (row_idx, (arr, sub_arr)) in array.iter().zip(sub_array.iter()).enumerate() {
         if let (Some(arr), Some(sub_arr)) = (arr, sub_arr) {
+            // whether an array has a NULL element is unknown, unless the array is
+            // empty. Unlike `IN`, a non-NULL element that is not found is false,
+            // even if the array has NULL elements
+            if comparison_type == ComparisonType::Single && element.is_null(row_idx) {
+                if arr.is_empty() {
+                    boolean_builder.append_value(false);
+                } else {
+                    boolean_builder.append_null();
+                }
+                continue;
+            }
+
             let arr_values = converter.convert_columns(&[arr])?;
             let sub_arr_values = if comparison_type != ComparisonType::Single {
                 converter.convert_columns(&[sub_arr])?
@@ -300,6 +355,9 @@ fn general_array_has_dispatch<O: OffsetSizeTrait>(
             }
 
             boolean_builder.append_value(res);
+        } else {
+            // keep the output aligned with the input rows
+            boolean_builder.append_null();
         }
     }
     Ok(Arc::new(boolean_builder.finish()))
@@ -20,12 +20,16 @@
 use std::{any::Any, cmp::Ordering, sync::Arc};
 
 use arrow::array::{Capacities, MutableArrayData};
-use arrow_array::{Array, ArrayRef, GenericListArray, OffsetSizeTrait};
+use arrow_array::{
+    new_empty_array, new_null_array, Array, ArrayRef, GenericListArray, OffsetSizeTrait,
+};
 use arrow_buffer::{BooleanBufferBuilder, NullBuffer, OffsetBuffer};
 use arrow_schema::{DataType, Field};
 use datafusion_common::Result;
 use datafusion_common::{
-    cast::as_generic_list_array, exec_err, not_impl_err, plan_err, utils::list_ndims,
+    cast::as_generic_list_array,
+    exec_err, not_impl_err, plan_err,
+    utils::{coerced_type_with_base_type_only, list_ndims},
 };
 use datafusion_expr::expr::ScalarFunction;
 use datafusion_expr::Expr;
@@ -78,7 +82,7 @@ impl ScalarUDFImpl for ArrayAppend {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        Ok(arg_types[0].clone())
+        Ok(append_or_prepend_return_type(&arg_types[0], &arg_types[1]))
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -132,7 +136,7 @@ impl ScalarUDFImpl for ArrayPrepend {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        Ok(arg_types[1].clone())
+        Ok(append_or_prepend_return_type(&arg_types[1], &arg_types[0]))
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -144,6 +148,20 @@ impl ScalarUDFImpl for ArrayPrepend {
     }
 }
 
+/// Returns the type of `array_append` and `array_prepend`: an untyped `NULL`
+/// list is treated as a null list of the element type
+fn append_or_prepend_return_type(
+    list_type: &DataType,
+    element_type: &DataType,
+) -> DataType {
+    match list_type {
+        DataType::Null => {
+            DataType::List(Arc::new(Field::new("item", element_type.clone(), true)))
+        }
+        _ => list_type.clone(),
+    }
+}
+
 make_udf_function!(
     ArrayConcat,
     array_concat,
@@ -185,32 +203,11 @@ impl ScalarUDFImpl for ArrayConcat {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        let mut expr_type = DataType::Null;
-        let mut max_dims = 0;
-        for arg_type in arg_types {
-            match arg_type {
-                DataType::List(field) => {
-                    if !field.data_type().equals_datatype(&DataType::Null) {
-                        let dims = list_ndims(arg_type);
-                        expr_type = match max_dims.cmp(&dims) {
-                            Ordering::Greater => expr_type,
-                            Ordering::Equal => get_wider_type(&expr_type, arg_type)?,
-                            Ordering::Less => {
-                                max_dims = dims;
-                                arg_type.clone()
-                            }
-                        };
-                    }
-                }
-                _ => {
-                    return plan_err!(
-                        "The array_concat function can only accept list as the args."
-                    )
-                }
-            }
+        // there is no element type to infer without arguments, unlike `make_array()`
+        if arg_types.is_empty() {
+            return plan_err!("array_concat requires at least one argument");
         }
-
-        Ok(expr_type)
+        concat_return_type(arg_types)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -222,30 +219,105 @@ impl ScalarUDFImpl for ArrayConcat {
     }
 }
 
+/// Returns the type of `array_concat`: the widest of the list arguments with the
+/// most dimensions. Untyped arguments, `NULL` or lists of `Null` such as `[]`,
+/// take that type, and the result is `List(Null)` if there are only untyped lists.
+fn concat_return_type(arg_types: &[DataType]) -> Result<DataType> {
+    let mut expr_type = DataType::Null;
+    let mut max_dims = 0;
+    for arg_type in arg_types {
+        match arg_type {
+            DataType::Null => {}
+            DataType::List(field) => {
+                if field.data_type().equals_datatype(&DataType::Null) {
+                    if max_dims == 0 {
+                        expr_type = arg_type.clone();
+                    }
+                } else {
+                    let dims = list_ndims(arg_type);
+                    expr_type = match max_dims.cmp(&dims) {
+                        Ordering::Greater => expr_type,
+                        Ordering::Equal => get_wider_type(&expr_type, arg_type)?,
+                        Ordering::Less => {
+                            max_dims = dims;
+                            arg_type.clone()
+                        }
+                    };
+                }
+            }
+            _ => {
+                return plan_err!(
+                    "The array_concat function can only accept list as the args."
+                )
+            }
+        }
+    }
+
+    Ok(expr_type)
+}
+
 /// Array_concat/Array_cat SQL function
 pub(crate) fn array_concat_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.is_empty() {
         return exec_err!("array_concat expects at least one arguments");
     }
 
+    let arg_types = args
+        .iter()
+        .map(|arg| arg.data_type().clone())
+        .collect::<Vec<_>>();
+    let return_type = concat_return_type(&arg_types)?;
+    let return_base_type = datafusion_common::utils::base_type(&return_type);
+
     let mut new_args = vec![];
     for arg in args {
         let ndim = list_ndims(arg.data_type());
         let base_type = datafusion_common::utils::base_type(arg.data_type());
+        // untyped arguments contribute no elements
+        if base_type.eq(&DataType::Null) {
+            continue;
+        }
         if ndim == 0 {
             return not_impl_err!("Array is not type '{base_type:?}'.");
         }
-        if !base_type.eq(&DataType::Null) {
-            new_args.push(arg.clone());
-        }
+        // cast the elements to the common type, e.g. `[1]::INT[]` with `[2]::BIGINT[]`
+        let data_type =
+            coerced_type_with_base_type_only(arg.data_type(), &return_base_type);
+        new_args.push(arrow::compute::cast(arg, &data_type)?);
+    }
+
+    if new_args.is_empty() {
+        return concat_untyped(args, &return_type);
     }
 
-    match &args[0].data_type() {
+    match &new_args[0].data_type() {
         DataType::LargeList(_) => concat_internal::<i64>(new_args.as_slice()),
         _ => concat_internal::<i32>(new_args.as_slice()),
     }
 }
 
+/// Concatenates arguments that are all `NULL` or lists of `Null`: each row is an
+/// empty list, or null if all the lists on that row are null
+fn concat_untyped(args: &[ArrayRef], return_type: &DataType) -> Result<ArrayRef> {
+    let len = args[0].len();
+    if return_type.is_null() {
+        return Ok(new_null_array(return_type, len));
+    }
+
+    let valid = (0..len)
+        .map(|i| {
+            args.iter()
+                .any(|arg| !arg.data_type().is_null() && arg.is_valid(i))
+        })
+        .collect::<Vec<_>>();
+    Ok(Arc::new(GenericListArray::<i32>::new(
+        Arc::new(Field::new("item", DataType::Null, true)),
+        OffsetBuffer::new_zeroed(len),
+        new_empty_array(&DataType::Null),
+        Some(NullBuffer::from(valid)),
+    )))
+}
+
 fn concat_internal<O: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let args = align_array_dimensions::<O>(args.to_vec())?;
 
@@ -297,11 +369,17 @@ fn concat_internal<O: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
         .iter()
         .map(|a| a.as_ref())
         .collect::<Vec<&dyn Array>>();
+    // every row is null when there are no elements to concatenate
+    let values = if elements.is_empty() {
+        new_empty_array(&data_type)
+    } else {
+        arrow::compute::concat(elements.as_slice())?
+    };
 
     let list_arr = GenericListArray::<O>::new(
         Arc::new(Field::new("item", data_type, true)),
         OffsetBuffer::from_lengths(array_lengths),
-        Arc::new(arrow::compute::concat(elements.as_slice())?),
+        values,
         Some(NullBuffer::new(buffer)),
     );
 
@@ -414,7 +492,12 @@ where
 
     for (row_index, offset_window) in list_array.offsets().windows(2).enumerate() {
         let start = offset_window[0].to_usize().unwrap();
-        let end = offset_window[1].to_usize().unwrap();
+        // a null list is empty, so the result is a single element list
+        let end = if list_array.is_null(row_index) {
+            start
+        } else {
+            offset_window[1].to_usize().unwrap()
+        };
         if is_append {
             mutable.extend(values_index, start, end);
             mutable.extend(element_index, row_index, row_index + 1);
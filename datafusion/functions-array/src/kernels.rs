@@ -38,11 +38,13 @@ use datafusion_common::cast::{
     as_interval_mdn_array, as_large_list_array, as_list_array, as_null_array,
     as_string_array,
 };
+use datafusion_common::utils::base_type;
 use datafusion_common::{
-    exec_err, internal_err, not_impl_datafusion_err, DataFusionError, Result,
+    exec_datafusion_err, exec_err, internal_err, not_impl_datafusion_err,
+    DataFusionError, Result,
 };
-use itertools::Itertools;
 use std::any::type_name;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 macro_rules! downcast_arg {
@@ -128,12 +130,15 @@ pub(super) fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
     let delimiters = as_string_array(&args[1])?;
     let delimiters: Vec<Option<&str>> = delimiters.iter().collect();
 
-    let mut null_string = String::from("");
-    let mut with_null_string = false;
-    if args.len() == 3 {
-        null_string = as_string_array(&args[2])?.value(0).to_string();
-        with_null_string = true;
-    }
+    // null elements are dropped, unless a non-null `null_string` is given to replace them
+    let null_string = match args.get(2) {
+        Some(null_strings) if null_strings.data_type() != &DataType::Null => {
+            as_string_array(null_strings)?.iter().next().flatten()
+        }
+        _ => None,
+    };
+    let with_null_string = null_string.is_some();
+    let null_string = null_string.unwrap_or_default().to_string();
 
     fn compute_array_to_string(
         arg: &mut String,
@@ -171,7 +176,16 @@ pub(super) fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
 
                 Ok(arg)
             }
-            DataType::Null => Ok(arg),
+            // every element of a Null typed array is null
+            DataType::Null => {
+                if with_null_string {
+                    for _ in 0..arr.len() {
+                        arg.push_str(&null_string);
+                        arg.push_str(&delimiter);
+                    }
+                }
+                Ok(arg)
+            }
             data_type => {
                 macro_rules! array_function {
                     ($ARRAY_TYPE:ident) => {
@@ -195,7 +209,7 @@ pub(super) fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
         delimiters: Vec<Option<&str>>,
         null_string: String,
         with_null_string: bool,
-    ) -> datafusion_common::Result<StringArray> {
+    ) -> datafusion_common::Result<Vec<Option<String>>> {
         let mut res: Vec<Option<String>> = Vec::new();
         for (arr, &delimiter) in list_arr.iter().zip(delimiters.iter()) {
             if let (Some(arr), Some(delimiter)) = (arr, delimiter) {
@@ -219,11 +233,11 @@ pub(super) fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
             }
         }
 
-        Ok(StringArray::from(res))
+        Ok(res)
     }
 
     let arr_type = arr.data_type();
-    let string_arr = match arr_type {
+    let strings = match arr_type {
         DataType::List(_) | DataType::FixedSizeList(_, _) => {
             let list_array = as_list_array(&arr)?;
             generate_string_array::<i32>(
@@ -263,15 +277,23 @@ pub(super) fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
             } else {
                 res.push(Some(s));
             }
-            StringArray::from(res)
+            res
         }
     };
 
-    Ok(Arc::new(string_arr))
+    // large string elements are joined into a large string
+    Ok(match base_type(arr_type) {
+        DataType::LargeUtf8 => Arc::new(LargeStringArray::from(strings)),
+        _ => Arc::new(StringArray::from(strings)),
+    })
 }
 
 /// Splits string at occurrences of delimiter and returns an array of parts
 /// string_to_array('abc~@~def~@~ghi', '~@~') = '["abc", "def", "ghi"]'
+///
+/// An empty or NULL delimiter splits the string into its characters, like an
+/// empty delimiter in `split_part`.
+/// string_to_array('abc', '') = '["a", "b", "c"]'
 pub fn string_to_array<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.len() < 2 || args.len() > 3 {
         return exec_err!("string_to_array expects two or three arguments");
@@ -289,17 +311,13 @@ pub fn string_to_array<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef
             string_array.iter().zip(delimiter_array.iter()).for_each(
                 |(string, delimiter)| {
                     match (string, delimiter) {
-                        (Some(string), Some("")) => {
-                            list_builder.values().append_value(string);
-                            list_builder.append(true);
-                        }
-                        (Some(string), Some(delimiter)) => {
+                        (Some(string), Some(delimiter)) if !delimiter.is_empty() => {
                             string.split(delimiter).for_each(|s| {
                                 list_builder.values().append_value(s);
                             });
                             list_builder.append(true);
                         }
-                        (Some(string), None) => {
+                        (Some(string), _) => {
                             string.chars().map(|c| c.to_string()).for_each(|c| {
                                 list_builder.values().append_value(c);
                             });
@@ -319,15 +337,7 @@ pub fn string_to_array<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef
                 .zip(null_value_array.iter())
                 .for_each(|((string, delimiter), null_value)| {
                     match (string, delimiter) {
-                        (Some(string), Some("")) => {
-                            if Some(string) == null_value {
-                                list_builder.values().append_null();
-                            } else {
-                                list_builder.values().append_value(string);
-                            }
-                            list_builder.append(true);
-                        }
-                        (Some(string), Some(delimiter)) => {
+                        (Some(string), Some(delimiter)) if !delimiter.is_empty() => {
                             string.split(delimiter).for_each(|s| {
                                 if Some(s) == null_value {
                                     list_builder.values().append_null();
@@ -337,7 +347,7 @@ pub fn string_to_array<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef
                             });
                             list_builder.append(true);
                         }
-                        (Some(string), None) => {
+                        (Some(string), _) => {
                             string.chars().map(|c| c.to_string()).for_each(|c| {
                                 if Some(c.as_str()) == null_value {
                                     list_builder.values().append_null();
@@ -677,6 +687,9 @@ fn general_array_empty<O: OffsetSizeTrait>(array: &ArrayRef) -> Result<ArrayRef>
 }
 
 /// Returns the length of a concrete array dimension
+///
+/// As in PostgreSQL, the length is NULL rather than 0 for an empty array, and
+/// for a dimension greater than the nesting depth of the array.
 fn compute_array_length(
     arr: Option<ArrayRef>,
     dimension: Option<i64>,
@@ -698,6 +711,10 @@ fn compute_array_length(
     };
 
     loop {
+        // an empty array has no length along any dimension
+        if value.is_empty() {
+            return Ok(None);
+        }
         if current_dimension == dimension {
             return Ok(Some(value.len() as u64));
         }
@@ -756,11 +773,24 @@ pub fn array_repeat(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// Returns how many times each element is repeated, where a NULL count repeats it 0
+/// times. A negative count is an error.
+fn repeat_counts(count_array: &Int64Array) -> Result<Vec<usize>> {
+    count_array
+        .iter()
+        .map(|count| match count {
+            Some(count) if count < 0 => {
+                exec_err!("array_repeat count must not be negative, got {count}")
+            }
+            count => Ok(count.unwrap_or(0) as usize),
+        })
+        .collect()
+}
+
 /// For each element of `array[i]` repeat `count_array[i]` times.
 ///
 /// Assumption for the input:
-///     1. `count[i] >= 0`
-///     2. `array.len() == count_array.len()`
+///     1. `array.len() == count_array.len()`
 ///
 /// For example,
 /// ```text
@@ -775,12 +805,7 @@ fn general_repeat<O: OffsetSizeTrait>(
     let data_type = array.data_type();
     let mut new_values = vec![];
 
-    let count_vec = count_array
-        .values()
-        .to_vec()
-        .iter()
-        .map(|x| *x as usize)
-        .collect::<Vec<_>>();
+    let count_vec = repeat_counts(count_array)?;
 
     for (row_index, &count) in count_vec.iter().enumerate() {
         let repeated_array = if array.is_null(row_index) {
@@ -830,12 +855,7 @@ fn general_list_repeat<O: OffsetSizeTrait>(
     let value_type = list_array.value_type();
     let mut new_values = vec![];
 
-    let count_vec = count_array
-        .values()
-        .to_vec()
-        .iter()
-        .map(|x| *x as usize)
-        .collect::<Vec<_>>();
+    let count_vec = repeat_counts(count_array)?;
 
     for (list_array_row, &count) in list_array.iter().zip(count_vec.iter()) {
         let list_arr = match list_array_row {
@@ -894,33 +914,58 @@ pub fn array_length(args: &[ArrayRef]) -> Result<ArrayRef> {
 }
 
 /// Array_sort SQL function
+///
+/// The sort is stable: elements that compare equal keep their original
+/// relative order within each list.
+///
+/// The optional fourth argument selects `LEXICAL` (the default) or `NUMERIC`
+/// ordering. Numeric ordering compares string elements by the numbers they
+/// parse to, while returning the original strings.
 pub fn array_sort(args: &[ArrayRef]) -> Result<ArrayRef> {
-    if args.is_empty() || args.len() > 3 {
-        return exec_err!("array_sort expects one to three arguments");
+    if args.is_empty() || args.len() > 4 {
+        return exec_err!("array_sort expects one to four arguments");
     }
 
     let sort_option = match args.len() {
-        1 => None,
+        1 => SortOptions::default(),
         2 => {
             let sort = as_string_array(&args[1])?.value(0);
-            Some(SortOptions {
+            SortOptions {
                 descending: order_desc(sort)?,
                 nulls_first: true,
-            })
+            }
         }
-        3 => {
+        3 | 4 => {
             let sort = as_string_array(&args[1])?.value(0);
             let nulls_first = as_string_array(&args[2])?.value(0);
-            Some(SortOptions {
+            SortOptions {
                 descending: order_desc(sort)?,
                 nulls_first: order_nulls_first(nulls_first)?,
-            })
+            }
         }
-        _ => return exec_err!("array_sort expects 1 to 3 arguments"),
+        _ => return exec_err!("array_sort expects 1 to 4 arguments"),
+    };
+    let numeric = match args.get(3) {
+        Some(mode) => order_numeric(as_string_array(mode)?.value(0))?,
+        None => false,
     };
 
     let list_array = as_list_array(&args[0])?;
     let row_count = list_array.len();
+    let key_type = if numeric {
+        match list_array.value_type() {
+            DataType::Utf8 | DataType::LargeUtf8 => DataType::Float64,
+            other => {
+                return exec_err!(
+                    "array_sort NUMERIC ordering expects string elements, got {other}"
+                )
+            }
+        }
+    } else {
+        list_array.value_type()
+    };
+    let converter =
+        RowConverter::new(vec![SortField::new_with_options(key_type, sort_option)])?;
 
     let mut array_lengths = vec![];
     let mut arrays = vec![];
@@ -931,9 +976,13 @@ pub fn array_sort(args: &[ArrayRef]) -> Result<ArrayRef> {
             valid.append(false);
         } else {
             let arr_ref = list_array.value(i);
-            let arr_ref = arr_ref.as_ref();
+            let keys = if numeric {
+                parse_numeric_keys(&arr_ref)?
+            } else {
+                arr_ref.clone()
+            };
 
-            let sorted_array = compute::sort(arr_ref, sort_option)?;
+            let sorted_array = stable_sort(&converter, &keys, &arr_ref)?;
             array_lengths.push(sorted_array.len());
             arrays.push(sorted_array);
             valid.append(true);
@@ -958,6 +1007,24 @@ pub fn array_sort(args: &[ArrayRef]) -> Result<ArrayRef> {
     Ok(Arc::new(list_arr))
 }
 
+/// Sorts `values` by the corresponding elements of `keys` using the ordering
+/// of `converter`, keeping equal keys in their original order.
+fn stable_sort(
+    converter: &RowConverter,
+    keys: &ArrayRef,
+    values: &ArrayRef,
+) -> Result<ArrayRef> {
+    let rows = converter.convert_columns(std::slice::from_ref(keys))?;
+    let mut indices = (0..rows.num_rows() as u32).collect::<Vec<_>>();
+    // `sort_by` is a stable sort, unlike `compute::sort`
+    indices.sort_by(|a, b| rows.row(*a as usize).cmp(&rows.row(*b as usize)));
+    Ok(compute::take(
+        values.as_ref(),
+        &UInt32Array::from(indices),
+        None,
+    )?)
+}
+
 fn order_desc(modifier: &str) -> Result<bool> {
     match modifier.to_uppercase().as_str() {
         "DESC" => Ok(true),
@@ -966,6 +1033,45 @@ fn order_desc(modifier: &str) -> Result<bool> {
     }
 }
 
+fn order_numeric(modifier: &str) -> Result<bool> {
+    match modifier.to_uppercase().as_str() {
+        "NUMERIC" => Ok(true),
+        "LEXICAL" => Ok(false),
+        _ => exec_err!("the fourth parameter of array_sort expects LEXICAL or NUMERIC"),
+    }
+}
+
+/// Parses the string elements of `array` as numbers to sort them by, keeping nulls
+fn parse_numeric_keys(array: &ArrayRef) -> Result<ArrayRef> {
+    fn parse<O: OffsetSizeTrait>(array: &ArrayRef) -> Result<Float64Array> {
+        as_generic_string_array::<O>(array)?
+            .iter()
+            .map(|value| {
+                value
+                    .map(|value| {
+                        value.parse::<f64>().map_err(|_| {
+                            exec_datafusion_err!(
+                                "array_sort NUMERIC ordering cannot parse '{value}' as a number"
+                            )
+                        })
+                    })
+                    .transpose()
+            })
+            .collect()
+    }
+
+    let keys = match array.data_type() {
+        DataType::Utf8 => parse::<i32>(array)?,
+        DataType::LargeUtf8 => parse::<i64>(array)?,
+        other => {
+            return exec_err!(
+                "array_sort NUMERIC ordering expects string elements, got {other}"
+            )
+        }
+    };
+    Ok(Arc::new(keys))
+}
+
 fn order_nulls_first(modifier: &str) -> Result<bool> {
     match modifier.to_uppercase().as_str() {
         "NULLS FIRST" => Ok(true),
@@ -976,51 +1082,13 @@ fn order_nulls_first(modifier: &str) -> Result<bool> {
     }
 }
 
-// Create new offsets that are euqiavlent to `flatten` the array.
-fn get_offsets_for_flatten<O: OffsetSizeTrait>(
-    offsets: OffsetBuffer<O>,
-    indexes: OffsetBuffer<O>,
-) -> OffsetBuffer<O> {
-    let buffer = offsets.into_inner();
-    let offsets: Vec<O> = indexes
-        .iter()
-        .map(|i| buffer[i.to_usize().unwrap()])
-        .collect();
-    OffsetBuffer::new(offsets.into())
-}
-
-fn flatten_internal<O: OffsetSizeTrait>(
-    list_arr: GenericListArray<O>,
-    indexes: Option<OffsetBuffer<O>>,
-) -> Result<GenericListArray<O>> {
-    let (field, offsets, values, _) = list_arr.clone().into_parts();
-    let data_type = field.data_type();
-
-    match data_type {
-        // Recursively get the base offsets for flattened array
-        DataType::List(_) | DataType::LargeList(_) => {
-            let sub_list = as_generic_list_array::<O>(&values)?;
-            if let Some(indexes) = indexes {
-                let offsets = get_offsets_for_flatten(offsets, indexes);
-                flatten_internal::<O>(sub_list.clone(), Some(offsets))
-            } else {
-                flatten_internal::<O>(sub_list.clone(), Some(offsets))
-            }
-        }
-        // Reach the base level, create a new list array
-        _ => {
-            if let Some(indexes) = indexes {
-                let offsets = get_offsets_for_flatten(offsets, indexes);
-                let list_arr = GenericListArray::<O>::new(field, offsets, values, None);
-                Ok(list_arr)
-            } else {
-                Ok(list_arr.clone())
-            }
-        }
-    }
-}
-
 /// Flatten SQL function
+///
+/// Removes one level of nesting: every row becomes the concatenation of its inner
+/// arrays. NULL inner arrays are skipped, and NULL rows stay NULL.
+///
+/// For example:
+/// > flatten(\[\[1, 2], NULL, \[3]]) -> \[1, 2, 3]
 pub fn flatten(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.len() != 1 {
         return exec_err!("flatten expects one argument");
@@ -1028,15 +1096,25 @@ pub fn flatten(args: &[ArrayRef]) -> Result<ArrayRef> {
 
     let array_type = args[0].data_type();
     match array_type {
-        DataType::List(_) => {
-            let list_arr = as_list_array(&args[0])?;
-            let flattened_array = flatten_internal::<i32>(list_arr.clone(), None)?;
-            Ok(Arc::new(flattened_array) as ArrayRef)
+        DataType::List(field) => {
+            let array = as_list_array(&args[0])?;
+            match field.data_type() {
+                DataType::List(_) => general_flatten::<i32, i32>(array),
+                DataType::LargeList(_) => general_flatten::<i32, i64>(array),
+                dt => {
+                    exec_err!("flatten expects an array of arrays, got an array of {dt}")
+                }
+            }
         }
-        DataType::LargeList(_) => {
-            let list_arr = as_large_list_array(&args[0])?;
-            let flattened_array = flatten_internal::<i64>(list_arr.clone(), None)?;
-            Ok(Arc::new(flattened_array) as ArrayRef)
+        DataType::LargeList(field) => {
+            let array = as_large_list_array(&args[0])?;
+            match field.data_type() {
+                DataType::List(_) => general_flatten::<i64, i32>(array),
+                DataType::LargeList(_) => general_flatten::<i64, i64>(array),
+                dt => {
+                    exec_err!("flatten expects an array of arrays, got an array of {dt}")
+                }
+            }
         }
         DataType::Null => Ok(args[0].clone()),
         _ => {
@@ -1045,8 +1123,55 @@ pub fn flatten(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// Concatenates the inner lists of every row of `array`.
+///
+/// `O` is the offset type of the outer list and `I` the offset type of the inner lists.
+fn general_flatten<O: OffsetSizeTrait, I: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+) -> Result<ArrayRef> {
+    let inner = as_generic_list_array::<I>(array.values())?;
+    let field = match inner.data_type() {
+        DataType::List(field) | DataType::LargeList(field) => field.clone(),
+        dt => return internal_err!("flatten got unexpected type {dt:?}"),
+    };
+
+    let values = inner.values().to_data();
+    let mut mutable = MutableArrayData::with_capacities(
+        vec![&values],
+        false,
+        Capacities::Array(values.len()),
+    );
+    let inner_offsets = inner.offsets();
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::usize_as(0));
+    let mut len = 0;
+
+    for (row_index, window) in array.offsets().windows(2).enumerate() {
+        if array.is_valid(row_index) {
+            for inner_index in window[0].as_usize()..window[1].as_usize() {
+                // NULL inner arrays do not contribute any element
+                if inner.is_null(inner_index) {
+                    continue;
+                }
+                let start = inner_offsets[inner_index].as_usize();
+                let end = inner_offsets[inner_index + 1].as_usize();
+                mutable.extend(0, start, end);
+                len += end - start;
+            }
+        }
+        offsets.push(O::usize_as(len));
+    }
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field,
+        OffsetBuffer::new(offsets.into()),
+        arrow_array::make_array(mutable.freeze()),
+        array.nulls().cloned(),
+    )?))
+}
+
 /// array_distinct SQL function
-/// example: from list [1, 3, 2, 3, 1, 2, 4] to [1, 2, 3, 4]
+/// example: from list [1, 3, 2, 3, 1, 2, 4] to [1, 3, 2, 4]
 pub fn array_distinct(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.len() != 1 {
         return exec_err!("array_distinct needs one argument");
@@ -1071,38 +1196,90 @@ pub fn array_distinct(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// Removes duplicate elements from every list of `array`, keeping the first
+/// occurrence of each element (including at most one null) in its original order.
+///
+/// All elements are converted to the row format once, so that elements of any type,
+/// including structs and nested lists, are hashed and compared by their row bytes.
+/// NULL lists stay NULL.
 pub fn general_array_distinct<OffsetSize: OffsetSizeTrait>(
     array: &GenericListArray<OffsetSize>,
     field: &FieldRef,
 ) -> Result<ArrayRef> {
-    let dt = array.value_type();
-    let mut offsets = Vec::with_capacity(array.len());
+    let converter = RowConverter::new(vec![SortField::new(array.value_type())])?;
+    let rows = converter.convert_columns(&[array.values().clone()])?;
+
+    let mut offsets = Vec::with_capacity(array.len() + 1);
     offsets.push(OffsetSize::usize_as(0));
-    let mut new_arrays = Vec::with_capacity(array.len());
-    let converter = RowConverter::new(vec![SortField::new(dt)])?;
-    // distinct for each list in ListArray
-    for arr in array.iter().flatten() {
-        let values = converter.convert_columns(&[arr])?;
-        // sort elements in list and remove duplicates
-        let rows = values.iter().sorted().dedup().collect::<Vec<_>>();
-        let last_offset: OffsetSize = offsets.last().copied().unwrap();
-        offsets.push(last_offset + OffsetSize::usize_as(rows.len()));
-        let arrays = converter.convert_rows(rows)?;
-        let array = match arrays.first() {
-            Some(array) => array.clone(),
-            None => {
-                return internal_err!("array_distinct: failed to get array from rows")
+    // indices into `array.values()` of the elements to keep
+    let mut indices = Vec::with_capacity(rows.num_rows());
+    let mut seen = HashSet::new();
+    for (row_index, window) in array.offsets().windows(2).enumerate() {
+        if array.is_valid(row_index) {
+            seen.clear();
+            for i in window[0].as_usize()..window[1].as_usize() {
+                if seen.insert(rows.row(i)) {
+                    indices.push(i as u64);
+                }
             }
-        };
-        new_arrays.push(array);
+        }
+        offsets.push(OffsetSize::usize_as(indices.len()));
     }
-    let offsets = OffsetBuffer::new(offsets.into());
-    let new_arrays_ref = new_arrays.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
-    let values = compute::concat(&new_arrays_ref)?;
+
+    let values = compute::take(array.values(), &UInt64Array::from(indices), None)?;
     Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
         field.clone(),
-        offsets,
+        OffsetBuffer::new(offsets.into()),
         values,
-        None,
+        array.nulls().cloned(),
     )?))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::StructArray;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+
+    /// Only test internal functions, array-related sql functions will be tested in sqllogictest `array.slt`
+    #[test]
+    fn test_stable_sort_preserves_order_of_ties() {
+        let keys: ArrayRef = Arc::new(Int32Array::from(vec![2, 1, 2, 1, 1]));
+        let tags: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e"]));
+        let values: ArrayRef = Arc::new(StructArray::from(vec![
+            (
+                Arc::new(Field::new("k", DataType::Int32, false)),
+                keys.clone(),
+            ),
+            (Arc::new(Field::new("v", DataType::Utf8, false)), tags),
+        ]));
+
+        // sort the structs by their first field only
+        let converter = RowConverter::new(vec![SortField::new(DataType::Int32)]).unwrap();
+        let sorted = stable_sort(&converter, &keys, &values).unwrap();
+        let sorted = sorted.as_struct();
+        assert_eq!(
+            sorted.column(0).as_primitive::<Int32Type>().values(),
+            &[1, 1, 1, 2, 2]
+        );
+        assert_eq!(
+            as_string_array(sorted.column(1)).unwrap(),
+            &StringArray::from(vec!["b", "d", "e", "a", "c"])
+        );
+
+        let converter = RowConverter::new(vec![SortField::new_with_options(
+            DataType::Int32,
+            SortOptions {
+                descending: true,
+                nulls_first: true,
+            },
+        )])
+        .unwrap();
+        let sorted = stable_sort(&converter, &keys, &values).unwrap();
+        assert_eq!(
+            as_string_array(sorted.as_struct().column(1)).unwrap(),
+            &StringArray::from(vec!["a", "c", "b", "d", "e"])
+        );
+    }
+}
@@ -101,3 +101,65 @@ pub fn register_all(registry: &mut dyn FunctionRegistry) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow_schema::{DataType, Field};
+    use datafusion_common::{internal_err, plan_err, DataFusionError};
+    use datafusion_expr::type_coercion::functions::data_types;
+
+    use super::*;
+
+    /// Plans a call of `udf` with arguments of `arg_types`, returning its type
+    fn planned_return_type(udf: &ScalarUDF, arg_types: &[DataType]) -> Result<DataType> {
+        let arg_types = data_types(arg_types, udf.signature())?;
+        udf.inner().return_type(&arg_types)
+    }
+
+    #[test]
+    fn test_empty_arguments_error() -> Result<()> {
+        let funs = [
+            concat::array_concat_udf(),
+            concat::array_append_udf(),
+            concat::array_prepend_udf(),
+        ];
+
+        for fun in funs.iter() {
+            match planned_return_type(fun, &[]) {
+                Ok(..) => {
+                    return plan_err!(
+                        "Array function {} does not support empty arguments",
+                        fun.name()
+                    );
+                }
+                Err(DataFusionError::Plan(_)) => {
+                    // Continue the loop
+                }
+                Err(..) => {
+                    return internal_err!(
+                        "Array function {} didn't get the right error with empty arguments",
+                        fun.name()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_arguments() -> Result<()> {
+        // `make_array()` is the `[]` literal, an empty list of an untyped element
+        let null_list = DataType::new_list(DataType::Null, true);
+        let make_array = make_array::make_array_udf();
+        assert_eq!(planned_return_type(&make_array, &[])?, null_list);
+        assert_eq!(
+            planned_return_type(&make_array, &[DataType::Null, DataType::Null])?,
+            null_list
+        );
+        assert_eq!(
+            planned_return_type(&make_array, &[DataType::Null, DataType::Int64])?,
+            DataType::List(Arc::new(Field::new("item", DataType::Int64, true)))
+        );
+        Ok(())
+    }
+}
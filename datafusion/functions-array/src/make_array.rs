@@ -73,6 +73,9 @@ impl ScalarUDFImpl for MakeArray {
         &self.signature
     }
 
+    /// Returns a list of the first argument type that is not `Null`. Both
+    /// `make_array()`, which is also the `[]` literal, and `make_array(NULL, NULL)`
+    /// return a `List(Null)`, which other array functions coerce to their types.
     fn return_type(&self, arg_types: &[DataType]) -> datafusion_common::Result<DataType> {
         match arg_types.len() {
             0 => Ok(DataType::List(Arc::new(Field::new(
@@ -24,6 +24,7 @@ use arrow::datatypes::IntervalUnit::MonthDayNano;
 use arrow_schema::DataType::{LargeUtf8, List, Utf8};
 use datafusion_common::exec_err;
 use datafusion_common::plan_err;
+use datafusion_common::utils::{base_type, coerced_fixed_size_list_to_list};
 use datafusion_common::Result;
 use datafusion_expr::expr::ScalarFunction;
 use datafusion_expr::Expr;
@@ -74,7 +75,13 @@ impl ScalarUDFImpl for ArrayToString {
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         use DataType::*;
         Ok(match arg_types[0] {
-            List(_) | LargeList(_) | FixedSizeList(_, _) => Utf8,
+            // large string elements are joined into a large string
+            List(_) | LargeList(_) | FixedSizeList(_, _) => {
+                match base_type(&arg_types[0]) {
+                    LargeUtf8 => LargeUtf8,
+                    _ => Utf8,
+                }
+            }
             _ => {
                 return plan_err!("The array_to_string function can only accept List/LargeList/FixedSizeList.");
             }
@@ -699,7 +706,7 @@ make_udf_function!(
     Flatten,
     flatten,
     array,
-    "flattens an array of arrays into a single array.",
+    "flattens one level of nesting of an array of arrays.",
     flatten_udf
 );
 
@@ -731,26 +738,23 @@ impl ScalarUDFImpl for Flatten {
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         use DataType::*;
-        fn get_base_type(data_type: &DataType) -> Result<DataType> {
-            match data_type {
-                List(field) | FixedSizeList(field, _)
-                    if matches!(field.data_type(), List(_) | FixedSizeList(_, _)) =>
-                {
-                    get_base_type(field.data_type())
-                }
-                LargeList(field) if matches!(field.data_type(), LargeList(_)) => {
-                    get_base_type(field.data_type())
-                }
-                Null | List(_) | LargeList(_) => Ok(data_type.to_owned()),
-                FixedSizeList(field, _) => Ok(List(field.clone())),
-                _ => exec_err!(
-                    "Not reachable, data_type should be List, LargeList or FixedSizeList"
+        // exactly one level of nesting is removed, fixed size lists flatten as lists
+        match coerced_fixed_size_list_to_list(&arg_types[0]) {
+            List(field) => match field.data_type() {
+                List(inner) | LargeList(inner) => Ok(List(inner.clone())),
+                data_type => plan_err!(
+                    "flatten expects an array of arrays, got an array of {data_type}"
                 ),
-            }
+            },
+            LargeList(field) => match field.data_type() {
+                List(inner) | LargeList(inner) => Ok(LargeList(inner.clone())),
+                data_type => plan_err!(
+                    "flatten expects an array of arrays, got an array of {data_type}"
+                ),
+            },
+            Null => Ok(Null),
+            data_type => plan_err!("flatten expects an array of arrays, got {data_type}"),
         }
-
-        let data_type = get_base_type(&arg_types[0])?;
-        Ok(data_type)
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
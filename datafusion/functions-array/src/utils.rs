@@ -19,7 +19,7 @@
 
 use std::sync::Arc;
 
-use arrow::{array::ArrayRef, datatypes::DataType};
+use arrow::{array::ArrayRef, compute::cast, datatypes::DataType};
 use arrow_array::{GenericListArray, OffsetSizeTrait};
 use arrow_buffer::OffsetBuffer;
 use arrow_schema::Field;
@@ -39,6 +39,18 @@ pub(crate) fn check_datatypes(name: &str, args: &[&ArrayRef]) -> Result<()> {
     Ok(())
 }
 
+/// Converts a `FixedSizeList` array into a `List` array with the same child field,
+/// so kernels that only handle `List`/`LargeList` can process it. Other arrays are
+/// returned unchanged.
+pub(crate) fn fixed_size_list_to_list(array: &ArrayRef) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::FixedSizeList(field, _) => {
+            Ok(cast(array, &DataType::List(field.clone()))?)
+        }
+        _ => Ok(array.clone()),
+    }
+}
+
 pub(crate) fn make_scalar_function<F>(inner: F) -> ScalarFunctionImplementation
 where
     F: Fn(&[ArrayRef]) -> Result<ArrayRef> + Sync + Send + 'static,
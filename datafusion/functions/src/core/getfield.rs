@@ -15,8 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use arrow::buffer::NullBuffer;
 use arrow::datatypes::DataType;
-use arrow_array::{Scalar, StringArray};
+use arrow_array::{make_array, new_null_array, Array, Scalar, StringArray, UInt32Array};
 use datafusion_common::cast::{as_map_array, as_struct_array};
 use datafusion_common::{exec_err, ExprSchema, Result, ScalarValue};
 use datafusion_expr::field_util::GetFieldAccessSchema;
@@ -102,28 +103,151 @@ impl ScalarUDFImpl for GetFieldFunc {
             }
         };
         match (array.data_type(), name) {
-                (DataType::Map(_, _), ScalarValue::Utf8(Some(k))) => {
-                    let map_array = as_map_array(array.as_ref())?;
-                    let key_scalar = Scalar::new(StringArray::from(vec![k.clone()]));
-                    let keys = arrow::compute::kernels::cmp::eq(&key_scalar, map_array.keys())?;
-                    let entries = arrow::compute::filter(map_array.entries(), &keys)?;
-                    let entries_struct_array = as_struct_array(entries.as_ref())?;
-                    Ok(ColumnarValue::Array(entries_struct_array.column(1).clone()))
-                }
-                (DataType::Struct(_), ScalarValue::Utf8(Some(k))) => {
-                    let as_struct_array = as_struct_array(&array)?;
-                    match as_struct_array.column_by_name(k) {
-                        None => exec_err!(
-                            "get indexed field {k} not found in struct"),
-                        Some(col) => Ok(ColumnarValue::Array(col.clone()))
+            (DataType::Map(_, _), ScalarValue::Utf8(Some(k))) => {
+                let map_array = as_map_array(array.as_ref())?;
+                let key_scalar = Scalar::new(StringArray::from(vec![k.clone()]));
+                let keys = arrow::compute::kernels::cmp::eq(&key_scalar, map_array.keys())?;
+                // the value of the first entry with the key in each map, or null if the
+                // map is null or has no such key
+                let indices = map_array
+                    .value_offsets()
+                    .windows(2)
+                    .enumerate()
+                    .map(|(row, w)| {
+                        if map_array.is_null(row) {
+                            return None;
+                        }
+                        (w[0] as usize..w[1] as usize)
+                            .find(|&i| keys.is_valid(i) && keys.value(i))
+                            .map(|i| i as u32)
+                    })
+                    .collect::<UInt32Array>();
+                let values = arrow::compute::take(map_array.values(), &indices, None)?;
+                Ok(ColumnarValue::Array(values))
+            }
+            (DataType::Map(_, _), name) if name.is_null() => {
+                let map_array = as_map_array(array.as_ref())?;
+                Ok(ColumnarValue::Array(new_null_array(
+                    map_array.value_type(),
+                    map_array.len(),
+                )))
+            }
+            (DataType::Struct(_), ScalarValue::Utf8(Some(k))) => {
+                let as_struct_array = as_struct_array(&array)?;
+                match as_struct_array.column_by_name(k) {
+                    None => exec_err!("get indexed field {k} not found in struct"),
+                    // the field of a null struct is null, whatever the child value
+                    Some(col) => {
+                        let nulls = NullBuffer::union(as_struct_array.nulls(), col.nulls());
+                        let data = col.to_data().into_builder().nulls(nulls).build()?;
+                        Ok(ColumnarValue::Array(make_array(data)))
                     }
                 }
-                (DataType::Struct(_), name) => exec_err!(
-                    "get indexed field is only possible on struct with utf8 indexes. \
-                             Tried with {name:?} index"),
-                (dt, name) => exec_err!(
-                                "get indexed field is only possible on lists with int64 indexes or struct \
-                                         with utf8 indexes. Tried {dt:?} with {name:?} index"),
             }
+            (DataType::Struct(_), name) => exec_err!(
+                "get indexed field is only possible on struct with utf8 indexes. \
+                         Tried with {name:?} index"
+            ),
+            (dt, name) => exec_err!(
+                "get indexed field is only possible on lists with int64 indexes or struct \
+                         with utf8 indexes. Tried {dt:?} with {name:?} index"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::*;
+    use arrow::datatypes::{Field, Fields};
+
+    use super::*;
+    use datafusion_common::{Result, ScalarValue};
+
+    fn get_field(array: ArrayRef, name: ScalarValue) -> Result<ArrayRef> {
+        GetFieldFunc::new()
+            .invoke(&[ColumnarValue::Array(array), ColumnarValue::Scalar(name)])?
+            .into_array(0)
+    }
+
+    fn name(name: &str) -> ScalarValue {
+        ScalarValue::Utf8(Some(name.to_string()))
+    }
+
+    /// Maps {a: 1, b: 2}, NULL (with a hidden entry), {}, {b: 3}
+    fn maps() -> ArrayRef {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        builder.keys().append_value("a");
+        builder.values().append_value(10);
+        builder.append(false).unwrap();
+        builder.append(true).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        Arc::new(builder.finish())
+    }
+
+    #[test]
+    fn get_field_of_null_struct_is_null() -> Result<()> {
+        // the second struct is null, although its children are not
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let structs = StructArray::new(
+            fields,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), Some(2), None])),
+                Arc::new(StringArray::from(vec!["x", "y", "z"])),
+            ],
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        let structs = Arc::new(structs) as ArrayRef;
+
+        let result = get_field(structs.clone(), name("a"))?;
+        let expected = Int32Array::from(vec![Some(1), None, None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = get_field(structs.slice(1, 2), name("b"))?;
+        let expected = StringArray::from(vec![None, Some("z")]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        Ok(())
+    }
+
+    #[test]
+    fn get_field_of_map_returns_a_value_per_row() -> Result<()> {
+        let result = get_field(maps(), name("a"))?;
+        let expected = Int32Array::from(vec![Some(1), None, None, None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = get_field(maps(), name("b"))?;
+        let expected = Int32Array::from(vec![Some(2), None, None, Some(3)]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = get_field(maps().slice(1, 3), name("b"))?;
+        let expected = Int32Array::from(vec![None, None, Some(3)]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = get_field(maps(), name("missing"))?;
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.null_count(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn get_field_of_map_with_null_key_is_null() -> Result<()> {
+        let result = get_field(maps(), ScalarValue::Utf8(None))?;
+        assert_eq!(result.data_type(), &DataType::Int32);
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.null_count(), 4);
+        Ok(())
     }
 }
@@ -20,7 +20,8 @@ use arrow::compute::is_not_null;
 use arrow::compute::kernels::zip::zip;
 use arrow::datatypes::DataType;
 use datafusion_common::{internal_err, Result};
-use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
 
 #[derive(Debug)]
 pub(super) struct NVLFunc {
@@ -81,6 +82,33 @@ impl ScalarUDFImpl for NVLFunc {
         nvl_func(args)
     }
 
+    /// `nvl(a, b)` is simplified to `a` when `a` can not be null, as long as `b`
+    /// has the same type so the type of the result does not change
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        // without a schema the nullability and types are unknown
+        let first_is_result = match &args[..] {
+            [first, second] => {
+                matches!(info.nullable(first), Ok(false))
+                    && matches!(
+                        (info.get_data_type(first), info.get_data_type(second)),
+                        (Ok(first), Ok(second)) if first == second
+                    )
+            }
+            _ => false,
+        };
+        if first_is_result {
+            Ok(ExprSimplifyResult::Simplified(
+                args.into_iter().next().unwrap(),
+            ))
+        } else {
+            Ok(ExprSimplifyResult::Original(args))
+        }
+    }
+
     fn aliases(&self) -> &[String] {
         &self.aliases
     }
@@ -276,4 +304,44 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn nvl_simplify() -> Result<()> {
+        use arrow::datatypes::{Field, Schema};
+        use datafusion_common::ToDFSchema;
+        use datafusion_expr::execution_props::ExecutionProps;
+        use datafusion_expr::simplify::SimplifyContext;
+        use datafusion_expr::{col, lit};
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("a_non_null", DataType::Int32, false),
+            Field::new("b_non_null", DataType::Int64, false),
+        ])
+        .to_dfschema_ref()?;
+        let props = ExecutionProps::new();
+        let info = SimplifyContext::new(&props).with_schema(schema);
+        let simplify = |args: Vec<Expr>| NVLFunc::new().simplify(args, &info);
+
+        // a first argument that can not be null is the result
+        let result = simplify(vec![col("a_non_null"), col("a")])?;
+        assert!(
+            matches!(result, ExprSimplifyResult::Simplified(expr) if expr == col("a_non_null"))
+        );
+
+        // the second argument is used when the first is nullable
+        let result = simplify(vec![col("a"), col("a_non_null")])?;
+        assert!(matches!(result, ExprSimplifyResult::Original(args) if args.len() == 2));
+
+        // or when it has another type, which the result is coerced to
+        let result = simplify(vec![col("a_non_null"), col("b_non_null")])?;
+        assert!(matches!(result, ExprSimplifyResult::Original(args) if args.len() == 2));
+
+        // without a schema nothing is known about the arguments
+        let info = SimplifyContext::new(&props);
+        let result = NVLFunc::new().simplify(vec![lit(1), col("a")], &info)?;
+        assert!(matches!(result, ExprSimplifyResult::Original(args) if args.len() == 2));
+
+        Ok(())
+    }
 }
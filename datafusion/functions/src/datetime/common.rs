@@ -145,10 +145,17 @@ pub(crate) fn string_to_timestamp_nanos_formatted(
         })
 }
 
+/// Given a function that maps a `&str` to an arrow native type, returns a
+/// `ColumnarValue` where the function is applied to either a `ArrayRef` or
+/// `ScalarValue` depending on the `args`'s variant.
+///
+/// When `safe` is true a value that `op` fails to parse becomes null instead of
+/// returning the error, like the `safe` option of arrow's cast kernel.
 pub(crate) fn handle<'a, O, F, S>(
     args: &'a [ColumnarValue],
     op: F,
     name: &str,
+    safe: bool,
 ) -> Result<ColumnarValue>
 where
     O: ArrowPrimitiveType,
@@ -158,13 +165,22 @@ where
     match &args[0] {
         ColumnarValue::Array(a) => match a.data_type() {
             DataType::Utf8 | DataType::LargeUtf8 => Ok(ColumnarValue::Array(Arc::new(
-                unary_string_to_primitive_function::<i32, O, _>(&[a.as_ref()], op, name)?,
+                unary_string_to_primitive_function::<i32, O, _>(
+                    &[a.as_ref()],
+                    op,
+                    name,
+                    safe,
+                )?,
             ))),
             other => exec_err!("Unsupported data type {other:?} for function {name}"),
         },
         ColumnarValue::Scalar(scalar) => match scalar {
             ScalarValue::Utf8(a) | ScalarValue::LargeUtf8(a) => {
-                let result = a.as_ref().map(|x| (op)(x)).transpose()?;
+                let result = if safe {
+                    a.as_ref().and_then(|x| (op)(x).ok())
+                } else {
+                    a.as_ref().map(|x| (op)(x)).transpose()?
+                };
                 Ok(ColumnarValue::Scalar(S::scalar(result)))
             }
             other => exec_err!("Unsupported data type {other:?} for function {name}"),
@@ -174,12 +190,14 @@ where
 
 // given an function that maps a `&str`, `&str` to an arrow native type,
 // returns a `ColumnarValue` where the function is applied to either a `ArrayRef` or `ScalarValue`
-// depending on the `args`'s variant.
+// depending on the `args`'s variant. When `safe` is true a value that can't be parsed
+// with any of the formats becomes null instead of returning the error.
 pub(crate) fn handle_multiple<'a, O, F, S, M>(
     args: &'a [ColumnarValue],
     op: F,
     op2: M,
     name: &str,
+    safe: bool,
 ) -> Result<ColumnarValue>
 where
     O: ArrowPrimitiveType,
@@ -211,7 +229,9 @@ where
                 }
 
                 Ok(ColumnarValue::Array(Arc::new(
-                    strings_to_primitive_function::<i32, O, _, _>(args, op, op2, name)?,
+                    strings_to_primitive_function::<i32, O, _, _>(
+                        args, op, op2, name, safe,
+                    )?,
                 )))
             }
             other => {
@@ -258,6 +278,8 @@ where
 
                 if let Some(v) = val {
                     v
+                } else if safe {
+                    Ok(ColumnarValue::Scalar(S::scalar(None)))
                 } else {
                     Err(err.unwrap())
                 }
@@ -275,16 +297,18 @@ where
 /// successful result. This function calls the `op` function with the first and second
 /// argument and if not successful continues with first and third, first and fourth,
 /// etc until the result was successful or no more arguments are present.
+/// If `safe` is true a value that no format parses becomes null.
 /// # Errors
 /// This function errors iff:
 /// * the number of arguments is not > 1 or
 /// * the array arguments are not castable to a `GenericStringArray` or
-/// * the function `op` errors for all input
+/// * the function `op` errors for all input and `safe` is false
 pub(crate) fn strings_to_primitive_function<'a, T, O, F, F2>(
     args: &'a [ColumnarValue],
     op: F,
     op2: F2,
     name: &str,
+    safe: bool,
 ) -> Result<PrimitiveArray<O>>
 where
     O: ArrowPrimitiveType,
@@ -353,23 +377,28 @@ where
                 }
             };
 
-            val.transpose()
+            if safe {
+                Ok(val.and_then(Result::ok))
+            } else {
+                val.transpose()
+            }
         })
         .collect()
 }
 
 /// given a function `op` that maps a `&str` to a Result of an arrow native type,
 /// returns a `PrimitiveArray` after the application
-/// of the function to `args[0]`.
+/// of the function to `args[0]`. If `safe` is true a value `op` fails on becomes null.
 /// # Errors
 /// This function errors iff:
 /// * the number of arguments is not 1 or
 /// * the first argument is not castable to a `GenericStringArray` or
-/// * the function `op` errors
+/// * the function `op` errors and `safe` is false
 fn unary_string_to_primitive_function<'a, T, O, F>(
     args: &[&'a dyn Array],
     op: F,
     name: &str,
+    safe: bool,
 ) -> Result<PrimitiveArray<O>>
 where
     O: ArrowPrimitiveType,
@@ -386,6 +415,10 @@ where
 
     let array = as_generic_string_array::<T>(args[0])?;
 
+    if safe {
+        return Ok(array.iter().map(|x| x.and_then(|x| op(x).ok())).collect());
+    }
+
     // first map is the iterator, second is for the `Option<_>`
     array.iter().map(|x| x.map(&op).transpose()).collect()
 }
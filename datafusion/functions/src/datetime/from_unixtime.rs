@@ -19,7 +19,8 @@ use std::any::Any;
 
 use arrow::datatypes::DataType;
 use arrow::datatypes::DataType::{Int64, Timestamp};
-use arrow::datatypes::TimeUnit::Second;
+use arrow::datatypes::TimeUnit;
+use arrow::datatypes::TimeUnit::{Microsecond, Millisecond, Second};
 
 use datafusion_common::{exec_err, Result};
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
@@ -29,6 +30,16 @@ pub(super) struct FromUnixtimeFunc {
     signature: Signature,
 }
 
+#[derive(Debug)]
+pub(super) struct FromUnixtimeMillisFunc {
+    signature: Signature,
+}
+
+#[derive(Debug)]
+pub(super) struct FromUnixtimeMicrosFunc {
+    signature: Signature,
+}
+
 impl FromUnixtimeFunc {
     pub fn new() -> Self {
         Self {
@@ -37,6 +48,22 @@ impl FromUnixtimeFunc {
     }
 }
 
+impl FromUnixtimeMillisFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(1, vec![Int64], Volatility::Immutable),
+        }
+    }
+}
+
+impl FromUnixtimeMicrosFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(1, vec![Int64], Volatility::Immutable),
+        }
+    }
+}
+
 impl ScalarUDFImpl for FromUnixtimeFunc {
     fn as_any(&self) -> &dyn Any {
         self
@@ -55,21 +82,67 @@ impl ScalarUDFImpl for FromUnixtimeFunc {
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        if args.len() != 1 {
-            return exec_err!(
-                "from_unixtime function requires 1 argument, got {}",
-                args.len()
-            );
-        }
+        from_unixtime_impl(args, Second, "from_unixtime")
+    }
+}
+
+impl ScalarUDFImpl for FromUnixtimeMillisFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "from_unixtime_millis"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Timestamp(Millisecond, None))
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        from_unixtime_impl(args, Millisecond, "from_unixtime_millis")
+    }
+}
+
+impl ScalarUDFImpl for FromUnixtimeMicrosFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "from_unixtime_micros"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Timestamp(Microsecond, None))
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        from_unixtime_impl(args, Microsecond, "from_unixtime_micros")
+    }
+}
+
+fn from_unixtime_impl(
+    args: &[ColumnarValue],
+    unit: TimeUnit,
+    name: &str,
+) -> Result<ColumnarValue> {
+    if args.len() != 1 {
+        return exec_err!("{name} function requires 1 argument, got {}", args.len());
+    }
 
-        match args[0].data_type() {
-            Int64 => args[0].cast_to(&Timestamp(Second, None), None),
-            other => {
-                exec_err!(
-                    "Unsupported data type {:?} for function from_unixtime",
-                    other
-                )
-            }
+    match args[0].data_type() {
+        Int64 => args[0].cast_to(&Timestamp(unit, None), None),
+        other => {
+            exec_err!("Unsupported data type {:?} for function {name}", other)
         }
     }
 }
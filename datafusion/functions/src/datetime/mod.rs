@@ -44,10 +44,36 @@ make_udf_function!(
     FROM_UNIXTIME,
     from_unixtime
 );
+make_udf_function!(
+    from_unixtime::FromUnixtimeMillisFunc,
+    FROM_UNIXTIME_MILLIS,
+    from_unixtime_millis
+);
+make_udf_function!(
+    from_unixtime::FromUnixtimeMicrosFunc,
+    FROM_UNIXTIME_MICROS,
+    from_unixtime_micros
+);
 make_udf_function!(now::NowFunc, NOW, now);
 make_udf_function!(to_date::ToDateFunc, TO_DATE, to_date);
+make_udf_function!(to_date::TryToDateFunc, TRY_TO_DATE, try_to_date);
 make_udf_function!(to_unixtime::ToUnixtimeFunc, TO_UNIXTIME, to_unixtime);
+make_udf_function!(
+    to_unixtime::ToUnixtimeMillisFunc,
+    TO_UNIXTIME_MILLIS,
+    to_unixtime_millis
+);
+make_udf_function!(
+    to_unixtime::ToUnixtimeMicrosFunc,
+    TO_UNIXTIME_MICROS,
+    to_unixtime_micros
+);
 make_udf_function!(to_timestamp::ToTimestampFunc, TO_TIMESTAMP, to_timestamp);
+make_udf_function!(
+    to_timestamp::TryToTimestampFunc,
+    TRY_TO_TIMESTAMP,
+    try_to_timestamp
+);
 make_udf_function!(
     to_timestamp::ToTimestampSecondsFunc,
     TO_TIMESTAMP_SECONDS,
@@ -105,6 +131,16 @@ pub mod expr_fn {
         super::from_unixtime().call(vec![unixtime])
     }
 
+    #[doc = "converts milliseconds since the unix epoch to a `Timestamp(Milliseconds, None)`"]
+    pub fn from_unixtime_millis(unixtime: Expr) -> Expr {
+        super::from_unixtime_millis().call(vec![unixtime])
+    }
+
+    #[doc = "converts microseconds since the unix epoch to a `Timestamp(Microseconds, None)`"]
+    pub fn from_unixtime_micros(unixtime: Expr) -> Expr {
+        super::from_unixtime_micros().call(vec![unixtime])
+    }
+
     #[doc = "returns the current timestamp in nanoseconds, using the same value for all instances of now() in same statement"]
     pub fn now() -> Expr {
         super::now().call(vec![])
@@ -160,16 +196,36 @@ pub mod expr_fn {
         super::to_date().call(args)
     }
 
+    #[doc = "converts a string and optional formats to a `Date32`, returning null for strings that can't be parsed"]
+    pub fn try_to_date(args: Vec<Expr>) -> Expr {
+        super::try_to_date().call(args)
+    }
+
     #[doc = "converts a string and optional formats to a Unixtime"]
     pub fn to_unixtime(args: Vec<Expr>) -> Expr {
         super::to_unixtime().call(args)
     }
 
+    #[doc = "converts a string and optional formats to milliseconds since the unix epoch"]
+    pub fn to_unixtime_millis(args: Vec<Expr>) -> Expr {
+        super::to_unixtime_millis().call(args)
+    }
+
+    #[doc = "converts a string and optional formats to microseconds since the unix epoch"]
+    pub fn to_unixtime_micros(args: Vec<Expr>) -> Expr {
+        super::to_unixtime_micros().call(args)
+    }
+
     #[doc = "converts a string and optional formats to a `Timestamp(Nanoseconds, None)`"]
     pub fn to_timestamp(args: Vec<Expr>) -> Expr {
         super::to_timestamp().call(args)
     }
 
+    #[doc = "converts a string and optional formats to a `Timestamp(Nanoseconds, None)`, returning null for strings that can't be parsed"]
+    pub fn try_to_timestamp(args: Vec<Expr>) -> Expr {
+        super::try_to_timestamp().call(args)
+    }
+
     #[doc = "converts a string and optional formats to a `Timestamp(Seconds, None)`"]
     pub fn to_timestamp_seconds(args: Vec<Expr>) -> Expr {
         super::to_timestamp_seconds().call(args)
@@ -200,10 +256,16 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         date_part(),
         date_trunc(),
         from_unixtime(),
+        from_unixtime_millis(),
+        from_unixtime_micros(),
         now(),
         to_date(),
+        try_to_date(),
         to_unixtime(),
+        to_unixtime_millis(),
+        to_unixtime_micros(),
         to_timestamp(),
+        try_to_timestamp(),
         to_timestamp_seconds(),
         to_timestamp_millis(),
         to_timestamp_micros(),
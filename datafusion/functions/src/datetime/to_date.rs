@@ -36,37 +36,17 @@ impl ToDateFunc {
             signature: Signature::variadic_any(Volatility::Immutable),
         }
     }
+}
 
-    fn to_date(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        match args.len() {
-            1 => handle::<Date32Type, _, Date32Type>(
-                args,
-                |s| {
-                    string_to_timestamp_nanos_shim(s)
-                        .map(|n| n / (1_000_000 * 24 * 60 * 60 * 1_000))
-                        .and_then(|v| {
-                            v.try_into().map_err(|_| {
-                                internal_datafusion_err!("Unable to cast to Date32 for converting from i64 to i32 failed")
-                            })
-                        })
-                },
-                "to_date",
-            ),
-            n if n >= 2 => handle_multiple::<Date32Type, _, Date32Type, _>(
-                args,
-                |s, format| {
-                    string_to_timestamp_nanos_formatted(s, format)
-                        .map(|n| n / (1_000_000 * 24 * 60 * 60 * 1_000))
-                        .and_then(|v| {
-                            v.try_into().map_err(|_| {
-                                internal_datafusion_err!("Unable to cast to Date32 for converting from i64 to i32 failed")
-                            })
-                        })
-                },
-                |n| n,
-                "to_date",
-            ),
-            _ => exec_err!("Unsupported 0 argument count for function to_date"),
+#[derive(Debug)]
+pub(super) struct TryToDateFunc {
+    signature: Signature,
+}
+
+impl TryToDateFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
         }
     }
 }
@@ -89,26 +69,93 @@ impl ScalarUDFImpl for ToDateFunc {
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        if args.is_empty() {
-            return exec_err!("to_date function requires 1 or more arguments, got 0");
-        }
+        to_date_invoke(args, "to_date", false)
+    }
+}
 
-        // validate that any args after the first one are Utf8
-        if args.len() > 1 {
-            validate_data_types(args, "to_date")?;
-        }
+impl ScalarUDFImpl for TryToDateFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 
-        match args[0].data_type() {
-            DataType::Int32
-            | DataType::Int64
-            | DataType::Null
-            | DataType::Float64
-            | DataType::Date32
-            | DataType::Date64 => args[0].cast_to(&DataType::Date32, None),
-            DataType::Utf8 => self.to_date(args),
-            other => {
-                exec_err!("Unsupported data type {:?} for function to_date", other)
-            }
+    fn name(&self) -> &str {
+        "try_to_date"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Date32)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        to_date_invoke(args, "try_to_date", true)
+    }
+}
+
+/// Implements `to_date` and, when `safe` is true, `try_to_date` which returns null
+/// for strings that can't be parsed instead of an error
+fn to_date_invoke(
+    args: &[ColumnarValue],
+    name: &str,
+    safe: bool,
+) -> Result<ColumnarValue> {
+    if args.is_empty() {
+        return exec_err!("{name} function requires 1 or more arguments, got 0");
+    }
+
+    // validate that any args after the first one are Utf8
+    if args.len() > 1 {
+        validate_data_types(args, name)?;
+    }
+
+    match args[0].data_type() {
+        DataType::Int32
+        | DataType::Int64
+        | DataType::Null
+        | DataType::Float64
+        | DataType::Date32
+        | DataType::Date64 => args[0].cast_to(&DataType::Date32, None),
+        DataType::Utf8 => to_date_impl(args, name, safe),
+        other => {
+            exec_err!("Unsupported data type {:?} for function {name}", other)
         }
     }
 }
+
+fn to_date_impl(args: &[ColumnarValue], name: &str, safe: bool) -> Result<ColumnarValue> {
+    match args.len() {
+        1 => handle::<Date32Type, _, Date32Type>(
+            args,
+            |s| {
+                string_to_timestamp_nanos_shim(s)
+                    .map(|n| n / (1_000_000 * 24 * 60 * 60 * 1_000))
+                    .and_then(|v| {
+                        v.try_into().map_err(|_| {
+                            internal_datafusion_err!("Unable to cast to Date32 for converting from i64 to i32 failed")
+                        })
+                    })
+            },
+            name,
+            safe,
+        ),
+        n if n >= 2 => handle_multiple::<Date32Type, _, Date32Type, _>(
+            args,
+            |s, format| {
+                string_to_timestamp_nanos_formatted(s, format)
+                    .map(|n| n / (1_000_000 * 24 * 60 * 60 * 1_000))
+                    .and_then(|v| {
+                        v.try_into().map_err(|_| {
+                            internal_datafusion_err!("Unable to cast to Date32 for converting from i64 to i32 failed")
+                        })
+                    })
+            },
+            |n| n,
+            name,
+            safe,
+        ),
+        _ => exec_err!("Unsupported 0 argument count for function {name}"),
+    }
+}
@@ -20,11 +20,11 @@ use std::any::Any;
 use arrow::datatypes::DataType::Timestamp;
 use arrow::datatypes::TimeUnit::{Microsecond, Millisecond, Nanosecond, Second};
 use arrow::datatypes::{
-    ArrowTimestampType, DataType, TimestampMicrosecondType, TimestampMillisecondType,
-    TimestampNanosecondType, TimestampSecondType,
+    ArrowTimestampType, DataType, TimeUnit, TimestampMicrosecondType,
+    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType,
 };
 
-use datafusion_common::{exec_err, Result, ScalarType};
+use datafusion_common::{exec_err, Result, ScalarType, ScalarValue};
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 
 use crate::datetime::common::*;
@@ -34,6 +34,11 @@ pub(super) struct ToTimestampFunc {
     signature: Signature,
 }
 
+#[derive(Debug)]
+pub(super) struct TryToTimestampFunc {
+    signature: Signature,
+}
+
 #[derive(Debug)]
 pub(super) struct ToTimestampSecondsFunc {
     signature: Signature,
@@ -62,6 +67,14 @@ impl ToTimestampFunc {
     }
 }
 
+impl TryToTimestampFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
 impl ToTimestampSecondsFunc {
     pub fn new() -> Self {
         Self {
@@ -97,6 +110,8 @@ impl ToTimestampNanosFunc {
 /// to_timestamp SQL function
 ///
 /// Note: `to_timestamp` returns `Timestamp(Nanosecond)` though its arguments are interpreted as **seconds**.
+/// Integer input can instead be interpreted in another unit by passing `'millis'`, `'micros'`
+/// or `'nanos'` as the second argument.
 /// The supported range for integer input is between `-9223372037` and `9223372036`.
 /// Supported range for string input is between `1677-09-21T00:12:44.0` and `2262-04-11T23:47:16.0`.
 /// Please use `to_timestamp_seconds` for the input outside of supported bounds.
@@ -118,35 +133,96 @@ impl ScalarUDFImpl for ToTimestampFunc {
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        if args.is_empty() {
-            return exec_err!(
-                "to_timestamp function requires 1 or more arguments, got {}",
-                args.len()
-            );
-        }
+        to_timestamp_invoke(args, "to_timestamp", false)
+    }
+}
 
-        // validate that any args after the first one are Utf8
-        if args.len() > 1 {
-            validate_data_types(args, "to_timestamp")?;
+/// try_to_timestamp SQL function
+///
+/// Like `to_timestamp` but returns null for strings that can't be parsed instead of
+/// an error.
+impl ScalarUDFImpl for TryToTimestampFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "try_to_timestamp"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Timestamp(Nanosecond, None))
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        to_timestamp_invoke(args, "try_to_timestamp", true)
+    }
+}
+
+/// Implements `to_timestamp` and, when `safe` is true, `try_to_timestamp`
+fn to_timestamp_invoke(
+    args: &[ColumnarValue],
+    name: &str,
+    safe: bool,
+) -> Result<ColumnarValue> {
+    if args.is_empty() {
+        return exec_err!(
+            "{name} function requires 1 or more arguments, got {}",
+            args.len()
+        );
+    }
+
+    // validate that any args after the first one are Utf8
+    if args.len() > 1 {
+        validate_data_types(args, name)?;
+    }
+
+    match args[0].data_type() {
+        DataType::Int32 | DataType::Int64 => args[0]
+            .cast_to(&Timestamp(integer_input_unit(args, name)?, None), None)?
+            .cast_to(&Timestamp(Nanosecond, None), None),
+        DataType::Null | DataType::Float64 | Timestamp(_, None) => {
+            args[0].cast_to(&Timestamp(Nanosecond, None), None)
         }
+        DataType::Utf8 => to_timestamp_impl::<TimestampNanosecondType>(args, name, safe),
+        other => {
+            exec_err!("Unsupported data type {:?} for function {name}", other)
+        }
+    }
+}
 
-        match args[0].data_type() {
-            DataType::Int32 | DataType::Int64 => args[0]
-                .cast_to(&Timestamp(Second, None), None)?
-                .cast_to(&Timestamp(Nanosecond, None), None),
-            DataType::Null | DataType::Float64 | Timestamp(_, None) => {
-                args[0].cast_to(&Timestamp(Nanosecond, None), None)
-            }
-            DataType::Utf8 => {
-                to_timestamp_impl::<TimestampNanosecondType>(args, "to_timestamp")
-            }
-            other => {
-                exec_err!(
-                    "Unsupported data type {:?} for function to_timestamp",
-                    other
-                )
-            }
+/// Returns the unit integer input to `to_timestamp` is in: seconds, unless a
+/// constant unit name is passed as the second and last argument
+fn integer_input_unit(args: &[ColumnarValue], name: &str) -> Result<TimeUnit> {
+    let unit = match args {
+        [_] => return Ok(Second),
+        [_, ColumnarValue::Scalar(
+            ScalarValue::Utf8(Some(unit)) | ScalarValue::LargeUtf8(Some(unit)),
+        )] => unit,
+        [_, _] => {
+            return exec_err!(
+                "{name} expects the unit of integer input to be a constant string"
+            )
         }
+        _ => {
+            return exec_err!(
+                "{name} expects integer input to be followed by at most a unit, got {} arguments",
+                args.len()
+            )
+        }
+    };
+    match unit.to_lowercase().as_str() {
+        "seconds" => Ok(Second),
+        "millis" => Ok(Millisecond),
+        "micros" => Ok(Microsecond),
+        "nanos" => Ok(Nanosecond),
+        _ => exec_err!(
+            "{name} unit must be one of 'seconds', 'millis', 'micros' or 'nanos', got '{unit}'"
+        ),
     }
 }
 
@@ -184,9 +260,11 @@ impl ScalarUDFImpl for ToTimestampSecondsFunc {
             DataType::Null | DataType::Int32 | DataType::Int64 | Timestamp(_, None) => {
                 args[0].cast_to(&Timestamp(Second, None), None)
             }
-            DataType::Utf8 => {
-                to_timestamp_impl::<TimestampSecondType>(args, "to_timestamp_seconds")
-            }
+            DataType::Utf8 => to_timestamp_impl::<TimestampSecondType>(
+                args,
+                "to_timestamp_seconds",
+                false,
+            ),
             other => {
                 exec_err!(
                     "Unsupported data type {:?} for function to_timestamp_seconds",
@@ -231,9 +309,11 @@ impl ScalarUDFImpl for ToTimestampMillisFunc {
             DataType::Null | DataType::Int32 | DataType::Int64 | Timestamp(_, None) => {
                 args[0].cast_to(&Timestamp(Millisecond, None), None)
             }
-            DataType::Utf8 => {
-                to_timestamp_impl::<TimestampMillisecondType>(args, "to_timestamp_millis")
-            }
+            DataType::Utf8 => to_timestamp_impl::<TimestampMillisecondType>(
+                args,
+                "to_timestamp_millis",
+                false,
+            ),
             other => {
                 exec_err!(
                     "Unsupported data type {:?} for function to_timestamp_millis",
@@ -278,9 +358,11 @@ impl ScalarUDFImpl for ToTimestampMicrosFunc {
             DataType::Null | DataType::Int32 | DataType::Int64 | Timestamp(_, None) => {
                 args[0].cast_to(&Timestamp(Microsecond, None), None)
             }
-            DataType::Utf8 => {
-                to_timestamp_impl::<TimestampMicrosecondType>(args, "to_timestamp_micros")
-            }
+            DataType::Utf8 => to_timestamp_impl::<TimestampMicrosecondType>(
+                args,
+                "to_timestamp_micros",
+                false,
+            ),
             other => {
                 exec_err!(
                     "Unsupported data type {:?} for function to_timestamp_micros",
@@ -325,9 +407,11 @@ impl ScalarUDFImpl for ToTimestampNanosFunc {
             DataType::Null | DataType::Int32 | DataType::Int64 | Timestamp(_, None) => {
                 args[0].cast_to(&Timestamp(Nanosecond, None), None)
             }
-            DataType::Utf8 => {
-                to_timestamp_impl::<TimestampNanosecondType>(args, "to_timestamp_nanos")
-            }
+            DataType::Utf8 => to_timestamp_impl::<TimestampNanosecondType>(
+                args,
+                "to_timestamp_nanos",
+                false,
+            ),
             other => {
                 exec_err!(
                     "Unsupported data type {:?} for function to_timestamp_nanos",
@@ -341,6 +425,7 @@ impl ScalarUDFImpl for ToTimestampNanosFunc {
 fn to_timestamp_impl<T: ArrowTimestampType + ScalarType<i64>>(
     args: &[ColumnarValue],
     name: &str,
+    safe: bool,
 ) -> Result<ColumnarValue> {
     let factor = match T::UNIT {
         Second => 1_000_000_000,
@@ -354,12 +439,14 @@ fn to_timestamp_impl<T: ArrowTimestampType + ScalarType<i64>>(
             args,
             |s| string_to_timestamp_nanos_shim(s).map(|n| n / factor),
             name,
+            safe,
         ),
         n if n >= 2 => handle_multiple::<T, _, T, _>(
             args,
             string_to_timestamp_nanos_formatted,
             |n| n / factor,
             name,
+            safe,
         ),
         _ => exec_err!("Unsupported 0 argument count for function {name}"),
     }
@@ -369,7 +456,7 @@ fn to_timestamp_impl<T: ArrowTimestampType + ScalarType<i64>>(
 mod tests {
     use std::sync::Arc;
 
-    use arrow::array::{ArrayRef, Int64Array, StringBuilder};
+    use arrow::array::{ArrayRef, Int64Array, StringArray, StringBuilder};
     use arrow::datatypes::TimeUnit;
     use arrow_array::types::Int64Type;
     use arrow_array::{
@@ -384,27 +471,27 @@ mod tests {
     use super::*;
 
     fn to_timestamp(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        to_timestamp_impl::<TimestampNanosecondType>(args, "to_timestamp")
+        to_timestamp_impl::<TimestampNanosecondType>(args, "to_timestamp", false)
     }
 
     /// to_timestamp_millis SQL function
     fn to_timestamp_millis(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        to_timestamp_impl::<TimestampMillisecondType>(args, "to_timestamp_millis")
+        to_timestamp_impl::<TimestampMillisecondType>(args, "to_timestamp_millis", false)
     }
 
     /// to_timestamp_micros SQL function
     fn to_timestamp_micros(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        to_timestamp_impl::<TimestampMicrosecondType>(args, "to_timestamp_micros")
+        to_timestamp_impl::<TimestampMicrosecondType>(args, "to_timestamp_micros", false)
     }
 
     /// to_timestamp_nanos SQL function
     fn to_timestamp_nanos(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        to_timestamp_impl::<TimestampNanosecondType>(args, "to_timestamp_nanos")
+        to_timestamp_impl::<TimestampNanosecondType>(args, "to_timestamp_nanos", false)
     }
 
     /// to_timestamp_seconds SQL function
     fn to_timestamp_seconds(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        to_timestamp_impl::<TimestampSecondType>(args, "to_timestamp_seconds")
+        to_timestamp_impl::<TimestampSecondType>(args, "to_timestamp_seconds", false)
     }
 
     #[test]
@@ -612,6 +699,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn try_to_timestamp_with_unparseable_data() -> Result<()> {
+        let string_array = ColumnarValue::Array(Arc::new(StringArray::from(vec![
+            Some("2020-09-08T13:42:29.190855Z"),
+            Some("2020-09-08 - 13:42:29.19085Z"),
+            None,
+        ])) as ArrayRef);
+        let expected =
+            TimestampNanosecondArray::from(vec![Some(1599572549190855000), None, None]);
+
+        let result =
+            to_timestamp_invoke(&[string_array.clone()], "try_to_timestamp", true)?
+                .into_array(3)?;
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        // with formats only the strings that no format matches become null
+        let formats = [
+            string_array,
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%H:%M:%S".to_string()))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%+".to_string()))),
+        ];
+        let result =
+            to_timestamp_invoke(&formats, "try_to_timestamp", true)?.into_array(3)?;
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let scalar = ColumnarValue::Scalar(ScalarValue::Utf8(Some("bad".to_string())));
+        match to_timestamp_invoke(&[scalar], "try_to_timestamp", true)? {
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(None, None)) => {}
+            other => panic!("Expected a null timestamp but got {other:?}"),
+        }
+        Ok(())
+    }
+
     #[test]
     fn string_to_timestamp_formatted() {
         // Explicit timezone
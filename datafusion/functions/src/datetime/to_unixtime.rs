@@ -16,20 +16,33 @@
 // under the License.
 
 use std::any::Any;
+use std::sync::Arc;
 
-use arrow::datatypes::{DataType, TimeUnit};
+use arrow::array::{ArrayRef, AsArray};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Int64Type, TimeUnit};
 
 use crate::datetime::common::*;
-use datafusion_common::{exec_err, Result};
+use datafusion_common::{exec_err, Result, ScalarValue};
 use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
 
-use super::to_timestamp::ToTimestampSecondsFunc;
+use super::to_timestamp::ToTimestampNanosFunc;
 
 #[derive(Debug)]
 pub(super) struct ToUnixtimeFunc {
     signature: Signature,
 }
 
+#[derive(Debug)]
+pub(super) struct ToUnixtimeMillisFunc {
+    signature: Signature,
+}
+
+#[derive(Debug)]
+pub(super) struct ToUnixtimeMicrosFunc {
+    signature: Signature,
+}
+
 impl ToUnixtimeFunc {
     pub fn new() -> Self {
         Self {
@@ -38,6 +51,22 @@ impl ToUnixtimeFunc {
     }
 }
 
+impl ToUnixtimeMillisFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+impl ToUnixtimeMicrosFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
 impl ScalarUDFImpl for ToUnixtimeFunc {
     fn as_any(&self) -> &dyn Any {
         self
@@ -56,28 +85,137 @@ impl ScalarUDFImpl for ToUnixtimeFunc {
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
-        if args.is_empty() {
-            return exec_err!("to_unixtime function requires 1 or more arguments, got 0");
-        }
+        to_unixtime_impl(args, TimeUnit::Second, "to_unixtime")
+    }
+}
+
+impl ScalarUDFImpl for ToUnixtimeMillisFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "to_unixtime_millis"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
 
-        // validate that any args after the first one are Utf8
-        if args.len() > 1 {
-            validate_data_types(args, "to_unixtime")?;
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        to_unixtime_impl(args, TimeUnit::Millisecond, "to_unixtime_millis")
+    }
+}
+
+impl ScalarUDFImpl for ToUnixtimeMicrosFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "to_unixtime_micros"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        to_unixtime_impl(args, TimeUnit::Microsecond, "to_unixtime_micros")
+    }
+}
+
+/// Converts the first argument to the number of `unit`s since the unix epoch.
+///
+/// Numeric arguments are assumed to already be expressed in `unit`. Dates,
+/// timestamps and strings are rounded toward negative infinity, so pre-epoch
+/// values with a fractional part map to the preceding whole `unit`.
+fn to_unixtime_impl(
+    args: &[ColumnarValue],
+    unit: TimeUnit,
+    name: &str,
+) -> Result<ColumnarValue> {
+    if args.is_empty() {
+        return exec_err!("{name} function requires 1 or more arguments, got 0");
+    }
+
+    // validate that any args after the first one are Utf8
+    if args.len() > 1 {
+        validate_data_types(args, name)?;
+    }
+
+    match args[0].data_type() {
+        DataType::Int32 | DataType::Int64 | DataType::Null | DataType::Float64 => {
+            args[0].cast_to(&DataType::Int64, None)
+        }
+        DataType::Date32 => timestamp_to_unixtime(
+            &args[0].cast_to(&DataType::Timestamp(TimeUnit::Second, None), None)?,
+            unit,
+        ),
+        DataType::Date64 => timestamp_to_unixtime(
+            &args[0].cast_to(&DataType::Timestamp(TimeUnit::Millisecond, None), None)?,
+            unit,
+        ),
+        DataType::Timestamp(_, None) => timestamp_to_unixtime(&args[0], unit),
+        DataType::Utf8 => {
+            timestamp_to_unixtime(&ToTimestampNanosFunc::new().invoke(args)?, unit)
         }
+        other => {
+            exec_err!("Unsupported data type {:?} for function {name}", other)
+        }
+    }
+}
 
-        match args[0].data_type() {
-            DataType::Int32 | DataType::Int64 | DataType::Null | DataType::Float64 => {
-                args[0].cast_to(&DataType::Int64, None)
-            }
-            DataType::Date64 | DataType::Date32 | DataType::Timestamp(_, None) => args[0]
-                .cast_to(&DataType::Timestamp(TimeUnit::Second, None), None)?
-                .cast_to(&DataType::Int64, None),
-            DataType::Utf8 => ToTimestampSecondsFunc::new()
-                .invoke(args)?
-                .cast_to(&DataType::Int64, None),
-            other => {
-                exec_err!("Unsupported data type {:?} for function to_unixtime", other)
-            }
+/// Rescales a `Timestamp(_, None)` value to an `Int64` count of `unit`s,
+/// flooring rather than truncating when the source unit is finer.
+fn timestamp_to_unixtime(value: &ColumnarValue, unit: TimeUnit) -> Result<ColumnarValue> {
+    match value {
+        ColumnarValue::Array(array) => {
+            Ok(ColumnarValue::Array(rescale_timestamps(array, &unit)?))
+        }
+        ColumnarValue::Scalar(scalar) => {
+            let array = rescale_timestamps(&scalar.to_array()?, &unit)?;
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &array, 0,
+            )?))
         }
     }
 }
+
+fn rescale_timestamps(array: &ArrayRef, unit: &TimeUnit) -> Result<ArrayRef> {
+    let DataType::Timestamp(from_unit, _) = array.data_type() else {
+        return exec_err!("Expected a timestamp array, got {:?}", array.data_type());
+    };
+    let from_scale = units_per_second(from_unit);
+    let to_scale = units_per_second(unit);
+
+    if from_scale <= to_scale {
+        let rescaled = cast(array, &DataType::Timestamp(unit.clone(), None))?;
+        return Ok(cast(&rescaled, &DataType::Int64)?);
+    }
+
+    let divisor = from_scale / to_scale;
+    let values = cast(array, &DataType::Int64)?;
+    Ok(Arc::new(
+        values
+            .as_primitive::<Int64Type>()
+            .unary::<_, Int64Type>(|v| v.div_euclid(divisor)),
+    ))
+}
+
+fn units_per_second(unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
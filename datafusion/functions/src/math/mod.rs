@@ -19,10 +19,13 @@
 
 mod abs;
 mod nans;
+mod try_root;
 
 // Create UDFs
 make_udf_function!(nans::IsNanFunc, ISNAN, isnan);
 make_udf_function!(abs::AbsFunc, ABS, abs);
+make_udf_function!(try_root::TrySqrtFunc, TRY_SQRT, try_sqrt);
+make_udf_function!(try_root::TryCbrtFunc, TRY_CBRT, try_cbrt);
 
 make_math_unary_udf!(TanhFunc, TANH, tanh, tanh);
 make_math_unary_udf!(AcosFunc, ACOS, acos, acos);
@@ -48,5 +51,15 @@ export_functions!(
         "returns the arc sine or inverse sine of a number"
     ),
     (tan, num, "returns the tangent of a number"),
-    (tanh, num, "returns the hyperbolic tangent of a number")
+    (tanh, num, "returns the hyperbolic tangent of a number"),
+    (
+        try_sqrt,
+        num,
+        "returns the square root of an integer if it is a perfect square, otherwise NULL"
+    ),
+    (
+        try_cbrt,
+        num,
+        "returns the cube root of an integer if it is a perfect cube, otherwise NULL"
+    )
 );
@@ -0,0 +1,172 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Math functions: `try_sqrt()` and `try_cbrt()`.
+//!
+//! Unlike `sqrt` and `cbrt`, which always return a float, these functions take
+//! an integer and return an integer: the exact root if the input is a perfect
+//! square / cube, and NULL otherwise.
+
+use arrow::array::{ArrayRef, Int64Array};
+use arrow::datatypes::DataType;
+use datafusion_common::{exec_err, DataFusionError, Result};
+use datafusion_expr::ColumnarValue;
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use std::any::Any;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub(super) struct TrySqrtFunc {
+    signature: Signature,
+}
+
+impl TrySqrtFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![DataType::Int64],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for TrySqrtFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "try_sqrt"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        invoke_exact_root(args, self.name(), exact_sqrt)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct TryCbrtFunc {
+    signature: Signature,
+}
+
+impl TryCbrtFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![DataType::Int64],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for TryCbrtFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "try_cbrt"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        invoke_exact_root(args, self.name(), exact_cbrt)
+    }
+}
+
+fn invoke_exact_root(
+    args: &[ColumnarValue],
+    name: &str,
+    root: fn(i64) -> Option<i64>,
+) -> Result<ColumnarValue> {
+    if args.len() != 1 {
+        return exec_err!("{name} expects 1 argument, got {}", args.len());
+    }
+    let args = ColumnarValue::values_to_arrays(args)?;
+    let arg = downcast_arg!(&args[0], name, Int64Array);
+    let arr: ArrayRef =
+        Arc::new(arg.iter().map(|v| v.and_then(root)).collect::<Int64Array>());
+    Ok(ColumnarValue::Array(arr))
+}
+
+/// Returns the square root of `value` if it is a perfect square
+fn exact_sqrt(value: i64) -> Option<i64> {
+    if value < 0 {
+        return None;
+    }
+    // the float estimate is within 1 of the integer root, correct it exactly
+    let estimate = (value as f64).sqrt() as i64;
+    (estimate - 1..=estimate + 1)
+        .find(|r| *r >= 0 && (*r as i128) * (*r as i128) == value as i128)
+}
+
+/// Returns the cube root of `value` if it is a perfect cube
+fn exact_cbrt(value: i64) -> Option<i64> {
+    // the float estimate is within 1 of the integer root, correct it exactly
+    let estimate = (value as f64).cbrt().round() as i64;
+    (estimate - 1..=estimate + 1).find(|r| {
+        let r = *r as i128;
+        r * r * r == value as i128
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_sqrt() {
+        assert_eq!(exact_sqrt(0), Some(0));
+        assert_eq!(exact_sqrt(1), Some(1));
+        assert_eq!(exact_sqrt(16), Some(4));
+        assert_eq!(exact_sqrt(15), None);
+        assert_eq!(exact_sqrt(-16), None);
+        assert_eq!(exact_sqrt(3037000499 * 3037000499), Some(3037000499));
+        assert_eq!(exact_sqrt(3037000499 * 3037000499 - 1), None);
+        assert_eq!(exact_sqrt(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_exact_cbrt() {
+        assert_eq!(exact_cbrt(0), Some(0));
+        assert_eq!(exact_cbrt(27), Some(3));
+        assert_eq!(exact_cbrt(-27), Some(-3));
+        assert_eq!(exact_cbrt(26), None);
+        assert_eq!(exact_cbrt(2097151 * 2097151 * 2097151), Some(2097151));
+        assert_eq!(exact_cbrt(2097151 * 2097151 * 2097151 + 1), None);
+        // i64::MIN is -2^63 = (-2^21)^3
+        assert_eq!(exact_cbrt(i64::MIN), Some(-2097152));
+        assert_eq!(exact_cbrt(i64::MAX), None);
+    }
+}
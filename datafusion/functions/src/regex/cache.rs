@@ -0,0 +1,220 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Bounded cache of compiled regular expressions shared by the regex functions
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+use arrow::error::ArrowError;
+use datafusion_common::{arrow_datafusion_err, DataFusionError};
+use regex::Regex;
+
+/// Maximum number of compiled regexes kept by the shared cache
+const REGEX_CACHE_CAPACITY: usize = 512;
+
+/// A least recently used cache of compiled regexes, keyed by the pattern with
+/// any flags embedded in it (see [`pattern_with_flags`])
+#[derive(Debug)]
+struct RegexCache {
+    capacity: usize,
+    /// The compiled regexes, with the tick at which each was last used
+    entries: HashMap<String, (Arc<Regex>, u64)>,
+    tick: u64,
+}
+
+impl RegexCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            tick: 0,
+        }
+    }
+
+    fn get(&mut self, pattern: &str) -> Option<Arc<Regex>> {
+        self.tick += 1;
+        let (regex, last_used) = self.entries.get_mut(pattern)?;
+        *last_used = self.tick;
+        Some(Arc::clone(regex))
+    }
+
+    /// Inserts `regex`, evicting the least recently used regex if the cache is full
+    fn insert(&mut self, pattern: &str, regex: Arc<Regex>) {
+        self.tick += 1;
+        if !self.entries.contains_key(pattern) && self.entries.len() >= self.capacity {
+            // a linear scan is cheap next to the compilation that led here
+            let least_recently_used = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(pattern, _)| pattern.clone());
+            if let Some(pattern) = least_recently_used {
+                self.entries.remove(&pattern);
+            }
+        }
+        self.entries.insert(pattern.to_string(), (regex, self.tick));
+    }
+}
+
+fn regex_cache() -> &'static Mutex<RegexCache> {
+    static REGEX_CACHE: OnceLock<Mutex<RegexCache>> = OnceLock::new();
+    REGEX_CACHE.get_or_init(|| Mutex::new(RegexCache::new(REGEX_CACHE_CAPACITY)))
+}
+
+/// Returns the regex for `pattern` from `cache`, compiling it with `compile`
+/// on a miss. The lock is not held while compiling, so other threads are not
+/// blocked on it.
+fn get_or_compile(
+    cache: &Mutex<RegexCache>,
+    pattern: &str,
+    compile: impl FnOnce(&str) -> Result<Regex, regex::Error>,
+) -> Result<Arc<Regex>, regex::Error> {
+    // the cache is left consistent by every operation, so a poisoned lock is safe to use
+    let lock = || cache.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(regex) = lock().get(pattern) {
+        return Ok(regex);
+    }
+    let regex = Arc::new(compile(pattern)?);
+    lock().insert(pattern, Arc::clone(&regex));
+    Ok(regex)
+}
+
+/// Returns the compiled regex for `pattern` from the cache shared by all the
+/// regex functions, compiling it if it is not cached
+pub(crate) fn cached_regex(pattern: &str) -> Result<Arc<Regex>, regex::Error> {
+    get_or_compile(regex_cache(), pattern, Regex::new)
+}
+
+/// Embeds `flags` into `pattern` the way the regex functions have always
+/// combined them, e.g. `i` and `abc` become `(?i)abc`
+pub(crate) fn pattern_with_flags<'a>(
+    pattern: &'a str,
+    flags: Option<&str>,
+) -> Cow<'a, str> {
+    match flags {
+        Some(flags) => Cow::Owned(format!("(?{flags}){pattern}")),
+        None => Cow::Borrowed(pattern),
+    }
+}
+
+/// The error returned by `regexp_like` and `regexp_match` for invalid patterns
+pub(crate) fn compile_error(err: regex::Error) -> DataFusionError {
+    arrow_datafusion_err!(ArrowError::ComputeError(format!(
+        "Regular expression did not compile: {err:?}"
+    )))
+}
+
+/// Looks up regexes in the shared cache for the rows of a batch, remembering
+/// the last one so that a pattern repeated on consecutive rows, such as a
+/// scalar pattern, is only looked up once.
+#[derive(Debug, Default)]
+pub(crate) struct RegexLookup {
+    last: Option<(String, Arc<Regex>)>,
+}
+
+impl RegexLookup {
+    pub(crate) fn get(&mut self, pattern: &str) -> Result<&Regex, regex::Error> {
+        if !matches!(&self.last, Some((last_pattern, _)) if last_pattern == pattern) {
+            self.last = Some((pattern.to_string(), cached_regex(pattern)?));
+        }
+        let (_, regex) = self.last.as_ref().expect("the regex was just looked up");
+        Ok(regex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn test_repeated_pattern_is_not_recompiled() {
+        let cache = Mutex::new(RegexCache::new(2));
+        let compiles = AtomicUsize::new(0);
+        let compile = |pattern: &str| {
+            compiles.fetch_add(1, Ordering::Relaxed);
+            Regex::new(pattern)
+        };
+
+        let first = get_or_compile(&cache, "a+", compile).unwrap();
+        let second = get_or_compile(&cache, "a+", compile).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(compiles.load(Ordering::Relaxed), 1);
+
+        // invalid patterns are reported and not cached
+        assert!(get_or_compile(&cache, "(", compile).is_err());
+        assert!(get_or_compile(&cache, "(", compile).is_err());
+        assert_eq!(compiles.load(Ordering::Relaxed), 3);
+        assert_eq!(cache.lock().unwrap().entries.len(), 1);
+    }
+
+    #[test]
+    fn test_least_recently_used_pattern_is_evicted() {
+        let cache = Mutex::new(RegexCache::new(2));
+        let compiles = AtomicUsize::new(0);
+        let compile = |pattern: &str| {
+            compiles.fetch_add(1, Ordering::Relaxed);
+            Regex::new(pattern)
+        };
+
+        get_or_compile(&cache, "a", compile).unwrap();
+        get_or_compile(&cache, "b", compile).unwrap();
+        // using "a" again makes "b" the least recently used
+        get_or_compile(&cache, "a", compile).unwrap();
+        get_or_compile(&cache, "c", compile).unwrap();
+        assert_eq!(compiles.load(Ordering::Relaxed), 3);
+
+        let mut patterns = cache
+            .lock()
+            .unwrap()
+            .entries
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        patterns.sort();
+        assert_eq!(patterns, vec!["a", "c"]);
+
+        get_or_compile(&cache, "b", compile).unwrap();
+        assert_eq!(compiles.load(Ordering::Relaxed), 4);
+        assert_eq!(cache.lock().unwrap().entries.len(), 2);
+    }
+
+    #[test]
+    fn test_regex_lookup_reuses_shared_regex() {
+        let mut lookup = RegexLookup::default();
+        let pattern = "^regex_lookup_test_[0-9]+$";
+        assert!(lookup
+            .get(pattern)
+            .unwrap()
+            .is_match("regex_lookup_test_42"));
+        assert!(!lookup.get(pattern).unwrap().is_match("regex_lookup_test_"));
+        assert!(Arc::ptr_eq(
+            &cached_regex(pattern).unwrap(),
+            &cached_regex(pattern).unwrap()
+        ));
+        assert!(lookup.get("(").is_err());
+    }
+
+    #[test]
+    fn test_pattern_with_flags() {
+        assert_eq!(pattern_with_flags("abc", None), "abc");
+        assert_eq!(pattern_with_flags("abc", Some("i")), "(?i)abc");
+    }
+}
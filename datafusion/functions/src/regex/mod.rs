@@ -17,12 +17,15 @@
 
 //! "regx" DataFusion functions
 
+mod cache;
+pub mod regexpinstr;
 pub mod regexplike;
 pub mod regexpmatch;
 pub mod regexpreplace;
 // create UDFs
 make_udf_function!(regexpmatch::RegexpMatchFunc, REGEXP_MATCH, regexp_match);
 make_udf_function!(regexplike::RegexpLikeFunc, REGEXP_LIKE, regexp_like);
+make_udf_function!(regexpinstr::RegexpInstrFunc, REGEXP_INSTR, regexp_instr);
 make_udf_function!(
     regexpreplace::RegexpReplaceFunc,
     REGEXP_REPLACE,
@@ -36,4 +39,8 @@ export_functions!((
     regexp_like,
     input_arg1 input_arg2,
     "Returns true if a has at least one match in a string,false otherwise."
+),(
+    regexp_instr,
+    input_arg1 input_arg2,
+    "Returns the position of the first match of a regular expression in a string, 0 if there is none."
 ),(regexp_replace, arg1 arg2 arg3 arg4, "Replaces substrings in a string that match"));
@@ -0,0 +1,344 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Regx expressions
+use arrow::array::{Array, ArrayRef, Int64Array, OffsetSizeTrait};
+use arrow::datatypes::DataType;
+use datafusion_common::cast::as_int64_array;
+use datafusion_common::exec_err;
+use datafusion_common::plan_err;
+use datafusion_common::ScalarValue;
+use datafusion_common::{cast::as_generic_string_array, internal_err, Result};
+use datafusion_expr::ColumnarValue;
+use datafusion_expr::TypeSignature::*;
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use regex::Regex;
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::regex::cache::{compile_error, RegexLookup};
+
+/// `regexp_instr(string, regex[, start[, occurrence]])` returns the 1-based
+/// character position of the first match of a regular expression in a string,
+/// or 0 if there is no match.
+///
+/// The optional `start` argument is the character position to start searching
+/// from, and `occurrence` selects the n-th non-overlapping match instead of the
+/// first one. Both default to 1. A null argument produces a null.
+///
+/// The full list of supported features and syntax can be found at
+/// <https://docs.rs/regex/latest/regex/#syntax>
+///
+/// # Examples
+///
+/// ```ignore
+/// # use datafusion::prelude::*;
+/// # use datafusion::error::Result;
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let ctx = SessionContext::new();
+/// let df = ctx.read_csv("tests/data/regex.csv", CsvReadOptions::new()).await?;
+///
+/// // use the regexp_instr function to find the position of the first match
+/// // of the patterns in col 'patterns' in col 'values'
+/// let df = df.with_column("a", regexp_instr(col("values"), col("patterns")))?;
+/// // the position of the second match, searching from the third character,
+/// // which needs the function itself as `regexp_instr` takes two arguments
+/// let df = df.with_column(
+///     "b",
+///     datafusion::functions::regex::regexp_instr().call(vec![
+///         col("values"),
+///         col("patterns"),
+///         lit(3),
+///         lit(2),
+///     ]),
+/// )?;
+///
+/// df.show().await?;
+///
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub(super) struct RegexpInstrFunc {
+    signature: Signature,
+}
+impl RegexpInstrFunc {
+    pub fn new() -> Self {
+        use DataType::*;
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    Exact(vec![Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, Utf8]),
+                    Exact(vec![Utf8, Utf8, Int64]),
+                    Exact(vec![LargeUtf8, Utf8, Int64]),
+                    Exact(vec![Utf8, Utf8, Int64, Int64]),
+                    Exact(vec![LargeUtf8, Utf8, Int64, Int64]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpInstrFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_instr"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        use DataType::*;
+
+        Ok(match &arg_types[0] {
+            LargeUtf8 | Utf8 => Int64,
+            Null => Null,
+            other => {
+                return plan_err!(
+                    "The regexp_instr function can only accept strings. Got {other}"
+                );
+            }
+        })
+    }
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        let len = args
+            .iter()
+            .fold(Option::<usize>::None, |acc, arg| match arg {
+                ColumnarValue::Scalar(_) => acc,
+                ColumnarValue::Array(a) => Some(a.len()),
+            });
+
+        let is_scalar = len.is_none();
+        let inferred_length = len.unwrap_or(1);
+        let args = args
+            .iter()
+            .map(|arg| arg.clone().into_array(inferred_length))
+            .collect::<Result<Vec<_>>>()?;
+
+        let result = regexp_instr_func(&args);
+        if is_scalar {
+            // If all inputs are scalar, keeps output as scalar
+            let result = result.and_then(|arr| ScalarValue::try_from_array(&arr, 0));
+            result.map(ColumnarValue::Scalar)
+        } else {
+            result.map(ColumnarValue::Array)
+        }
+    }
+}
+fn regexp_instr_func(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Utf8 => regexp_instr::<i32>(args),
+        DataType::LargeUtf8 => regexp_instr::<i64>(args),
+        other => {
+            internal_err!("Unsupported data type {other:?} for function regexp_instr")
+        }
+    }
+}
+/// Returns the 1-based character position of the first match of the regular
+/// expressions in `args[1]` in the strings in `args[0]`, or 0 if there is no
+/// match, searching from the optional `start` position in `args[2]` for the
+/// optional `occurrence` in `args[3]`.
+pub fn regexp_instr<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if !(2..=4).contains(&args.len()) {
+        return exec_err!(
+            "regexp_instr was called with {} arguments. It requires at least 2 and at most 4.",
+            args.len()
+        );
+    }
+    let values = as_generic_string_array::<T>(&args[0])?;
+    let regex = as_generic_string_array::<i32>(&args[1])?;
+    let starts = args.get(2).map(|arg| as_int64_array(arg)).transpose()?;
+    let occurrences = args.get(3).map(|arg| as_int64_array(arg)).transpose()?;
+    // an omitted argument is 1 on every row, a null one is null
+    let positive_arg = |array: Option<&Int64Array>, name: &str, i: usize| {
+        let Some(array) = array else {
+            return Ok(Some(1));
+        };
+        if array.is_null(i) {
+            return Ok(None);
+        }
+        match array.value(i) {
+            value if value >= 1 => Ok(Some(value as usize)),
+            value => {
+                exec_err!("regexp_instr() requires {name} to be at least 1, got {value}")
+            }
+        }
+    };
+
+    let mut regexes = RegexLookup::default();
+    let array = values
+        .iter()
+        .zip(regex.iter())
+        .enumerate()
+        .map(|(i, (value, pattern))| {
+            let start = positive_arg(starts, "start", i)?;
+            let occurrence = positive_arg(occurrences, "occurrence", i)?;
+            let (Some(value), Some(pattern), Some(start), Some(occurrence)) =
+                (value, pattern, start, occurrence)
+            else {
+                return Ok(None);
+            };
+            let re = regexes.get(pattern).map_err(compile_error)?;
+            // the byte offset of the start character, which may be just past the end
+            let Some(offset) = value
+                .char_indices()
+                .map(|(offset, _)| offset)
+                .chain(std::iter::once(value.len()))
+                .nth(start - 1)
+            else {
+                return Ok(Some(0));
+            };
+            let position = nth_match_start(re, value, offset, occurrence)
+                .map(|byte_index| value[..byte_index].chars().count() as i64 + 1);
+            Ok(Some(position.unwrap_or(0)))
+        })
+        .collect::<Result<Int64Array>>()?;
+
+    Ok(Arc::new(array) as ArrayRef)
+}
+
+/// Returns the byte index of the `occurrence`-th non-overlapping match of `re`
+/// in `value` that starts at or after `offset`. Anchors such as `^` still refer
+/// to the start of `value`.
+fn nth_match_start(
+    re: &Regex,
+    value: &str,
+    offset: usize,
+    occurrence: usize,
+) -> Option<usize> {
+    let mut at = offset;
+    for _ in 1..occurrence {
+        let m = re.find_at(value, at)?;
+        at = if m.is_empty() {
+            // step over the next character so an empty match is not found again
+            m.end() + value[m.end()..].chars().next()?.len_utf8()
+        } else {
+            m.end()
+        };
+    }
+    re.find_at(value, at).map(|m| m.start())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Int64Array, StringArray};
+
+    use crate::regex::regexpinstr::regexp_instr;
+
+    #[test]
+    fn test_regexp_instr() {
+        let values = StringArray::from(vec![
+            Some("abcabc"),
+            Some("abcabc"),
+            Some("abcabc"),
+            Some("köln köln"),
+            Some("xyz"),
+            None,
+        ]);
+        let patterns = StringArray::from(vec!["b", "c", "^a", "l", "b", "b"]);
+
+        let re = regexp_instr::<i32>(&[Arc::new(values), Arc::new(patterns)]).unwrap();
+
+        let expected =
+            Int64Array::from(vec![Some(2), Some(3), Some(1), Some(3), Some(0), None]);
+        assert_eq!(re.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_instr_start_and_occurrence() {
+        let values = StringArray::from(vec!["abcabcabc"; 7]);
+        let patterns = StringArray::from(vec!["b", "b", "b", "b", "^a", "", "b"]);
+        let starts = Int64Array::from(vec![
+            Some(1),
+            Some(3),
+            Some(3),
+            Some(9),
+            Some(2),
+            Some(10),
+            None,
+        ]);
+        let occurrences = Int64Array::from(vec![
+            Some(2),
+            Some(1),
+            Some(2),
+            Some(1),
+            Some(1),
+            Some(1),
+            Some(1),
+        ]);
+
+        let re = regexp_instr::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(starts),
+            Arc::new(occurrences),
+        ])
+        .unwrap();
+
+        let expected = Int64Array::from(vec![
+            Some(5),
+            Some(5),
+            Some(8),
+            Some(0),
+            // anchors refer to the start of the string, not the start position
+            Some(0),
+            // an empty pattern matches just past the end
+            Some(10),
+            None,
+        ]);
+        assert_eq!(re.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_instr_invalid_arguments() {
+        let values = StringArray::from(vec!["abc"]);
+        let patterns = StringArray::from(vec!["b"]);
+
+        let re_err = regexp_instr::<i32>(&[
+            Arc::new(values.clone()),
+            Arc::new(patterns.clone()),
+            Arc::new(Int64Array::from(vec![0])),
+        ])
+        .expect_err("a start of 0 should have failed");
+        assert_eq!(
+            re_err.strip_backtrace(),
+            "Execution error: regexp_instr() requires start to be at least 1, got 0"
+        );
+
+        let re_err = regexp_instr::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(Int64Array::from(vec![1])),
+            Arc::new(Int64Array::from(vec![-1])),
+        ])
+        .expect_err("a negative occurrence should have failed");
+        assert_eq!(
+            re_err.strip_backtrace(),
+            "Execution error: regexp_instr() requires occurrence to be at least 1, got -1"
+        );
+    }
+}
@@ -16,21 +16,20 @@
 // under the License.
 
 //! Regx expressions
-use arrow::array::{Array, ArrayRef, OffsetSizeTrait};
-use arrow::compute::kernels::regexp;
+use arrow::array::{Array, ArrayRef, BooleanArray, OffsetSizeTrait};
 use arrow::datatypes::DataType;
 use datafusion_common::exec_err;
+use datafusion_common::plan_err;
 use datafusion_common::ScalarValue;
-use datafusion_common::{arrow_datafusion_err, plan_err};
-use datafusion_common::{
-    cast::as_generic_string_array, internal_err, DataFusionError, Result,
-};
+use datafusion_common::{cast::as_generic_string_array, internal_err, Result};
 use datafusion_expr::ColumnarValue;
 use datafusion_expr::TypeSignature::*;
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 use std::sync::Arc;
 
+use crate::regex::cache::{compile_error, pattern_with_flags, RegexLookup};
+
 #[derive(Debug)]
 pub(super) struct RegexpLikeFunc {
     signature: Signature,
@@ -155,33 +154,48 @@ fn regexp_like_func(args: &[ArrayRef]) -> Result<ArrayRef> {
 /// # }
 /// ```
 pub fn regexp_like<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
-    match args.len() {
-        2 => {
-            let values = as_generic_string_array::<T>(&args[0])?;
-            let regex = as_generic_string_array::<T>(&args[1])?;
-            let array = regexp::regexp_is_match_utf8(values, regex, None)
-                .map_err(|e| arrow_datafusion_err!(e))?;
-
-            Ok(Arc::new(array) as ArrayRef)
-        }
+    let flags = match args.len() {
+        2 => None,
         3 => {
-            let values = as_generic_string_array::<T>(&args[0])?;
-            let regex = as_generic_string_array::<T>(&args[1])?;
             let flags = as_generic_string_array::<T>(&args[2])?;
-
             if flags.iter().any(|s| s == Some("g")) {
                 return plan_err!("regexp_like() does not support the \"global\" option");
             }
+            Some(flags)
+        }
+        other => {
+            return exec_err!(
+                "regexp_like was called with {other} arguments. It requires at least 2 and at most 3."
+            )
+        }
+    };
+    let values = as_generic_string_array::<T>(&args[0])?;
+    let regex = as_generic_string_array::<T>(&args[1])?;
 
-            let array = regexp::regexp_is_match_utf8(values, regex, Some(flags))
-                .map_err(|e| arrow_datafusion_err!(e))?;
+    let mut regexes = RegexLookup::default();
+    let array = values
+        .iter()
+        .zip(regex.iter())
+        .enumerate()
+        .map(|(i, (value, pattern))| {
+            let flags = flags.and_then(|flags| flags.is_valid(i).then(|| flags.value(i)));
+            match (value, pattern) {
+                (Some(value), Some(pattern)) => {
+                    let pattern = pattern_with_flags(pattern, flags);
+                    // Required for Postgres compatibility:
+                    // SELECT 'foobarbequebaz' ~ ''); = true
+                    if pattern.is_empty() {
+                        return Ok(Some(true));
+                    }
+                    let re = regexes.get(&pattern).map_err(compile_error)?;
+                    Ok(Some(re.is_match(value)))
+                }
+                _ => Ok(None),
+            }
+        })
+        .collect::<Result<BooleanArray>>()?;
 
-            Ok(Arc::new(array) as ArrayRef)
-        }
-        other => exec_err!(
-            "regexp_like was called with {other} arguments. It requires at least 2 and at most 3."
-        ),
-    }
+    Ok(Arc::new(array) as ArrayRef)
 }
 #[cfg(test)]
 mod tests {
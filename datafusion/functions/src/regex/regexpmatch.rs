@@ -16,22 +16,21 @@
 // under the License.
 
 //! Regx expressions
-use arrow::array::{Array, ArrayRef, OffsetSizeTrait};
-use arrow::compute::kernels::regexp;
+use arrow::array::{Array, ArrayRef, GenericStringBuilder, ListBuilder, OffsetSizeTrait};
 use arrow::datatypes::DataType;
 use arrow::datatypes::Field;
 use datafusion_common::exec_err;
+use datafusion_common::plan_err;
 use datafusion_common::ScalarValue;
-use datafusion_common::{arrow_datafusion_err, plan_err};
-use datafusion_common::{
-    cast::as_generic_string_array, internal_err, DataFusionError, Result,
-};
+use datafusion_common::{cast::as_generic_string_array, internal_err, Result};
 use datafusion_expr::ColumnarValue;
 use datafusion_expr::TypeSignature::*;
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use std::any::Any;
 use std::sync::Arc;
 
+use crate::regex::cache::{compile_error, pattern_with_flags, RegexLookup};
+
 #[derive(Debug)]
 pub(super) struct RegexpMatchFunc {
     signature: Signature,
@@ -115,29 +114,59 @@ fn regexp_match_func(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 pub fn regexp_match<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
-    match args.len() {
-        2 => {
-            let values = as_generic_string_array::<T>(&args[0])?;
-            let regex = as_generic_string_array::<T>(&args[1])?;
-            regexp::regexp_match(values, regex, None)
-                .map_err(|e| arrow_datafusion_err!(e))
-        }
+    let flags = match args.len() {
+        2 => None,
         3 => {
-            let values = as_generic_string_array::<T>(&args[0])?;
-            let regex = as_generic_string_array::<T>(&args[1])?;
             let flags = as_generic_string_array::<T>(&args[2])?;
-
             if flags.iter().any(|s| s == Some("g")) {
                 return plan_err!("regexp_match() does not support the \"global\" option")
             }
-
-            regexp::regexp_match(values, regex, Some(flags))
-                .map_err(|e| arrow_datafusion_err!(e))
+            Some(flags)
+        }
+        other => {
+            return exec_err!(
+                "regexp_match was called with {other} arguments. It requires at least 2 and at most 3."
+            )
+        }
+    };
+    let values = as_generic_string_array::<T>(&args[0])?;
+    let regex = as_generic_string_array::<T>(&args[1])?;
+
+    let mut regexes = RegexLookup::default();
+    let mut list_builder = ListBuilder::new(GenericStringBuilder::<T>::new());
+    for (i, (value, pattern)) in values.iter().zip(regex.iter()).enumerate() {
+        let flags = flags.and_then(|flags| flags.is_valid(i).then(|| flags.value(i)));
+        let (value, pattern) = match (value, pattern) {
+            (Some(value), Some(pattern)) => (value, pattern_with_flags(pattern, flags)),
+            _ => {
+                list_builder.append(false);
+                continue;
+            }
+        };
+        // Required for Postgres compatibility:
+        // SELECT regexp_match('foobarbequebaz', ''); = {""}
+        if pattern.is_empty() {
+            list_builder.values().append_value("");
+            list_builder.append(true);
+            continue;
+        }
+        let re = regexes.get(&pattern).map_err(compile_error)?;
+        match re.captures(value) {
+            Some(caps) => {
+                // only the capture groups are returned if there are any
+                let mut iter = caps.iter();
+                if caps.len() > 1 {
+                    iter.next();
+                }
+                for m in iter.flatten() {
+                    list_builder.values().append_value(m.as_str());
+                }
+                list_builder.append(true);
+            }
+            None => list_builder.append(false),
         }
-        other => exec_err!(
-            "regexp_match was called with {other} arguments. It requires at least 2 and at most 3."
-        ),
     }
+    Ok(Arc::new(list_builder.finish()))
 }
 #[cfg(test)]
 mod tests {
@@ -34,9 +34,10 @@ use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use datafusion_physical_expr::functions::Hint;
 use regex::Regex;
 use std::any::Any;
-use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::OnceLock;
+
+use crate::regex::cache::{cached_regex, RegexLookup};
 #[derive(Debug)]
 pub(super) struct RegexpReplaceFunc {
     signature: Signature,
@@ -141,6 +142,11 @@ fn regex_replace_posix_groups(replacement: &str) -> String {
 /// Supported flags with the addition of 'g' can be found at
 /// <https://docs.rs/regex/latest/regex/#grouping-and-flags>
 ///
+/// The replacement string may refer to capture groups either POSIX style
+/// (`\1`) or using the regex crate syntax (`$1`, `${1}`, `$name` or
+/// `${name}`). A reference is greedy, so `$1a` names the group `1a`; use
+/// `${1}a` instead. A literal `$` must be written as `$$`.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -177,8 +183,8 @@ pub fn regexp_replace<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef>
     // Default implementation for regexp_replace, assumes all args are arrays
     // and args is a sequence of 3 or 4 elements.
 
-    // creating Regex is expensive so create hashmap for memoization
-    let mut patterns: HashMap<String, Regex> = HashMap::new();
+    // creating Regex is expensive so look them up in the shared cache
+    let mut regexes = RegexLookup::default();
 
     match args.len() {
         3 => {
@@ -194,19 +200,9 @@ pub fn regexp_replace<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef>
                 (Some(string), Some(pattern), Some(replacement)) => {
                     let replacement = regex_replace_posix_groups(replacement);
 
-                    // if patterns hashmap already has regexp then use else create and return
-                    let re = match patterns.get(pattern) {
-                        Some(re) => Ok(re),
-                        None => {
-                            match Regex::new(pattern) {
-                                Ok(re) => {
-                                    patterns.insert(pattern.to_string(), re);
-                                    Ok(patterns.get(pattern).unwrap())
-                                },
-                                Err(err) => Err(DataFusionError::External(Box::new(err))),
-                            }
-                        }
-                    };
+                    let re = regexes
+                        .get(pattern)
+                        .map_err(|err| DataFusionError::External(Box::new(err)));
 
                     Some(re.map(|re| re.replace(string, replacement.as_str()))).transpose()
                 }
@@ -240,19 +236,9 @@ pub fn regexp_replace<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef>
                         (format!("(?{flags}){pattern}"), false)
                     };
 
-                    // if patterns hashmap already has regexp then use else create and return
-                    let re = match patterns.get(&pattern) {
-                        Some(re) => Ok(re),
-                        None => {
-                            match Regex::new(pattern.as_str()) {
-                                Ok(re) => {
-                                    patterns.insert(pattern.clone(), re);
-                                    Ok(patterns.get(&pattern).unwrap())
-                                },
-                                Err(err) => Err(DataFusionError::External(Box::new(err))),
-                            }
-                        }
-                    };
+                    let re = regexes
+                        .get(&pattern)
+                        .map_err(|err| DataFusionError::External(Box::new(err)));
 
                     Some(re.map(|re| {
                         if replace_all {
@@ -333,7 +319,7 @@ fn _regexp_replace_static_pattern_replace<T: OffsetSizeTrait>(
     };
 
     let re =
-        Regex::new(&pattern).map_err(|err| DataFusionError::External(Box::new(err)))?;
+        cached_regex(&pattern).map_err(|err| DataFusionError::External(Box::new(err)))?;
 
     // Replaces the posix groups in the replacement string
     // with rust ones.
@@ -487,6 +473,25 @@ mod tests {
         assert_eq!(re.as_ref(), &expected);
     }
 
+    #[test]
+    fn test_regexp_replace_backreferences() {
+        let values = StringArray::from(vec!["2024-01"; 5]);
+        let patterns = StringArray::from(vec![r"(?<y>\d+)-(?<m>\d+)"; 5]);
+        let replacements =
+            StringArray::from(vec!["$2/$1", "${m}/${y}", r"\2/\1", "$$$1", "${1}0"]);
+        let expected =
+            StringArray::from(vec!["01/2024", "01/2024", "01/2024", "$2024", "20240"]);
+
+        let re = regexp_replace::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(replacements),
+        ])
+        .unwrap();
+
+        assert_eq!(re.as_ref(), &expected);
+    }
+
     #[test]
     fn test_static_pattern_regexp_replace_early_abort() {
         let values = StringArray::from(vec!["abc"; 5]);
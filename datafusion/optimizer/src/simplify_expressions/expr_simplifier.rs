@@ -1334,6 +1334,13 @@ impl<'a, S: SimplifyInfo> TreeNodeRewriter for Simplifier<'a, S> {
                 args,
             }) => Transformed::yes(simpl_concat(args)?),
 
+            // coalesce
+            Expr::ScalarFunction(ScalarFunction {
+                func_def:
+                    ScalarFunctionDefinition::BuiltIn(BuiltinScalarFunction::Coalesce),
+                args,
+            }) => Transformed::yes(simpl_coalesce(args, info)?),
+
             // concat_ws
             Expr::ScalarFunction(ScalarFunction {
                 func_def:
@@ -2511,6 +2518,40 @@ mod tests {
         assert_eq!(simplify(expr), expected)
     }
 
+    #[test]
+    fn test_simplify_coalesce() {
+        // a single argument is returned as is
+        assert_change(coalesce(vec![col("c1")]), col("c1"));
+
+        // repeated arguments are removed
+        assert_change(coalesce(vec![col("c1"), col("c1")]), col("c1"));
+        assert_change(
+            coalesce(vec![col("c1"), col("c2"), col("c1"), col("c2")]),
+            coalesce(vec![col("c1"), col("c2")]),
+        );
+
+        // volatile arguments are evaluated each time, so are kept
+        assert_no_change(coalesce(vec![random(), random()]));
+        assert_no_change(coalesce(vec![col("c1"), col("c2")]));
+
+        // the arguments after one that can not be null are never used
+        assert_change(
+            coalesce(vec![col("c1_non_null"), col("c1"), lit("a")]),
+            col("c1_non_null"),
+        );
+        assert_change(
+            coalesce(vec![col("c3"), col("c3_non_null"), col("c3")]),
+            coalesce(vec![col("c3"), col("c3_non_null")]),
+        );
+        assert_change(
+            coalesce(vec![col("c3"), lit(1_i64), col("c3_non_null")]),
+            coalesce(vec![col("c3"), lit(1_i64)]),
+        );
+        assert_no_change(coalesce(vec![col("c3"), col("c3_non_null")]));
+        // unless that would change the type of the result
+        assert_no_change(coalesce(vec![col("c4_non_null"), col("c3")]));
+    }
+
     #[test]
     fn test_simplify_regex() {
         // malformed regex
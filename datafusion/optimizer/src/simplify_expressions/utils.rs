@@ -17,6 +17,8 @@
 
 //! Utility functions for expression simplification
 
+use crate::utils::is_volatile_expression;
+
 use datafusion_common::{internal_err, Result, ScalarValue};
 use datafusion_expr::simplify::SimplifyInfo;
 use datafusion_expr::{
@@ -461,6 +463,48 @@ pub fn simpl_concat(args: Vec<Expr>) -> Result<Expr> {
     )))
 }
 
+/// Simplify the `coalesce` function by
+/// 1. removing arguments that repeat an earlier argument, as a repeated argument is
+///    only evaluated when the earlier one is null (volatile arguments are kept)
+/// 2. removing the arguments after the first one that can not be null, as they are
+///    never used. This is only done when all the arguments have the same type, so
+///    the type of the result does not change
+/// 3. returning the argument itself when only a single argument remains
+///
+/// For example:
+/// `coalesce(col(a), col(b), col(a))` will be optimized to `coalesce(col(a), col(b))`,
+/// `coalesce(col(a), col(a))` will be optimized to `col(a)` and, if `b` is not
+/// nullable, `coalesce(col(a), col(b), col(c))` will be optimized to `coalesce(col(a), col(b))`
+pub fn simpl_coalesce(args: Vec<Expr>, info: &dyn SimplifyInfo) -> Result<Expr> {
+    let mut new_args: Vec<Expr> = Vec::with_capacity(args.len());
+    for arg in args {
+        if !new_args.contains(&arg) || is_volatile_expression(&arg)? {
+            new_args.push(arg);
+        }
+    }
+
+    // without a schema the nullability and types are unknown, so nothing is removed
+    let known_non_null = |arg: &Expr| matches!(info.nullable(arg), Ok(false));
+    if let Some(position) = new_args.iter().position(known_non_null) {
+        let data_types = new_args
+            .iter()
+            .map(|arg| info.get_data_type(arg).ok())
+            .collect::<Vec<_>>();
+        if data_types[0].is_some() && data_types.iter().all(|t| *t == data_types[0]) {
+            new_args.truncate(position + 1);
+        }
+    }
+
+    if new_args.len() == 1 {
+        Ok(new_args.swap_remove(0))
+    } else {
+        Ok(Expr::ScalarFunction(ScalarFunction::new(
+            BuiltinScalarFunction::Coalesce,
+            new_args,
+        )))
+    }
+}
+
 /// Simply the `concat_ws` function by
 /// 1. folding to `null` if the delimiter is null
 /// 2. filtering out `null` arguments
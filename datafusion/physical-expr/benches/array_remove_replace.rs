@@ -0,0 +1,99 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use std::sync::Arc;
+
+use arrow_array::types::Int64Type;
+use arrow_array::{ArrayRef, Int64Array, ListArray};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+use datafusion_physical_expr::array_expressions::{array_remove_all, array_replace_all};
+
+const NUM_ROWS: usize = 100;
+const LIST_LEN: usize = 5000;
+const MAX_VALUE: i64 = 10;
+
+fn lists(rng: &mut ThreadRng) -> ListArray {
+    ListArray::from_iter_primitive::<Int64Type, _, _>((0..NUM_ROWS).map(|_| {
+        Some(
+            (0..LIST_LEN)
+                .map(|_| rng.gen_bool(0.99).then(|| rng.gen_range(0..MAX_VALUE)))
+                .collect::<Vec<_>>(),
+        )
+    }))
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let list_array = Arc::new(lists(&mut rng)) as ArrayRef;
+    // a constant target is passed as a single value, a column as one value per row
+    let constant = |value: i64| Arc::new(Int64Array::from(vec![value])) as ArrayRef;
+    let column = Arc::new(Int64Array::from_iter_values(
+        (0..NUM_ROWS as i64).map(|row_index| row_index % MAX_VALUE),
+    )) as ArrayRef;
+
+    let args = vec![list_array.clone(), constant(1)];
+    c.bench_function(
+        &format!("array_remove_all_constant_{NUM_ROWS}x{LIST_LEN}"),
+        |b| {
+            b.iter(|| {
+                black_box(array_remove_all(&args).expect("array_remove_all should work"))
+            })
+        },
+    );
+
+    let args = vec![list_array.clone(), column.clone()];
+    c.bench_function(
+        &format!("array_remove_all_column_{NUM_ROWS}x{LIST_LEN}"),
+        |b| {
+            b.iter(|| {
+                black_box(array_remove_all(&args).expect("array_remove_all should work"))
+            })
+        },
+    );
+
+    let args = vec![list_array.clone(), constant(1), constant(-1)];
+    c.bench_function(
+        &format!("array_replace_all_constant_{NUM_ROWS}x{LIST_LEN}"),
+        |b| {
+            b.iter(|| {
+                black_box(
+                    array_replace_all(&args).expect("array_replace_all should work"),
+                )
+            })
+        },
+    );
+
+    let args = vec![list_array.clone(), column, constant(-1)];
+    c.bench_function(
+        &format!("array_replace_all_column_{NUM_ROWS}x{LIST_LEN}"),
+        |b| {
+            b.iter(|| {
+                black_box(
+                    array_replace_all(&args).expect("array_replace_all should work"),
+                )
+            })
+        },
+    );
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,74 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use std::sync::Arc;
+
+use arrow_array::types::Int64Type;
+use arrow_array::{ArrayRef, ListArray};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+use datafusion_physical_expr::array_expressions::{
+    array_except, array_intersect, array_union,
+};
+
+const NUM_ROWS: usize = 100;
+const LIST_LEN: usize = 5000;
+
+fn lists(rng: &mut ThreadRng) -> ListArray {
+    ListArray::from_iter_primitive::<Int64Type, _, _>((0..NUM_ROWS).map(|_| {
+        Some(
+            (0..LIST_LEN)
+                .map(|_| {
+                    rng.gen_bool(0.99)
+                        .then(|| rng.gen_range(0..2 * LIST_LEN as i64))
+                })
+                .collect::<Vec<_>>(),
+        )
+    }))
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let args = vec![
+        Arc::new(lists(&mut rng)) as ArrayRef,
+        Arc::new(lists(&mut rng)) as ArrayRef,
+    ];
+
+    type SetOp = fn(&[ArrayRef]) -> datafusion_common::Result<ArrayRef>;
+    let set_ops: [(&str, SetOp); 3] = [
+        ("array_union", array_union),
+        ("array_intersect", array_intersect),
+        ("array_except", array_except),
+    ];
+
+    for (name, set_op) in set_ops {
+        c.bench_function(&format!("{name}_{NUM_ROWS}x{LIST_LEN}"), |b| {
+            b.iter(|| {
+                black_box(
+                    set_op(&args).expect("set operation should work on valid values"),
+                )
+            })
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
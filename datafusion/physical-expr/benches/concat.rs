@@ -0,0 +1,135 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, StringArray};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::distributions::Alphanumeric;
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+use datafusion_common::ScalarValue;
+use datafusion_expr::ColumnarValue;
+use datafusion_physical_expr::string_expressions::{concat, concat_ws};
+
+const NUM_ROWS: usize = 100_000;
+const NUM_COLUMNS: usize = 5;
+
+fn strings(rng: &mut ThreadRng) -> StringArray {
+    (0..NUM_ROWS)
+        .map(|_| {
+            let len = rng.gen_range(16..64);
+            rng.gen_bool(0.9).then(|| {
+                rng.sample_iter(&Alphanumeric)
+                    .take(len)
+                    .map(char::from)
+                    .collect::<String>()
+            })
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let columns = (0..NUM_COLUMNS)
+        .map(|_| Arc::new(strings(&mut rng)) as ArrayRef)
+        .collect::<Vec<_>>();
+
+    let concat_args = columns
+        .iter()
+        .map(|column| ColumnarValue::Array(column.clone()))
+        .chain([ColumnarValue::Scalar(ScalarValue::Utf8(Some(
+            "suffix".to_string(),
+        )))])
+        .collect::<Vec<_>>();
+    let concat_name = format!("concat_{NUM_COLUMNS}_columns_{NUM_ROWS}");
+    c.bench_function(&concat_name, |b| {
+        b.iter(|| {
+            black_box(concat(&concat_args).expect("concat should work on valid values"))
+        })
+    });
+
+    let separator = Arc::new(StringArray::from(vec![", "; NUM_ROWS])) as ArrayRef;
+    let concat_ws_args = [separator].into_iter().chain(columns).collect::<Vec<_>>();
+    let concat_ws_name = format!("concat_ws_{NUM_COLUMNS}_columns_{NUM_ROWS}");
+    c.bench_function(&concat_ws_name, |b| {
+        b.iter(|| {
+            black_box(
+                concat_ws(&concat_ws_args)
+                    .expect("concat_ws should work on valid values"),
+            )
+        })
+    });
+}
+
+/// Half of the columns are entirely NULL, which concat and concat_ws skip wholesale
+fn sparse_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let columns = (0..NUM_COLUMNS * 2)
+        .map(|i| {
+            if i % 2 == 0 {
+                Arc::new(strings(&mut rng)) as ArrayRef
+            } else {
+                Arc::new(StringArray::from(vec![None::<&str>; NUM_ROWS])) as ArrayRef
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let concat_args = columns
+        .iter()
+        .map(|column| ColumnarValue::Array(column.clone()))
+        .collect::<Vec<_>>();
+    c.bench_function(
+        &format!(
+            "concat_{}_columns_{NUM_COLUMNS}_null_{NUM_ROWS}",
+            NUM_COLUMNS * 2
+        ),
+        |b| {
+            b.iter(|| {
+                black_box(
+                    concat(&concat_args).expect("concat should work on valid values"),
+                )
+            })
+        },
+    );
+
+    let separator = Arc::new(StringArray::from(vec![", "; NUM_ROWS])) as ArrayRef;
+    let concat_ws_args = [separator]
+        .into_iter()
+        .chain(columns.iter().cloned())
+        .collect::<Vec<_>>();
+    c.bench_function(
+        &format!(
+            "concat_ws_{}_columns_{NUM_COLUMNS}_null_{NUM_ROWS}",
+            NUM_COLUMNS * 2
+        ),
+        |b| {
+            b.iter(|| {
+                black_box(
+                    concat_ws(&concat_ws_args)
+                        .expect("concat_ws should work on valid values"),
+                )
+            })
+        },
+    );
+}
+
+criterion_group!(benches, criterion_benchmark, sparse_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,72 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+extern crate criterion;
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, LargeStringArray, StringArray};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::distributions::Alphanumeric;
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+use datafusion_physical_expr::string_expressions::levenshtein;
+
+const NUM_ROWS: usize = 64;
+const STR_LEN: usize = 1024;
+
+/// Kilobyte sized strings drawn from a small alphabet, so pairs share characters
+fn strings(rng: &mut ThreadRng) -> Vec<String> {
+    (0..NUM_ROWS)
+        .map(|_| {
+            let len = rng.gen_range(STR_LEN / 2..=STR_LEN);
+            rng.sample_iter(&Alphanumeric)
+                .take(len)
+                .map(|c| char::from(b'a' + c % 4))
+                .collect()
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let (left, right) = (strings(&mut rng), strings(&mut rng));
+
+    let args: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from(left.clone())),
+        Arc::new(StringArray::from(right.clone())),
+    ];
+    c.bench_function(&format!("levenshtein_utf8_{NUM_ROWS}x{STR_LEN}"), |b| {
+        b.iter(|| black_box(levenshtein::<i32>(&args).expect("levenshtein should work")))
+    });
+
+    let args: Vec<ArrayRef> = vec![
+        Arc::new(LargeStringArray::from(left)),
+        Arc::new(LargeStringArray::from(right)),
+    ];
+    c.bench_function(
+        &format!("levenshtein_large_utf8_{NUM_ROWS}x{STR_LEN}"),
+        |b| {
+            b.iter(|| {
+                black_box(levenshtein::<i64>(&args).expect("levenshtein should work"))
+            })
+        },
+    );
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
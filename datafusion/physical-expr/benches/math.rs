@@ -0,0 +1,68 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, Int64Array};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+use datafusion_expr::ColumnarValue;
+use datafusion_physical_expr::math_expressions::{power, round, signum};
+
+const NUM_ROWS: usize = 1_000_000;
+
+fn values(rng: &mut ThreadRng) -> Float64Array {
+    (0..NUM_ROWS)
+        .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(-1000.0..1000.0)))
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let values = Arc::new(values(&mut rng)) as ArrayRef;
+
+    c.bench_function("round_f64_1000000", |b| {
+        let args = vec![values.clone()];
+        b.iter(|| black_box(round(&args).expect("round should work on valid values")))
+    });
+
+    c.bench_function("round_f64_decimal_places_1000000", |b| {
+        // a constant number of decimal places is passed as a single value
+        let decimal_places = Arc::new(Int64Array::from(vec![2])) as ArrayRef;
+        let args = vec![values.clone(), decimal_places];
+        b.iter(|| black_box(round(&args).expect("round should work on valid values")))
+    });
+
+    c.bench_function("power_f64_constant_exponent_1000000", |b| {
+        // a constant exponent is passed as a single value
+        let exponent = Arc::new(Float64Array::from(vec![2.0])) as ArrayRef;
+        let args = vec![values.clone(), exponent];
+        b.iter(|| black_box(power(&args).expect("power should work on valid values")))
+    });
+
+    c.bench_function("signum_f64_1000000", |b| {
+        let args = vec![ColumnarValue::Array(values.clone())];
+        b.iter(|| black_box(signum(&args).expect("signum should work on valid values")))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use std::sync::Arc;
+
+use arrow::datatypes::Int32Type;
+use arrow_array::{ArrayRef, StringArray};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+
+use datafusion_physical_expr::string_expressions::{ends_with, starts_with};
+use datafusion_physical_expr::unicode_expressions::strpos;
+
+const NUM_ROWS: usize = 1_000_000;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let strings: StringArray = (0..NUM_ROWS)
+        .map(|_| {
+            let len = rng.gen_range(16..64);
+            rng.gen_bool(0.9).then(|| {
+                (&mut rng)
+                    .sample_iter(&Alphanumeric)
+                    .take(len)
+                    .map(char::from)
+                    .collect::<String>()
+            })
+        })
+        .collect();
+    let strings = Arc::new(strings) as ArrayRef;
+
+    // a constant needle is passed as a single value, a column of needles row by row
+    let scalar = Arc::new(StringArray::from(vec!["ab"])) as ArrayRef;
+    let column = Arc::new(StringArray::from(vec!["ab"; NUM_ROWS])) as ArrayRef;
+
+    for (name, needle) in [("scalar", &scalar), ("array", &column)] {
+        let args = vec![strings.clone(), needle.clone()];
+
+        c.bench_function(&format!("strpos_{name}_1000000"), |b| {
+            b.iter(|| black_box(strpos::<Int32Type>(&args).expect("strpos should work")))
+        });
+
+        c.bench_function(&format!("starts_with_{name}_1000000"), |b| {
+            b.iter(|| {
+                black_box(starts_with::<i32>(&args).expect("starts_with should work"))
+            })
+        });
+
+        c.bench_function(&format!("ends_with_{name}_1000000"), |b| {
+            b.iter(|| black_box(ends_with::<i32>(&args).expect("ends_with should work")))
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
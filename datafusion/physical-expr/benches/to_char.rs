@@ -19,7 +19,7 @@ extern crate criterion;
 
 use std::sync::Arc;
 
-use arrow_array::{ArrayRef, Date32Array, StringArray};
+use arrow_array::{ArrayRef, Date32Array, StringArray, TimestampNanosecondArray};
 use chrono::prelude::*;
 use chrono::TimeDelta;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
@@ -79,6 +79,27 @@ fn patterns(rng: &mut ThreadRng) -> StringArray {
     StringArray::from(data)
 }
 
+fn timestamps(rng: &mut ThreadRng, size: usize) -> TimestampNanosecondArray {
+    let start = NaiveDate::from_ymd_opt(1970, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_nanos_opt()
+        .unwrap();
+    let end = NaiveDate::from_ymd_opt(2050, 12, 31)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_nanos_opt()
+        .unwrap();
+    (0..size)
+        .map(|_| rng.gen_range(start..end))
+        .collect::<Vec<_>>()
+        .into()
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("to_char_array_array_1000", |b| {
         let mut rng = rand::thread_rng();
@@ -107,6 +128,35 @@ fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
+    // the same format as a scalar (parsed once per batch) and as an array (parsed per row)
+    let format = "%Y-%m-%d %H:%M:%S%.3f";
+    c.bench_function("to_char_timestamps_scalar_format_1000000", |b| {
+        let mut rng = rand::thread_rng();
+        let data = ColumnarValue::Array(Arc::new(timestamps(&mut rng, 1_000_000)));
+        let pattern = ColumnarValue::Scalar(ScalarValue::Utf8(Some(format.to_string())));
+
+        b.iter(|| {
+            black_box(
+                to_char(&[data.clone(), pattern.clone()])
+                    .expect("to_char should work on valid values"),
+            )
+        })
+    });
+
+    c.bench_function("to_char_timestamps_array_format_1000000", |b| {
+        let mut rng = rand::thread_rng();
+        let data = ColumnarValue::Array(Arc::new(timestamps(&mut rng, 1_000_000)));
+        let pattern =
+            ColumnarValue::Array(Arc::new(StringArray::from(vec![format; 1_000_000])));
+
+        b.iter(|| {
+            black_box(
+                to_char(&[data.clone(), pattern.clone()])
+                    .expect("to_char should work on valid values"),
+            )
+        })
+    });
+
     c.bench_function("to_char_scalar_scalar_1000", |b| {
         let timestamp = "2026-07-08T09:10:11"
             .parse::<NaiveDateTime>()
@@ -0,0 +1,84 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Int64Array, StringArray};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::distributions::Alphanumeric;
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+use datafusion_physical_expr::unicode_expressions::{left, right, substr};
+
+const NUM_ROWS: usize = 1_000_000;
+
+fn strings(rng: &mut ThreadRng, multibyte: bool) -> StringArray {
+    (0..NUM_ROWS)
+        .map(|_| {
+            rng.gen_bool(0.9).then(|| {
+                let mut value: String = rng
+                    .sample_iter(&Alphanumeric)
+                    .take(32)
+                    .map(char::from)
+                    .collect();
+                if multibyte {
+                    value.push('é');
+                }
+                value
+            })
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let n = Arc::new(Int64Array::from(vec![16; NUM_ROWS])) as ArrayRef;
+    let start = Arc::new(Int64Array::from(vec![8; NUM_ROWS])) as ArrayRef;
+    let first = Arc::new(Int64Array::from(vec![1; NUM_ROWS])) as ArrayRef;
+
+    // ASCII values are sliced by byte offset, multibyte values decode characters
+    for (name, multibyte) in [("ascii", false), ("multibyte", true)] {
+        let strings = Arc::new(strings(&mut rng, multibyte)) as ArrayRef;
+
+        c.bench_function(&format!("left_{name}_1000000"), |b| {
+            let args = vec![strings.clone(), n.clone()];
+            b.iter(|| black_box(left::<i32>(&args).expect("left should work")))
+        });
+
+        c.bench_function(&format!("right_{name}_1000000"), |b| {
+            let args = vec![strings.clone(), n.clone()];
+            b.iter(|| black_box(right::<i32>(&args).expect("right should work")))
+        });
+
+        c.bench_function(&format!("substr_{name}_1000000"), |b| {
+            let args = vec![strings.clone(), start.clone(), n.clone()];
+            b.iter(|| black_box(substr::<i32>(&args).expect("substr should work")))
+        });
+
+        // values that are kept whole are not copied
+        c.bench_function(&format!("substr_whole_{name}_1000000"), |b| {
+            let args = vec![strings.clone(), first.clone()];
+            b.iter(|| black_box(substr::<i32>(&args).expect("substr should work")))
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -31,11 +31,14 @@ use arrow_buffer::{ArrowNativeType, NullBuffer};
 use arrow_schema::FieldRef;
 use datafusion_common::cast::{
     as_generic_list_array, as_int64_array, as_large_list_array, as_list_array,
+    as_map_array, as_string_array, as_struct_array,
+};
+use datafusion_common::utils::{
+    array_into_list_array, is_untyped_list, set_op_common_type,
 };
-use datafusion_common::utils::array_into_list_array;
 use datafusion_common::{
-    exec_err, internal_datafusion_err, internal_err, plan_err, DataFusionError, Result,
-    ScalarValue,
+    exec_datafusion_err, exec_err, internal_datafusion_err, internal_err, plan_err,
+    DataFusionError, Result, ScalarValue,
 };
 use itertools::Itertools;
 
@@ -82,6 +85,7 @@ fn compare_element_to_list(
 
     let indices = UInt32Array::from(vec![row_index as u32]);
     let element_array_row = arrow::compute::take(element_array, &indices, None)?;
+    let element_is_null = element_array_row.is_null(0);
 
     // Compute all positions in list_row_array (that is itself an
     // array) that are equal to `from_array_row`
@@ -94,15 +98,13 @@ fn compare_element_to_list(
 
             list_array_row_inner
                 .iter()
-                // compare element by element the current row of list_array
+                // compare element by element the current row of list_array,
+                // NULL elements are only equal to a NULL element
                 .map(|row| {
-                    row.map(|row| {
-                        if eq {
-                            row.eq(&element_array_row_inner)
-                        } else {
-                            row.ne(&element_array_row_inner)
-                        }
-                    })
+                    let equal = row.map_or(element_is_null, |row| {
+                        !element_is_null && row.eq(&element_array_row_inner)
+                    });
+                    Some(equal == eq)
                 })
                 .collect::<BooleanArray>()
         }
@@ -114,15 +116,13 @@ fn compare_element_to_list(
 
             list_array_row_inner
                 .iter()
-                // compare element by element the current row of list_array
+                // compare element by element the current row of list_array,
+                // NULL elements are only equal to a NULL element
                 .map(|row| {
-                    row.map(|row| {
-                        if eq {
-                            row.eq(&element_array_row_inner)
-                        } else {
-                            row.ne(&element_array_row_inner)
-                        }
-                    })
+                    let equal = row.map_or(element_is_null, |row| {
+                        !element_is_null && row.eq(&element_array_row_inner)
+                    });
+                    Some(equal == eq)
                 })
                 .collect::<BooleanArray>()
         }
@@ -271,10 +271,16 @@ pub fn make_array(arrays: &[ArrayRef]) -> Result<ArrayRef> {
 fn general_array_element<O: OffsetSizeTrait>(
     array: &GenericListArray<O>,
     indexes: &Int64Array,
+    out_of_bounds_error: bool,
 ) -> Result<ArrayRef>
 where
     i64: TryInto<O>,
 {
+    // the elements of an untyped null list are all NULL
+    if array.value_type() == DataType::Null {
+        return Ok(new_null_array(&DataType::Null, array.len()));
+    }
+
     let values = array.values();
     let original_data = values.to_data();
     let capacity = Capacities::Array(original_data.len());
@@ -313,17 +319,28 @@ where
         let end = offset_window[1];
         let len = end - start;
 
-        // array is null
-        if len == O::usize_as(0) {
+        // array is null, or index is null. A null list may still cover a
+        // non-empty range of the child values, so the validity is checked explicitly.
+        if array.is_null(row_index) || indexes.is_null(row_index) {
             mutable.extend_nulls(1);
             continue;
         }
 
-        let index = adjusted_array_index::<O>(indexes.value(row_index), len)?;
+        let index = if len == O::usize_as(0) {
+            None
+        } else {
+            adjusted_array_index::<O>(indexes.value(row_index), len)?
+        };
 
         if let Some(index) = index {
             let start = start.as_usize() + index.as_usize();
             mutable.extend(0, start, start + 1_usize);
+        } else if out_of_bounds_error {
+            return exec_err!(
+                "array_element index {} is out of bounds for array of length {}",
+                indexes.value(row_index),
+                len.as_usize()
+            );
         } else {
             // Index out of bounds
             mutable.extend_nulls(1);
@@ -341,7 +358,19 @@ where
 ///
 /// For example:
 /// > array_element(\[1, 2, 3], 2) -> 2
+///
+/// Indexes are 1-based, so index 0 is out of bounds like an index past either end
+/// of the array. An out of bounds index returns NULL.
 pub fn array_element(args: &[ArrayRef]) -> Result<ArrayRef> {
+    array_element_with_bounds_check(args, false)
+}
+
+/// Same as [`array_element`], but returns an error for an out of bounds index
+/// when `out_of_bounds_error` is true.
+pub fn array_element_with_bounds_check(
+    args: &[ArrayRef],
+    out_of_bounds_error: bool,
+) -> Result<ArrayRef> {
     if args.len() != 2 {
         return exec_err!("array_element needs two arguments");
     }
@@ -350,12 +379,12 @@ pub fn array_element(args: &[ArrayRef]) -> Result<ArrayRef> {
         DataType::List(_) => {
             let array = as_list_array(&args[0])?;
             let indexes = as_int64_array(&args[1])?;
-            general_array_element::<i32>(array, indexes)
+            general_array_element::<i32>(array, indexes, out_of_bounds_error)
         }
         DataType::LargeList(_) => {
             let array = as_large_list_array(&args[0])?;
             let indexes = as_int64_array(&args[1])?;
-            general_array_element::<i64>(array, indexes)
+            general_array_element::<i64>(array, indexes, out_of_bounds_error)
         }
         _ => exec_err!(
             "array_element does not support type: {:?}",
@@ -364,6 +393,166 @@ pub fn array_element(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// element_at SQL function
+///
+/// Returns the element at the 1-indexed index of a list, or the value for the key of a map.
+/// `element_at(container, key_or_index)`
+///
+/// For example:
+/// > element_at(\[1, 2, 3], 2) -> 2
+/// > element_at(MAP {'a': 1, 'b': 2}, 'b') -> 2
+pub fn element_at(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 2 {
+        return exec_err!("element_at needs two arguments");
+    }
+
+    match &args[0].data_type() {
+        DataType::List(_) | DataType::LargeList(_) => {
+            let indexes = compute::cast(&args[1], &DataType::Int64)?;
+            array_element(&[args[0].clone(), indexes])
+        }
+        DataType::FixedSizeList(field, _) => {
+            let array = compute::cast(&args[0], &DataType::List(field.clone()))?;
+            let indexes = compute::cast(&args[1], &DataType::Int64)?;
+            array_element(&[array, indexes])
+        }
+        DataType::Map(_, _) => {
+            let map_array = as_map_array(&args[0])?;
+            general_map_element(map_array, &args[1])
+        }
+        DataType::Struct(_) => {
+            let struct_array = as_struct_array(&args[0])?;
+            general_struct_element(struct_array, &args[1])
+        }
+        // an element of a NULL container is NULL
+        DataType::Null => Ok(new_null_array(&DataType::Null, args[0].len())),
+        _ => exec_err!(
+            "element_at does not support type: {:?}",
+            args[0].data_type()
+        ),
+    }
+}
+
+/// Looks up the field of each struct named by `names` in the same row. Rows with a
+/// null struct or name are null.
+fn general_struct_element(
+    struct_array: &StructArray,
+    names: &ArrayRef,
+) -> Result<ArrayRef> {
+    let names = compute::cast(names, &DataType::Utf8)?;
+    let names = as_string_array(&names)?;
+
+    let column_index = |name: &str| {
+        struct_array
+            .column_names()
+            .iter()
+            .position(|column| *column == name)
+            .ok_or_else(|| exec_datafusion_err!("Field {name} not found in struct"))
+    };
+
+    // a constant name selects a whole column, which only needs the nulls of the structs
+    if names.null_count() == 0 && !names.is_empty() {
+        let first = names.value(0);
+        if names.iter().all(|name| name == Some(first)) {
+            let column = struct_array.column(column_index(first)?);
+            let nulls = NullBuffer::union(struct_array.nulls(), column.nulls());
+            let data = column.to_data().into_builder().nulls(nulls).build()?;
+            return Ok(arrow_array::make_array(data));
+        }
+    }
+
+    // otherwise pick the value of each row from the column it names, or from a single
+    // null value for null rows
+    let mut columns: Vec<usize> = vec![];
+    let mut indices = Vec::with_capacity(names.len());
+    for (row, name) in names.iter().enumerate() {
+        let index = match name {
+            Some(name) if struct_array.is_valid(row) => {
+                let column = column_index(name)?;
+                let position = match columns.iter().position(|c| *c == column) {
+                    Some(position) => position,
+                    None => {
+                        columns.push(column);
+                        columns.len() - 1
+                    }
+                };
+                (position + 1, row)
+            }
+            _ => (0, 0),
+        };
+        indices.push(index);
+    }
+
+    let value_type = match columns.first() {
+        Some(&first) => struct_array.column(first).data_type().clone(),
+        None => {
+            let value_type = struct_array
+                .columns()
+                .first()
+                .map_or(DataType::Null, |c| c.data_type().clone());
+            return Ok(new_null_array(&value_type, struct_array.len()));
+        }
+    };
+    if let Some(&other) = columns
+        .iter()
+        .find(|&&c| struct_array.column(c).data_type() != &value_type)
+    {
+        return exec_err!(
+            "element_at requires the fields it looks up to have the same type, but got {value_type:?} and {:?}",
+            struct_array.column(other).data_type()
+        );
+    }
+
+    let null_value = new_null_array(&value_type, 1);
+    let values = std::iter::once(null_value.as_ref())
+        .chain(columns.iter().map(|&c| struct_array.column(c).as_ref()))
+        .collect::<Vec<_>>();
+    Ok(compute::interleave(&values, &indices)?)
+}
+
+/// Looks up the value of the first entry of each map whose key equals `keys` in the
+/// same row. Rows without a matching key, or with a null map or key, are null.
+fn general_map_element(map_array: &MapArray, keys: &ArrayRef) -> Result<ArrayRef> {
+    let key_type = map_array.key_type();
+    let keys = if keys.data_type() == key_type {
+        keys.clone()
+    } else {
+        compute::cast(keys, key_type)?
+    };
+
+    let offsets = map_array.value_offsets();
+    let first_entry = offsets[0].as_usize();
+    let num_entries = offsets[map_array.len()].as_usize() - first_entry;
+
+    // repeat the key of each row for all of its entries, to compare all keys at once
+    let entry_rows =
+        UInt32Array::from_iter_values(offsets.windows(2).enumerate().flat_map(
+            |(row, w)| std::iter::repeat(row as u32).take((w[1] - w[0]) as usize),
+        ));
+    let lookup_keys = compute::take(&keys, &entry_rows, None)?;
+    let map_keys = map_array.keys().slice(first_entry, num_entries);
+    let matches = compute::kernels::cmp::eq(&map_keys, &lookup_keys)?;
+
+    let indices = offsets
+        .windows(2)
+        .enumerate()
+        .map(|(row, w)| {
+            // a null map may still have entries, which must not be returned
+            if map_array.is_null(row) {
+                return None;
+            }
+            (w[0].as_usize()..w[1].as_usize())
+                .find(|&entry| {
+                    let i = entry - first_entry;
+                    matches.is_valid(i) && matches.value(i)
+                })
+                .map(|entry| entry as u32)
+        })
+        .collect::<UInt32Array>();
+
+    Ok(compute::take(map_array.values(), &indices, None)?)
+}
+
 fn general_except<OffsetSize: OffsetSizeTrait>(
     l: &GenericListArray<OffsetSize>,
     r: &GenericListArray<OffsetSize>,
@@ -382,13 +571,23 @@ fn general_except<OffsetSize: OffsetSizeTrait>(
     let mut rows = Vec::with_capacity(l_values.num_rows());
     let mut dedup = HashSet::new();
 
-    for (l_w, r_w) in l.offsets().windows(2).zip(r.offsets().windows(2)) {
+    for (row_index, (l_w, r_w)) in l
+        .offsets()
+        .windows(2)
+        .zip(r.offsets().windows(2))
+        .enumerate()
+    {
         let l_slice = l_w[0].as_usize()..l_w[1].as_usize();
         let r_slice = r_w[0].as_usize()..r_w[1].as_usize();
-        for i in r_slice {
-            let right_row = r_values.row(i);
-            dedup.insert(right_row);
+        // a null list on the right removes nothing
+        if r.is_valid(row_index) {
+            for i in r_slice {
+                let right_row = r_values.row(i);
+                dedup.insert(right_row);
+            }
         }
+        // elements on the right and those already returned are skipped, so the
+        // result has no duplicates
         for i in l_slice {
             let left_row = l_values.row(i);
             if dedup.insert(left_row) {
@@ -412,25 +611,30 @@ fn general_except<OffsetSize: OffsetSizeTrait>(
     }
 }
 
+/// Array_except SQL function
+///
+/// Returns the distinct elements of the first array that are not in the second,
+/// in the order of their first occurrence, as in Spark: `array_except([1, 1, 2, 3], [2])`
+/// is `[1, 3]`. Nulls are compared as equal, so a null element is removed when the
+/// second array contains a null.
 pub fn array_except(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.len() != 2 {
         return exec_err!("array_except needs two arguments");
     }
 
-    let array1 = &args[0];
-    let array2 = &args[1];
+    let (array1, array2) = align_set_op_args(&args[0], &args[1])?;
 
     match (array1.data_type(), array2.data_type()) {
-        (DataType::Null, _) | (_, DataType::Null) => Ok(array1.to_owned()),
+        (DataType::Null, DataType::Null) => Ok(array1),
         (DataType::List(field), DataType::List(_)) => {
-            check_datatypes("array_except", &[array1, array2])?;
+            check_datatypes("array_except", &[&array1, &array2])?;
             let list1 = array1.as_list::<i32>();
             let list2 = array2.as_list::<i32>();
             let result = general_except::<i32>(list1, list2, field)?;
             Ok(Arc::new(result))
         }
         (DataType::LargeList(field), DataType::LargeList(_)) => {
-            check_datatypes("array_except", &[array1, array2])?;
+            check_datatypes("array_except", &[&array1, &array2])?;
             let list1 = array1.as_list::<i64>();
             let list2 = array2.as_list::<i64>();
             let result = general_except::<i64>(list1, list2, field)?;
@@ -442,6 +646,27 @@ pub fn array_except(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// Brings the arguments of `array_union`, `array_intersect` and `array_except`
+/// to a common type: an untyped argument takes the type of the other argument,
+/// a `NULL` becoming a null list of that type. This mirrors their return type.
+fn align_set_op_args(
+    array1: &ArrayRef,
+    array2: &ArrayRef,
+) -> Result<(ArrayRef, ArrayRef)> {
+    let data_type = set_op_common_type(array1.data_type(), array2.data_type());
+
+    let align = |array: &ArrayRef| -> Result<ArrayRef> {
+        if array.data_type() == &data_type || !is_untyped_list(array.data_type()) {
+            Ok(array.clone())
+        } else if array.data_type().is_null() {
+            Ok(new_null_array(&data_type, array.len()))
+        } else {
+            Ok(compute::cast(array, &data_type)?)
+        }
+    };
+    Ok((align(array1)?, align(array2)?))
+}
+
 /// array_slice SQL function
 ///
 /// We follow the behavior of array_slice in DuckDB
@@ -453,12 +678,23 @@ pub fn array_except(args: &[ArrayRef]) -> Result<ArrayRef> {
 /// `from` index is smaller than 1, it is treated as 1. If the `to` index is larger than the
 /// length of the array, it is treated as the length of the array.
 ///
-/// Negative index is treated as the index from the end of the array. If the index
-/// is larger than the length of the array, it is NOT VALID, either in `from` or `to`.
+/// Negative index is treated as the index from the end of the array. A `from` index
+/// before the first element is treated as 1, the same clamping `substr` applies to a
+/// start before the beginning of a string, while a `to` index before the first
+/// element is NOT VALID.
 /// The `to` index is exclusive like python slice syntax.
 ///
 /// See test cases in `array.slt` for more details.
 pub fn array_slice(args: &[ArrayRef]) -> Result<ArrayRef> {
+    array_slice_with_bounds_check(args, false)
+}
+
+/// Same as [`array_slice`], but returns an error instead of an empty array when
+/// `from` or `to` is out of bounds and `out_of_bounds_error` is true.
+pub fn array_slice_with_bounds_check(
+    args: &[ArrayRef],
+    out_of_bounds_error: bool,
+) -> Result<ArrayRef> {
     let args_len = args.len();
     if args_len != 3 && args_len != 4 {
         return exec_err!("array_slice needs three or four arguments");
@@ -477,13 +713,25 @@ pub fn array_slice(args: &[ArrayRef]) -> Result<ArrayRef> {
     match array_data_type {
         DataType::List(_) => {
             let array = as_list_array(&args[0])?;
-            general_array_slice::<i32>(array, from_array, to_array, stride)
+            general_array_slice::<i32>(
+                array,
+                from_array,
+                to_array,
+                stride,
+                out_of_bounds_error,
+            )
         }
         DataType::LargeList(_) => {
             let array = as_large_list_array(&args[0])?;
             let from_array = as_int64_array(&args[1])?;
             let to_array = as_int64_array(&args[2])?;
-            general_array_slice::<i64>(array, from_array, to_array, stride)
+            general_array_slice::<i64>(
+                array,
+                from_array,
+                to_array,
+                stride,
+                out_of_bounds_error,
+            )
         }
         _ => exec_err!("array_slice does not support type: {:?}", array_data_type),
     }
@@ -494,6 +742,7 @@ fn general_array_slice<O: OffsetSizeTrait>(
     from_array: &Int64Array,
     to_array: &Int64Array,
     stride: Option<&Int64Array>,
+    out_of_bounds_error: bool,
 ) -> Result<ArrayRef>
 where
     i64: TryInto<O>,
@@ -515,8 +764,10 @@ where
     {
         // 0 ~ len - 1
         let adjusted_zero_index = if index < 0 {
+            // like a start before the beginning of the string in `substr`, a negative
+            // index before the first element is clamped to it
             if let Ok(index) = index.try_into() {
-                index + len
+                std::cmp::max(index + len, O::usize_as(0))
             } else {
                 return exec_err!("array_slice got invalid index: {}", index);
             }
@@ -573,88 +824,92 @@ where
         let end = offset_window[1];
         let len = end - start;
 
-        // len 0 indicate array is null, return empty array in this row.
-        if len == O::usize_as(0) {
+        // a null list, or len 0 when out of bounds indexes are allowed, return empty
+        // array in this row.
+        if array.is_null(row_index) || (len == O::usize_as(0) && !out_of_bounds_error) {
             offsets.push(offsets[row_index]);
             continue;
         }
 
+        let stride = stride.map(|s| s.value(row_index));
+        if let Some(stride @ 0) = stride {
+            return exec_err!(
+                "array_slice got invalid stride: {:?}, it cannot be 0",
+                stride
+            );
+        }
+        let reverse = stride.is_some_and(|s| s < 0);
+
         // If index is null, we consider it as the minimum / maximum index of the array.
+        // When slicing in reverse, `from` is clamped to the last element and `to` to
+        // the first, so the bounds rules of the two indexes are swapped.
         let from_index = if from_array.is_null(row_index) {
-            Some(O::usize_as(0))
+            Some(if reverse {
+                len - O::usize_as(1)
+            } else {
+                O::usize_as(0)
+            })
+        } else if reverse {
+            adjusted_to_index::<O>(from_array.value(row_index), len)?
         } else {
             adjusted_from_index::<O>(from_array.value(row_index), len)?
         };
 
         let to_index = if to_array.is_null(row_index) {
-            Some(len - O::usize_as(1))
+            Some(if reverse {
+                O::usize_as(0)
+            } else {
+                len - O::usize_as(1)
+            })
+        } else if reverse {
+            adjusted_from_index::<O>(to_array.value(row_index), len)?
         } else {
             adjusted_to_index::<O>(to_array.value(row_index), len)?
         };
 
         if let (Some(from), Some(to)) = (from_index, to_index) {
-            let stride = stride.map(|s| s.value(row_index));
-            // array_slice with stride in duckdb, return empty array if stride is not supported and from > to.
-            if stride.is_none() && from > to {
-                // return empty array
+            // a forward slice with from > to, or a reverse slice with from < to,
+            // returns an empty array.
+            if (!reverse && from > to) || (reverse && from < to) {
                 offsets.push(offsets[row_index]);
                 continue;
             }
             let stride = stride.unwrap_or(1);
-            if stride.is_zero() {
-                return exec_err!(
-                    "array_slice got invalid stride: {:?}, it cannot be 0",
-                    stride
+
+            if stride == 1 {
+                assert!(start + to < end);
+                mutable.extend(
+                    0,
+                    (start + from).to_usize().unwrap(),
+                    (start + to + O::usize_as(1)).to_usize().unwrap(),
                 );
-            } else if from <= to && stride.is_negative() {
-                // return empty array
-                offsets.push(offsets[row_index]);
+                offsets.push(offsets[row_index] + (to - from + O::usize_as(1)));
                 continue;
             }
 
-            let stride: O = stride.try_into().map_err(|_| {
-                internal_datafusion_err!("array_slice got invalid stride: {}", stride)
-            })?;
-
-            if from <= to {
-                assert!(start + to <= end);
-                if stride.eq(&O::one()) {
-                    // stride is default to 1
-                    mutable.extend(
-                        0,
-                        (start + from).to_usize().unwrap(),
-                        (start + to + O::usize_as(1)).to_usize().unwrap(),
-                    );
-                    offsets.push(offsets[row_index] + (to - from + O::usize_as(1)));
-                    continue;
-                }
-                let mut index = start + from;
-                let mut cnt = 0;
-                while index <= start + to {
-                    mutable.extend(
-                        0,
-                        index.to_usize().unwrap(),
-                        index.to_usize().unwrap() + 1,
-                    );
-                    index += stride;
-                    cnt += 1;
-                }
-                offsets.push(offsets[row_index] + O::usize_as(cnt));
-            } else {
-                let mut index = start + from;
-                let mut cnt = 0;
-                while index >= start + to {
-                    mutable.extend(
-                        0,
-                        index.to_usize().unwrap(),
-                        index.to_usize().unwrap() + 1,
-                    );
-                    index += stride;
-                    cnt += 1;
-                }
-                // invalid range, return empty array
-                offsets.push(offsets[row_index] + O::usize_as(cnt));
+            // the number of elements taken walking from `from` to `to` (both
+            // inclusive) by `stride`
+            let from = from.as_usize();
+            let to = to.as_usize();
+            let step = stride.unsigned_abs() as usize;
+            let cnt = from.abs_diff(to) / step + 1;
+            for i in 0..cnt {
+                let index = if reverse {
+                    from - i * step
+                } else {
+                    from + i * step
+                };
+                let index = start.as_usize() + index;
+                mutable.extend(0, index, index + 1);
             }
+            offsets.push(offsets[row_index] + O::usize_as(cnt));
+        } else if out_of_bounds_error {
+            return exec_err!(
+                "array_slice range [{}, {}] is out of bounds for array of length {}",
+                from_array.value(row_index),
+                to_array.value(row_index),
+                len.as_usize()
+            );
         } else {
             // invalid range, return empty array
             offsets.push(offsets[row_index]);
@@ -663,8 +918,15 @@ where
 
     let data = mutable.freeze();
 
+    // keep the field of the input, so that the result has the type `return_type`
+    // promises, e.g. a non-nullable struct field of a `List(Struct)`
+    let field = match array.data_type() {
+        DataType::List(field) | DataType::LargeList(field) => field.clone(),
+        data_type => return internal_err!("array_slice got unexpected type {data_type}"),
+    };
+
     Ok(Arc::new(GenericListArray::<O>::try_new(
-        Arc::new(Field::new("item", array.value_type(), true)),
+        field,
         OffsetBuffer::<O>::new(offsets.into()),
         arrow_array::make_array(data),
         None,
@@ -684,7 +946,7 @@ where
             .map(|arr| arr.map_or(0, |arr| arr.len() as i64))
             .collect::<Vec<i64>>(),
     );
-    general_array_slice::<O>(array, &from_array, &to_array, None)
+    general_array_slice::<O>(array, &from_array, &to_array, None, false)
 }
 
 fn general_pop_back_list<O: OffsetSizeTrait>(
@@ -700,7 +962,7 @@ where
             .map(|arr| arr.map_or(0, |arr| arr.len() as i64 - 1))
             .collect::<Vec<i64>>(),
     );
-    general_array_slice::<O>(array, &from_array, &to_array, None)
+    general_array_slice::<O>(array, &from_array, &to_array, None, false)
 }
 
 /// array_pop_front SQL function
@@ -985,13 +1247,125 @@ fn array_remove_internal(
     }
 }
 
+/// Returns the values of `list_array` that are referenced by its offsets
+fn referenced_values<O: OffsetSizeTrait>(list_array: &GenericListArray<O>) -> ArrayRef {
+    let offsets = list_array.value_offsets();
+    let first = offsets[0].as_usize();
+    let last = offsets[offsets.len() - 1].as_usize();
+    list_array.values().slice(first, last - first)
+}
+
+/// Returns `element_array` as a [`Datum`] that lines up with the
+/// [`referenced_values`] of `list_array`, so that the elements of all rows can
+/// be compared to it with a single kernel call. A single value is compared to
+/// the elements of every row as a scalar, otherwise the value of each row is
+/// repeated for each of its elements.
+fn element_datum<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    element_array: &ArrayRef,
+) -> Result<Box<dyn Datum>> {
+    if element_array.len() == 1 {
+        return Ok(Box::new(Scalar::new(element_array.clone())));
+    }
+
+    let offsets = list_array.value_offsets();
+    let indices = offsets.windows(2).enumerate().flat_map(|(row_index, w)| {
+        std::iter::repeat(row_index as u32).take((w[1] - w[0]).as_usize())
+    });
+    let indices = UInt32Array::from_iter_values(indices);
+    Ok(Box::new(compute::take(element_array, &indices, None)?))
+}
+
+/// Repeats a single value `array` `len` times, for the kernels that index it by row
+fn broadcast(array: &ArrayRef, len: usize) -> Result<ArrayRef> {
+    if array.len() == 1 && len != 1 {
+        let indices = UInt32Array::from(vec![0; len]);
+        Ok(compute::take(array, &indices, None)?)
+    } else {
+        Ok(array.clone())
+    }
+}
+
+/// Removes every occurrence of `element_array[i]` from `list_array[i]`, or of
+/// `element_array[0]` from every row if it has a single value. NULL elements
+/// are removed only by a NULL element.
+///
+/// Unlike [`general_remove`], which compares and filters one row at a time,
+/// the elements of all rows are compared with a single kernel call and
+/// filtered with another. Nested elements, which the comparison kernels do not
+/// support, are removed by [`general_remove`].
+fn general_remove_all<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    element_array: &ArrayRef,
+) -> Result<ArrayRef> {
+    let values = referenced_values(list_array);
+    if values.data_type().is_nested() {
+        let element_array = broadcast(element_array, list_array.len())?;
+        let arr_n = vec![i64::MAX; list_array.len()];
+        return general_remove(list_array, &element_array, arr_n);
+    }
+    if values.data_type() != element_array.data_type() {
+        return exec_err!(
+            "array_remove_all received incompatible types: '{:?}' and '{:?}'.",
+            values.data_type(),
+            element_array.data_type()
+        );
+    }
+
+    let element = element_datum(list_array, element_array)?;
+    let keep = arrow_ord::cmp::distinct(&values, element.as_ref())?;
+    let value_offsets = list_array.value_offsets();
+    // the elements of null rows are not kept
+    let keep = match list_array.nulls() {
+        Some(nulls) if nulls.null_count() > 0 => {
+            let mut valid = BooleanBufferBuilder::new(values.len());
+            for (row_index, w) in value_offsets.windows(2).enumerate() {
+                valid.append_n((w[1] - w[0]).as_usize(), nulls.is_valid(row_index));
+            }
+            BooleanArray::new(keep.values() & &valid.finish(), None)
+        }
+        _ => keep,
+    };
+
+    let first = value_offsets[0];
+    let mut offsets = Vec::<O>::with_capacity(list_array.len() + 1);
+    offsets.push(O::zero());
+    for (row_index, w) in value_offsets.windows(2).enumerate() {
+        let start = (w[0] - first).as_usize();
+        let kept = keep
+            .values()
+            .slice(start, (w[1] - w[0]).as_usize())
+            .count_set_bits();
+        offsets.push(offsets[row_index] + O::usize_as(kept));
+    }
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        Arc::new(Field::new("item", list_array.value_type(), true)),
+        OffsetBuffer::new(offsets.into()),
+        compute::filter(&values, &keep)?,
+        list_array.nulls().cloned(),
+    )?))
+}
+
 pub fn array_remove_all(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.len() != 2 {
         return exec_err!("array_remove_all expects two arguments");
     }
 
-    let arr_n = vec![i64::MAX; args[0].len()];
-    array_remove_internal(&args[0], &args[1], arr_n)
+    let array = &args[0];
+    match array.data_type() {
+        DataType::List(_) => {
+            let list_array = array.as_list::<i32>();
+            general_remove_all::<i32>(list_array, &args[1])
+        }
+        DataType::LargeList(_) => {
+            let list_array = array.as_list::<i64>();
+            general_remove_all::<i64>(list_array, &args[1])
+        }
+        array_type => {
+            exec_err!("array_remove_all does not support type '{array_type:?}'.")
+        }
+    }
 }
 
 pub fn array_remove(args: &[ArrayRef]) -> Result<ArrayRef> {
@@ -1035,6 +1409,14 @@ fn general_replace<O: OffsetSizeTrait>(
     to_array: &ArrayRef,
     arr_n: Vec<i64>,
 ) -> Result<ArrayRef> {
+    if to_array.data_type() != &list_array.value_type() {
+        return exec_err!(
+            "array_replace received incompatible types: '{:?}' and '{:?}'.",
+            list_array.value_type(),
+            to_array.data_type()
+        );
+    }
+
     // Build up the offsets for the final output array
     let mut offsets: Vec<O> = vec![O::usize_as(0)];
     let values = list_array.values();
@@ -1123,6 +1505,98 @@ fn general_replace<O: OffsetSizeTrait>(
     )?))
 }
 
+/// Replaces every occurrence of `from_array[i]` in `list_array[i]` with
+/// `to_array[i]`, or of `from_array[0]` with `to_array[0]` in every row if
+/// they have a single value. NULL elements are replaced only by a NULL element.
+///
+/// Unlike [`general_replace`], which compares and copies one row at a time,
+/// the elements of all rows are compared with a single kernel call and
+/// replaced with another. Nested elements, which the comparison kernels do not
+/// support, are replaced by [`general_replace`].
+fn general_replace_all<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    from_array: &ArrayRef,
+    to_array: &ArrayRef,
+) -> Result<ArrayRef> {
+    let values = referenced_values(list_array);
+    if values.data_type().is_nested() {
+        let from_array = broadcast(from_array, list_array.len())?;
+        let to_array = broadcast(to_array, list_array.len())?;
+        let arr_n = vec![i64::MAX; list_array.len()];
+        return general_replace(list_array, &from_array, &to_array, arr_n);
+    }
+    for element_array in [from_array, to_array] {
+        if values.data_type() != element_array.data_type() {
+            return exec_err!(
+                "array_replace_all received incompatible types: '{:?}' and '{:?}'.",
+                values.data_type(),
+                element_array.data_type()
+            );
+        }
+    }
+
+    let from = element_datum(list_array, from_array)?;
+    let to = element_datum(list_array, to_array)?;
+    let eq = arrow_ord::cmp::not_distinct(&values, from.as_ref())?;
+    let values = compute::kernels::zip::zip(&eq, to.as_ref(), &values)?;
+
+    let first = list_array.value_offsets()[0];
+    let offsets = list_array.offsets().iter().map(|offset| *offset - first);
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        Arc::new(Field::new("item", list_array.value_type(), true)),
+        OffsetBuffer::new(offsets.collect::<Vec<_>>().into()),
+        values,
+        list_array.nulls().cloned(),
+    )?))
+}
+
+/// Returns `array` as an array of the element type of `list_array` if it is
+/// an untyped NULL, so that a NULL can be both the element to replace and its
+/// replacement.
+fn null_as_element_type<O: OffsetSizeTrait>(
+    list_array: &GenericListArray<O>,
+    array: &ArrayRef,
+) -> ArrayRef {
+    if array.data_type() == &DataType::Null {
+        new_null_array(&list_array.value_type(), array.len())
+    } else {
+        array.clone()
+    }
+}
+
+/// Dispatches `array_replace`, `array_replace_n` and `array_replace_all` on
+/// the type of the list array, where `arr_n` is the number of occurrences to
+/// replace in each row, or `None` to replace all of them
+fn array_replace_internal(
+    name: &str,
+    array: &ArrayRef,
+    from_array: &ArrayRef,
+    to_array: &ArrayRef,
+    arr_n: Option<Vec<i64>>,
+) -> Result<ArrayRef> {
+    fn replace<O: OffsetSizeTrait>(
+        list_array: &GenericListArray<O>,
+        from_array: &ArrayRef,
+        to_array: &ArrayRef,
+        arr_n: Option<Vec<i64>>,
+    ) -> Result<ArrayRef> {
+        let from_array = null_as_element_type(list_array, from_array);
+        let to_array = null_as_element_type(list_array, to_array);
+        match arr_n {
+            Some(arr_n) => general_replace(list_array, &from_array, &to_array, arr_n),
+            None => general_replace_all(list_array, &from_array, &to_array),
+        }
+    }
+
+    match array.data_type() {
+        DataType::List(_) => replace(array.as_list::<i32>(), from_array, to_array, arr_n),
+        DataType::LargeList(_) => {
+            replace(array.as_list::<i64>(), from_array, to_array, arr_n)
+        }
+        array_type => exec_err!("{name} does not support type '{array_type:?}'."),
+    }
+}
+
 pub fn array_replace(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.len() != 3 {
         return exec_err!("array_replace expects three arguments");
@@ -1130,18 +1604,7 @@ pub fn array_replace(args: &[ArrayRef]) -> Result<ArrayRef> {
 
     // replace at most one occurence for each element
     let arr_n = vec![1; args[0].len()];
-    let array = &args[0];
-    match array.data_type() {
-        DataType::List(_) => {
-            let list_array = array.as_list::<i32>();
-            general_replace::<i32>(list_array, &args[1], &args[2], arr_n)
-        }
-        DataType::LargeList(_) => {
-            let list_array = array.as_list::<i64>();
-            general_replace::<i64>(list_array, &args[1], &args[2], arr_n)
-        }
-        array_type => exec_err!("array_replace does not support type '{array_type:?}'."),
-    }
+    array_replace_internal("array_replace", &args[0], &args[1], &args[2], Some(arr_n))
 }
 
 pub fn array_replace_n(args: &[ArrayRef]) -> Result<ArrayRef> {
@@ -1151,20 +1614,7 @@ pub fn array_replace_n(args: &[ArrayRef]) -> Result<ArrayRef> {
 
     // replace the specified number of occurences
     let arr_n = as_int64_array(&args[3])?.values().to_vec();
-    let array = &args[0];
-    match array.data_type() {
-        DataType::List(_) => {
-            let list_array = array.as_list::<i32>();
-            general_replace::<i32>(list_array, &args[1], &args[2], arr_n)
-        }
-        DataType::LargeList(_) => {
-            let list_array = array.as_list::<i64>();
-            general_replace::<i64>(list_array, &args[1], &args[2], arr_n)
-        }
-        array_type => {
-            exec_err!("array_replace_n does not support type '{array_type:?}'.")
-        }
-    }
+    array_replace_internal("array_replace_n", &args[0], &args[1], &args[2], Some(arr_n))
 }
 
 pub fn array_replace_all(args: &[ArrayRef]) -> Result<ArrayRef> {
@@ -1172,22 +1622,8 @@ pub fn array_replace_all(args: &[ArrayRef]) -> Result<ArrayRef> {
         return exec_err!("array_replace_all expects three arguments");
     }
 
-    // replace all occurrences (up to "i64::MAX")
-    let arr_n = vec![i64::MAX; args[0].len()];
-    let array = &args[0];
-    match array.data_type() {
-        DataType::List(_) => {
-            let list_array = array.as_list::<i32>();
-            general_replace::<i32>(list_array, &args[1], &args[2], arr_n)
-        }
-        DataType::LargeList(_) => {
-            let list_array = array.as_list::<i64>();
-            general_replace::<i64>(list_array, &args[1], &args[2], arr_n)
-        }
-        array_type => {
-            exec_err!("array_replace_all does not support type '{array_type:?}'.")
-        }
-    }
+    // replace all occurrences
+    array_replace_internal("array_replace_all", &args[0], &args[1], &args[2], None)
 }
 
 #[derive(Debug, PartialEq)]
@@ -1211,71 +1647,83 @@ fn generic_set_lists<OffsetSize: OffsetSizeTrait>(
     field: Arc<Field>,
     set_op: SetOp,
 ) -> Result<ArrayRef> {
-    if matches!(l.value_type(), DataType::Null) {
-        let field = Arc::new(Field::new("item", r.value_type(), true));
-        return general_array_distinct::<OffsetSize>(r, &field);
-    } else if matches!(r.value_type(), DataType::Null) {
-        let field = Arc::new(Field::new("item", l.value_type(), true));
-        return general_array_distinct::<OffsetSize>(l, &field);
-    }
-
     if l.value_type() != r.value_type() {
         return internal_err!("{set_op:?} is not implemented for '{l:?}' and '{r:?}'");
     }
 
-    let dt = l.value_type();
-
-    let mut offsets = vec![OffsetSize::usize_as(0)];
-    let mut new_arrays = vec![];
-
-    let converter = RowConverter::new(vec![SortField::new(dt)])?;
-    for (first_arr, second_arr) in l.iter().zip(r.iter()) {
-        if let (Some(first_arr), Some(second_arr)) = (first_arr, second_arr) {
-            let l_values = converter.convert_columns(&[first_arr])?;
-            let r_values = converter.convert_columns(&[second_arr])?;
+    let converter = RowConverter::new(vec![SortField::new(l.value_type())])?;
+    // convert the values of all the lists at once, rather than list by list
+    let l_values = converter.convert_columns(&[l.values().clone()])?;
+    let r_values = converter.convert_columns(&[r.values().clone()])?;
+
+    // a null list contributes no elements, like a `NULL` argument
+    let value_range = |list: &GenericListArray<OffsetSize>, row_index: usize| {
+        if list.is_null(row_index) {
+            0..0
+        } else {
+            let offsets = list.value_offsets();
+            offsets[row_index].as_usize()..offsets[row_index + 1].as_usize()
+        }
+    };
 
-            let l_iter = l_values.iter().sorted().dedup();
-            let values_set: HashSet<_> = l_iter.clone().collect();
-            let mut rows = if set_op == SetOp::Union {
-                l_iter.collect::<Vec<_>>()
-            } else {
-                vec![]
-            };
-            for r_val in r_values.iter().sorted().dedup() {
-                match set_op {
-                    SetOp::Union => {
-                        if !values_set.contains(&r_val) {
-                            rows.push(r_val);
-                        }
-                    }
-                    SetOp::Intersect => {
-                        if values_set.contains(&r_val) {
-                            rows.push(r_val);
-                        }
-                    }
-                }
+    let mut offsets = Vec::<OffsetSize>::with_capacity(l.len() + 1);
+    offsets.push(OffsetSize::usize_as(0));
+    let mut rows = Vec::with_capacity(l_values.num_rows());
+    let mut set = HashSet::new();
+
+    // Each list is probed against a hash set of the distinct left elements, so the
+    // cost is linear in the list lengths. The output keeps the existing order: the
+    // distinct elements contributed by the left list in row order, followed by those
+    // contributed by the right list in row order.
+    for row_index in 0..l.len() {
+        let start = rows.len();
+        for i in value_range(l, row_index) {
+            let row = l_values.row(i);
+            if set.insert(row) && set_op == SetOp::Union {
+                rows.push(row);
             }
-
-            let last_offset = match offsets.last().copied() {
-                Some(offset) => offset,
-                None => return internal_err!("offsets should not be empty"),
-            };
-            offsets.push(last_offset + OffsetSize::usize_as(rows.len()));
-            let arrays = converter.convert_rows(rows)?;
-            let array = match arrays.first() {
-                Some(array) => array.clone(),
-                None => {
-                    return internal_err!("{set_op}: failed to get array from rows");
-                }
+        }
+        rows[start..].sort_unstable();
+
+        let start = rows.len();
+        for i in value_range(r, row_index) {
+            let row = r_values.row(i);
+            let emit = match set_op {
+                // not in the left list, nor already emitted from the right list
+                SetOp::Union => set.insert(row),
+                // in the left list, and removed so that it is only emitted once
+                SetOp::Intersect => set.remove(&row),
             };
-            new_arrays.push(array);
+            if emit {
+                rows.push(row);
+            }
         }
+        rows[start..].sort_unstable();
+
+        offsets.push(OffsetSize::usize_as(rows.len()));
+        set.clear();
     }
 
-    let offsets = OffsetBuffer::new(offsets.into());
-    let new_arrays_ref = new_arrays.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
-    let values = compute::concat(&new_arrays_ref)?;
-    let arr = GenericListArray::<OffsetSize>::try_new(field, offsets, values, None)?;
+    let values = match converter.convert_rows(rows)?.pop() {
+        Some(values) => values,
+        None => return internal_err!("{set_op}: failed to get array from rows"),
+    };
+    // the union is null when both lists are null, the intersection when either is
+    let nulls = match set_op {
+        SetOp::Union => match (l.nulls(), r.nulls()) {
+            (Some(l_nulls), Some(r_nulls)) => {
+                Some(NullBuffer::new(l_nulls.inner() | r_nulls.inner()))
+            }
+            _ => None,
+        },
+        SetOp::Intersect => NullBuffer::union(l.nulls(), r.nulls()),
+    };
+    let arr = GenericListArray::<OffsetSize>::try_new(
+        field,
+        OffsetBuffer::new(offsets.into()),
+        values,
+        nulls,
+    )?;
     Ok(Arc::new(arr))
 }
 
@@ -1284,38 +1732,10 @@ fn general_set_op(
     array2: &ArrayRef,
     set_op: SetOp,
 ) -> Result<ArrayRef> {
-    match (array1.data_type(), array2.data_type()) {
-        (DataType::Null, DataType::List(field)) => {
-            if set_op == SetOp::Intersect {
-                return Ok(new_empty_array(&DataType::Null));
-            }
-            let array = as_list_array(&array2)?;
-            general_array_distinct::<i32>(array, field)
-        }
-
-        (DataType::List(field), DataType::Null) => {
-            if set_op == SetOp::Intersect {
-                return make_array(&[]);
-            }
-            let array = as_list_array(&array1)?;
-            general_array_distinct::<i32>(array, field)
-        }
-        (DataType::Null, DataType::LargeList(field)) => {
-            if set_op == SetOp::Intersect {
-                return Ok(new_empty_array(&DataType::Null));
-            }
-            let array = as_large_list_array(&array2)?;
-            general_array_distinct::<i64>(array, field)
-        }
-        (DataType::LargeList(field), DataType::Null) => {
-            if set_op == SetOp::Intersect {
-                return make_array(&[]);
-            }
-            let array = as_large_list_array(&array1)?;
-            general_array_distinct::<i64>(array, field)
-        }
-        (DataType::Null, DataType::Null) => Ok(new_empty_array(&DataType::Null)),
+    let (array1, array2) = align_set_op_args(array1, array2)?;
 
+    match (array1.data_type(), array2.data_type()) {
+        (DataType::Null, DataType::Null) => Ok(array1),
         (DataType::List(field), DataType::List(_)) => {
             let array1 = as_list_array(&array1)?;
             let array2 = as_list_array(&array2)?;
@@ -1357,39 +1777,102 @@ pub fn array_intersect(args: &[ArrayRef]) -> Result<ArrayRef> {
     general_set_op(array1, array2, SetOp::Intersect)
 }
 
-pub fn general_array_distinct<OffsetSize: OffsetSizeTrait>(
-    array: &GenericListArray<OffsetSize>,
-    field: &FieldRef,
+/// array_flatten_distinct SQL function
+///
+/// Flattens one level of nesting and removes duplicate elements in a single pass.
+/// The result is the same as `array_distinct(flatten(array))` for an array of arrays:
+/// NULL inner arrays are skipped and the first occurrence of every remaining element
+/// is kept, in order.
+///
+/// For example:
+/// > array_flatten_distinct(\[\[3, 1], \[1, 2], NULL, \[3]]) -> \[3, 1, 2]
+pub fn array_flatten_distinct(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 1 {
+        return exec_err!("array_flatten_distinct needs one argument");
+    }
+
+    match args[0].data_type() {
+        DataType::Null => Ok(args[0].clone()),
+        DataType::List(field) => {
+            let array = as_list_array(&args[0])?;
+            match field.data_type() {
+                DataType::List(_) => general_flatten_distinct::<i32, i32>(array),
+                DataType::LargeList(_) => general_flatten_distinct::<i32, i64>(array),
+                dt => exec_err!("array_flatten_distinct does not support type '{dt:?}'"),
+            }
+        }
+        DataType::LargeList(field) => {
+            let array = as_large_list_array(&args[0])?;
+            match field.data_type() {
+                DataType::List(_) => general_flatten_distinct::<i64, i32>(array),
+                DataType::LargeList(_) => general_flatten_distinct::<i64, i64>(array),
+                dt => exec_err!("array_flatten_distinct does not support type '{dt:?}'"),
+            }
+        }
+        array_type => {
+            exec_err!("array_flatten_distinct does not support type '{array_type:?}'")
+        }
+    }
+}
+
+/// Flattens the inner lists of every row of `array` and deduplicates their elements.
+///
+/// `O` is the offset type of the outer list and `I` the offset type of the inner lists.
+/// All elements are converted to the row format once, and the distinct rows of every
+/// output row are converted back in a single batch.
+fn general_flatten_distinct<O: OffsetSizeTrait, I: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
 ) -> Result<ArrayRef> {
-    let dt = array.value_type();
-    let mut offsets = Vec::with_capacity(array.len());
-    offsets.push(OffsetSize::usize_as(0));
-    let mut new_arrays = Vec::with_capacity(array.len());
-    let converter = RowConverter::new(vec![SortField::new(dt)])?;
-    // distinct for each list in ListArray
-    for arr in array.iter().flatten() {
-        let values = converter.convert_columns(&[arr])?;
-        // sort elements in list and remove duplicates
-        let rows = values.iter().sorted().dedup().collect::<Vec<_>>();
-        let last_offset: OffsetSize = offsets.last().copied().unwrap();
-        offsets.push(last_offset + OffsetSize::usize_as(rows.len()));
-        let arrays = converter.convert_rows(rows)?;
-        let array = match arrays.first() {
-            Some(array) => array.clone(),
-            None => {
-                return internal_err!("array_distinct: failed to get array from rows")
+    let inner = as_generic_list_array::<I>(array.values())?;
+    let field = match inner.data_type() {
+        DataType::List(field) | DataType::LargeList(field) => field.clone(),
+        dt => return internal_err!("array_flatten_distinct got unexpected type {dt:?}"),
+    };
+
+    let converter = RowConverter::new(vec![SortField::new(inner.value_type())])?;
+    let values = converter.convert_columns(&[inner.values().clone()])?;
+
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::usize_as(0));
+    let mut rows = Vec::with_capacity(values.num_rows());
+    let mut seen = HashSet::new();
+
+    for (row_index, window) in array.offsets().windows(2).enumerate() {
+        if array.is_null(row_index) {
+            offsets.push(O::usize_as(rows.len()));
+            continue;
+        }
+
+        seen.clear();
+        let inner_offsets = inner.offsets();
+        for inner_index in window[0].as_usize()..window[1].as_usize() {
+            // NULL inner arrays do not contribute any element
+            if inner.is_null(inner_index) {
+                continue;
             }
-        };
-        new_arrays.push(array);
+            let start = inner_offsets[inner_index].as_usize();
+            let end = inner_offsets[inner_index + 1].as_usize();
+            // keep the first occurrence of every element, as array_distinct does
+            rows.extend(
+                (start..end)
+                    .map(|i| values.row(i))
+                    .filter(|row| seen.insert(*row)),
+            );
+        }
+
+        offsets.push(O::usize_as(rows.len()));
     }
-    let offsets = OffsetBuffer::new(offsets.into());
-    let new_arrays_ref = new_arrays.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
-    let values = compute::concat(&new_arrays_ref)?;
-    Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
-        field.clone(),
-        offsets,
+
+    let values = match converter.convert_rows(rows)?.pop() {
+        Some(values) => values,
+        None => return internal_err!("array_flatten_distinct failed to convert rows"),
+    };
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field,
+        OffsetBuffer::new(offsets.into()),
         values,
-        None,
+        array.nulls().cloned(),
     )?))
 }
 
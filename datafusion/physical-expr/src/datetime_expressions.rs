@@ -17,20 +17,34 @@
 
 //! DateTime expressions
 
+use std::borrow::Cow;
+use std::fmt::{Display, Write};
 use std::sync::Arc;
 
+use arrow::array::temporal_conversions::{as_date, as_datetime, as_time};
+use arrow::array::timezone::Tz;
 use arrow::util::display::{ArrayFormatter, DurationFormat, FormatOptions};
 use arrow::{
-    array::{Array, ArrayRef, PrimitiveArray},
-    datatypes::DataType,
+    array::{make_array, Array, ArrayRef, PrimitiveArray, UInt32Array},
+    buffer::NullBuffer,
+    compute::{filter, is_not_null, take},
+    datatypes::{DataType, TimeUnit},
 };
-use arrow_array::builder::PrimitiveBuilder;
+use arrow_array::builder::{PrimitiveBuilder, StringBuilder};
 use arrow_array::cast::AsArray;
-use arrow_array::types::{Date32Type, Int32Type};
+use arrow_array::types::{
+    ArrowPrimitiveType, Date32Type, Date64Type, Int32Type, Int64Type,
+    Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType,
+};
 use arrow_array::StringArray;
+use arrow_schema::ArrowError;
+use chrono::format::{Item, StrftimeItems};
 use chrono::prelude::*;
 use chrono::NaiveDate;
 
+use datafusion_common::cast::as_struct_array;
 use datafusion_common::{exec_err, Result, ScalarValue};
 use datafusion_expr::ColumnarValue;
 
@@ -40,6 +54,31 @@ use datafusion_expr::ColumnarValue;
 /// The syntax for the patterns can be found at
 /// <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>
 ///
+/// In addition to the Chrono specifiers, the following tokens are supported:
+///
+/// * `IYYY`: ISO 8601 week-based year (same as `%G`)
+/// * `IW`: ISO 8601 week number, 01-53 (same as `%V`)
+///
+/// The Chrono specifiers for numbered weeks and days are `%U` and `%W`, the week
+/// of the year starting on Sunday or Monday (00-53, days before the first Sunday
+/// or Monday are in week 00), `%u` and `%w`, the day of the week with Monday as 1
+/// to Sunday as 7 (ISO 8601) or Sunday as 0 to Saturday as 6, and `%j`, the day
+/// of the year zero padded to three digits (001-366).
+///
+/// Integers are formatted with a numeric pattern instead. The only numeric
+/// pattern supported is `RN`, which formats values from 1 to 3999 as upper case
+/// Roman numerals. The PostgreSQL `FM` prefix is accepted but has no effect, as
+/// the result is never padded.
+///
+/// The fractional seconds specifiers `%3f`, `%6f` and `%9f` (and `%.3f`, `%.6f`,
+/// `%.9f`) always print milliseconds, microseconds and nanoseconds with a fixed
+/// width, truncating any extra precision and padding with zeros when the value's
+/// unit is coarser. Specifiers with any other precision, such as `%4f`, and any
+/// other text are passed through literally.
+///
+/// Text in double quotes is always emitted verbatim, without expanding any
+/// tokens or specifiers in it, and `""` inside the quotes produces a `"`.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -114,6 +153,10 @@ pub fn to_char(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         return exec_err!("to_char function requires 2 arguments, got {}", args.len());
     }
 
+    if args[0].data_type().is_integer() {
+        return _to_char_numeric(args);
+    }
+
     match &args[1] {
         // null format, use default formats
         ColumnarValue::Scalar(ScalarValue::Utf8(None))
@@ -123,7 +166,8 @@ pub fn to_char(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         // constant format
         ColumnarValue::Scalar(ScalarValue::Utf8(Some(format))) => {
             // invoke to_char_scalar with the known string, without converting to array
-            _to_char_scalar(args[0].clone(), Some(format))
+            let format = expand_format_tokens(format);
+            _to_char_scalar(args[0].clone(), Some(&format))
         }
         ColumnarValue::Array(_) => _to_char_array(args),
         _ => {
@@ -135,6 +179,165 @@ pub fn to_char(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     }
 }
 
+/// Formats integers with a numeric `to_char` pattern. A null value or pattern
+/// produces a null.
+fn _to_char_numeric(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let is_scalar = args
+        .iter()
+        .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let values = arrow::compute::cast(&arrays[0], &DataType::Int64)?;
+    let values = values.as_primitive::<Int64Type>();
+    let formats = match arrays[1].data_type() {
+        DataType::Null => None,
+        DataType::Utf8 => Some(arrays[1].as_string::<i32>()),
+        other => {
+            return exec_err!(
+                "Format for `to_char` must be non-null Utf8, received {other:?}"
+            )
+        }
+    };
+
+    let mut builder = StringBuilder::with_capacity(values.len(), values.len() * 8);
+    for idx in 0..values.len() {
+        let format = formats
+            .filter(|formats| formats.is_valid(idx))
+            .map(|formats| formats.value(idx));
+        match (values.is_valid(idx), format) {
+            (true, Some(format)) => {
+                let pattern = format.strip_prefix("FM").unwrap_or(format);
+                if pattern != "RN" {
+                    return exec_err!(
+                        "to_char only supports the RN (Roman numeral) format for integers, received '{format}'"
+                    );
+                }
+                builder.append_value(to_roman_numeral(values.value(idx))?);
+            }
+            _ => builder.append_null(),
+        }
+    }
+
+    let result = Arc::new(builder.finish()) as ArrayRef;
+    if is_scalar {
+        Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+            &result, 0,
+        )?))
+    } else {
+        Ok(ColumnarValue::Array(result))
+    }
+}
+
+/// Converts `value` to upper case Roman numerals, e.g. 1994 to `MCMXCIV`
+fn to_roman_numeral(value: i64) -> Result<String> {
+    const NUMERALS: [(i64, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    if !(1..=3999).contains(&value) {
+        return exec_err!(
+            "to_char RN format requires a value between 1 and 3999, received {value}"
+        );
+    }
+
+    let mut remainder = value;
+    let mut result = String::new();
+    for (amount, numeral) in NUMERALS {
+        while remainder >= amount {
+            result.push_str(numeral);
+            remainder -= amount;
+        }
+    }
+    Ok(result)
+}
+
+/// Rewrites the non-Chrono tokens supported by `to_char` into the equivalent
+/// Chrono specifiers, escapes fractional second specifiers with an unsupported
+/// precision (e.g. `%4f`) so they are passed through literally, and leaves other
+/// `%` specifiers and any other text as is.
+///
+/// As in Postgres, text in double quotes is emitted verbatim, with `""` inside
+/// the quotes standing for a literal `"`. A quote that is never closed makes
+/// the rest of the format literal.
+fn expand_format_tokens(format: &str) -> Cow<'_, str> {
+    // every fractional second specifier ends with `f`
+    if !format.contains("IW")
+        && !format.contains("IYYY")
+        && !format.contains('f')
+        && !format.contains('"')
+    {
+        return Cow::Borrowed(format);
+    }
+
+    let mut expanded = String::with_capacity(format.len());
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if c == '%' {
+            // copy the specifier, including any padding / width modifiers
+            let end = rest[1..]
+                .find(|c: char| c.is_ascii_alphabetic() || c == '%')
+                .map(|i| i + 2)
+                .unwrap_or(rest.len());
+            let specifier = &rest[..end];
+            if is_unsupported_precision(specifier) {
+                expanded.push('%');
+            }
+            expanded.push_str(specifier);
+            rest = &rest[end..];
+        } else if c == '"' {
+            rest = &rest[1..];
+            loop {
+                let end = rest.find('"').unwrap_or(rest.len());
+                // `%` is the only character Chrono does not print literally
+                expanded.push_str(&rest[..end].replace('%', "%%"));
+                rest = rest.get(end + 1..).unwrap_or_default();
+                // `""` is an escaped quote, anything else ends the literal
+                let Some(tail) = rest.strip_prefix('"') else {
+                    break;
+                };
+                expanded.push('"');
+                rest = tail;
+            }
+        } else if let Some(tail) = rest.strip_prefix("IYYY") {
+            expanded.push_str("%G");
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("IW") {
+            expanded.push_str("%V");
+            rest = tail;
+        } else {
+            expanded.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    Cow::Owned(expanded)
+}
+
+/// Returns true for fractional second specifiers such as `%4f` or `%.2f` whose
+/// precision is not one of the 3, 6 or 9 digits supported by Chrono
+fn is_unsupported_precision(specifier: &str) -> bool {
+    let Some(precision) = specifier
+        .strip_prefix('%')
+        .and_then(|s| s.strip_suffix('f'))
+        .map(|s| s.strip_prefix('.').unwrap_or(s))
+    else {
+        return false;
+    };
+    !precision.is_empty()
+        && precision.bytes().all(|b| b.is_ascii_digit())
+        && !matches!(precision, "3" | "6" | "9")
+}
+
 fn _build_format_options<'a>(
     data_type: &DataType,
     format: Option<&'a str>,
@@ -181,6 +384,20 @@ fn _to_char_scalar(
         Err(value) => return value,
     };
 
+    // parse the constant format once and reuse it for every row of the batch
+    if let Some(format) = format {
+        let items = StrftimeItems::new(format).collect::<Vec<_>>();
+        if !items.contains(&Item::Error) {
+            if let Some(formatted) = _to_char_with_items(&array, &items)? {
+                return Ok(if is_scalar_expression {
+                    ColumnarValue::Scalar(ScalarValue::try_from_array(&formatted, 0)?)
+                } else {
+                    ColumnarValue::Array(formatted)
+                });
+            }
+        }
+    }
+
     let formatter = ArrayFormatter::try_new(array.as_ref(), &format_options)?;
     let formatted: Result<Vec<_>, arrow_schema::ArrowError> = (0..array.len())
         .map(|i| formatter.value(i).try_to_string())
@@ -201,6 +418,115 @@ fn _to_char_scalar(
     }
 }
 
+/// Formats the date, time and timestamp values of `array` with an already parsed
+/// format. Returns `None` for data types that are not formatted with a Chrono
+/// pattern (i.e. durations).
+///
+/// The output matches [`ArrayFormatter`]: nulls are formatted as empty strings.
+fn _to_char_with_items(array: &ArrayRef, items: &[Item<'_>]) -> Result<Option<ArrayRef>> {
+    let formatted = match array.data_type() {
+        DataType::Date32 => _format_temporal::<Date32Type, _, _>(array, |v| {
+            as_date::<Date32Type>(v as i64).map(|d| d.format_with_items(items.iter()))
+        }),
+        DataType::Date64 => _format_temporal::<Date64Type, _, _>(array, |v| {
+            as_datetime::<Date64Type>(v).map(|d| d.format_with_items(items.iter()))
+        }),
+        DataType::Time32(TimeUnit::Second) => {
+            _format_temporal::<Time32SecondType, _, _>(array, |v| {
+                as_time::<Time32SecondType>(v as i64)
+                    .map(|t| t.format_with_items(items.iter()))
+            })
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            _format_temporal::<Time32MillisecondType, _, _>(array, |v| {
+                as_time::<Time32MillisecondType>(v as i64)
+                    .map(|t| t.format_with_items(items.iter()))
+            })
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            _format_temporal::<Time64MicrosecondType, _, _>(array, |v| {
+                as_time::<Time64MicrosecondType>(v)
+                    .map(|t| t.format_with_items(items.iter()))
+            })
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            _format_temporal::<Time64NanosecondType, _, _>(array, |v| {
+                as_time::<Time64NanosecondType>(v)
+                    .map(|t| t.format_with_items(items.iter()))
+            })
+        }
+        DataType::Timestamp(unit, tz) => {
+            let tz = tz.as_deref().map(str::parse::<Tz>).transpose()?;
+            match unit {
+                TimeUnit::Second => {
+                    _format_timestamp::<TimestampSecondType>(array, tz, items)
+                }
+                TimeUnit::Millisecond => {
+                    _format_timestamp::<TimestampMillisecondType>(array, tz, items)
+                }
+                TimeUnit::Microsecond => {
+                    _format_timestamp::<TimestampMicrosecondType>(array, tz, items)
+                }
+                TimeUnit::Nanosecond => {
+                    _format_timestamp::<TimestampNanosecondType>(array, tz, items)
+                }
+            }
+        }
+        _ => return Ok(None),
+    };
+    formatted.map(Some)
+}
+
+fn _format_timestamp<T: ArrowPrimitiveType<Native = i64>>(
+    array: &ArrayRef,
+    tz: Option<Tz>,
+    items: &[Item<'_>],
+) -> Result<ArrayRef> {
+    match tz {
+        Some(tz) => _format_temporal::<T, _, _>(array, |v| {
+            as_datetime::<T>(v).map(|naive| {
+                Utc.from_utc_datetime(&naive)
+                    .with_timezone(&tz)
+                    .format_with_items(items.iter())
+            })
+        }),
+        None => _format_temporal::<T, _, _>(array, |v| {
+            as_datetime::<T>(v).map(|naive| naive.format_with_items(items.iter()))
+        }),
+    }
+}
+
+fn _format_temporal<T, F, D>(array: &ArrayRef, format: F) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> Option<D>,
+    D: Display,
+{
+    let array = array.as_primitive::<T>();
+    let mut builder = StringBuilder::with_capacity(array.len(), array.len() * 16);
+    for value in array.iter() {
+        if let Some(value) = value {
+            let Some(formatted) = format(value) else {
+                return exec_err!(
+                    "{}",
+                    ArrowError::CastError(format!(
+                        "Failed to convert {value:?} to temporal for {}",
+                        array.data_type()
+                    ))
+                );
+            };
+            if write!(builder, "{formatted}").is_err() {
+                return exec_err!(
+                    "{}",
+                    ArrowError::CastError("Format error".to_string())
+                );
+            }
+        }
+        builder.append_value("");
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
 fn _to_char_array(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     let arrays = ColumnarValue::values_to_arrays(args)?;
     let mut results: Vec<String> = vec![];
@@ -211,9 +537,9 @@ fn _to_char_array(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         let format = if format_array.is_null(idx) {
             None
         } else {
-            Some(format_array.value(idx))
+            Some(expand_format_tokens(format_array.value(idx)))
         };
-        let format_options = match _build_format_options(data_type, format) {
+        let format_options = match _build_format_options(data_type, format.as_deref()) {
             Ok(value) => value,
             Err(value) => return value,
         };
@@ -237,8 +563,73 @@ fn _to_char_array(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     }
 }
 
-/// make_date(year, month, day) SQL function implementation
+/// Splits a struct with `year`, `month` and `day` fields into the positional
+/// arguments of `make_date`
+fn struct_date_parts(arg: &ColumnarValue) -> Result<Vec<ColumnarValue>> {
+    let (array, is_scalar) = match arg {
+        ColumnarValue::Array(array) => (array.clone(), false),
+        ColumnarValue::Scalar(scalar) => (scalar.to_array()?, true),
+    };
+    let array = as_struct_array(&array)?;
+    ["year", "month", "day"]
+        .iter()
+        .map(|name| {
+            let Some(column) = array.column_by_name(name) else {
+                return exec_err!(
+                    "make_date struct argument is missing the '{name}' field"
+                );
+            };
+            // the fields of a null struct are null, whatever their values
+            let column = match array.nulls() {
+                Some(nulls) => {
+                    let nulls = NullBuffer::union(Some(nulls), column.nulls());
+                    make_array(column.to_data().into_builder().nulls(nulls).build()?)
+                }
+                None => column.clone(),
+            };
+            Ok(if is_scalar {
+                ColumnarValue::Scalar(ScalarValue::try_from_array(&column, 0)?)
+            } else {
+                ColumnarValue::Array(column)
+            })
+        })
+        .collect()
+}
+
+/// make_date(struct) for an array of structs, which is NULL for a NULL struct
+fn make_date_from_struct(arg: &ColumnarValue) -> Result<ColumnarValue> {
+    let ColumnarValue::Array(array) = arg else {
+        return make_date(&struct_date_parts(arg)?);
+    };
+    if array.null_count() == 0 {
+        return make_date(&struct_date_parts(arg)?);
+    }
+
+    // the fields of a NULL struct may hold any value, so only the dates of the
+    // other structs are made
+    let structs = filter(array, &is_not_null(array)?)?;
+    let dates = make_date(&struct_date_parts(&ColumnarValue::Array(structs))?)?
+        .into_array(array.len() - array.null_count())?;
+    let mut next = 0;
+    let indices = (0..array.len())
+        .map(|i| {
+            array.is_valid(i).then(|| {
+                next += 1;
+                next - 1
+            })
+        })
+        .collect::<UInt32Array>();
+    Ok(ColumnarValue::Array(take(&dates, &indices, None)?))
+}
+
+/// make_date(year, month, day) SQL function implementation, also accepting
+/// make_date(struct) for a struct with `year`, `month` and `day` fields
 pub fn make_date(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if let [arg] = args {
+        if matches!(arg.data_type(), DataType::Struct(_)) {
+            return make_date_from_struct(arg);
+        }
+    }
     if args.len() != 3 {
         return exec_err!(
             "make_date function requires 3 arguments, got {}",
@@ -356,11 +747,12 @@ mod tests {
     use std::sync::Arc;
 
     use arrow::array::{ArrayRef, Int64Array};
+    use arrow::datatypes::Field;
     use arrow_array::{
-        Date32Array, Date64Array, Int32Array, Time32MillisecondArray, Time32SecondArray,
-        Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
-        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray,
-        UInt32Array,
+        Date32Array, Date64Array, Int32Array, StringArray, StructArray,
+        Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray,
+        Time64NanosecondArray, TimestampMicrosecondArray, TimestampMillisecondArray,
+        TimestampNanosecondArray, TimestampSecondArray, UInt32Array,
     };
 
     use datafusion_common::ScalarValue;
@@ -475,6 +867,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_make_date_from_struct() {
+        let parts = StructArray::from(vec![
+            (
+                Arc::new(Field::new("day", DataType::Utf8, false)),
+                Arc::new(StringArray::from(vec!["27", "29"])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("year", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![2024, 2020])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("month", DataType::Int64, false)),
+                Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+            ),
+        ]);
+
+        // fields are read by name, in any order
+        let res = make_date(&[ColumnarValue::Array(Arc::new(parts.clone()))])
+            .expect("that make_date parsed values without error");
+        let ColumnarValue::Array(array) = res else {
+            panic!("Expected an array")
+        };
+        assert_eq!(
+            array.as_primitive::<Date32Type>(),
+            &Date32Array::from(vec![19749, 18321])
+        );
+
+        let res = make_date(&[ColumnarValue::Scalar(ScalarValue::Struct(Arc::new(
+            parts.slice(1, 1),
+        )))])
+        .expect("that make_date parsed values without error");
+        let ColumnarValue::Scalar(ScalarValue::Date32(date)) = res else {
+            panic!("Expected a scalar value")
+        };
+        assert_eq!(date, Some(18321));
+
+        // a null struct has a null date, even if its fields are not null
+        let with_null = StructArray::new(
+            parts.fields().clone(),
+            parts.columns().to_vec(),
+            Some(vec![true, false].into()),
+        );
+        let res = make_date(&[ColumnarValue::Array(Arc::new(with_null))])
+            .expect("that make_date parsed values without error");
+        let ColumnarValue::Array(array) = res else {
+            panic!("Expected an array")
+        };
+        assert_eq!(
+            array.as_primitive::<Date32Type>(),
+            &Date32Array::from(vec![Some(19749), None])
+        );
+
+        // missing field
+        let without_month = StructArray::new(
+            parts.fields()[..2].to_vec().into(),
+            parts.columns()[..2].to_vec(),
+            None,
+        );
+        let res = make_date(&[ColumnarValue::Array(Arc::new(without_month))]);
+        assert_eq!(
+            res.err().unwrap().strip_backtrace(),
+            "Execution error: make_date struct argument is missing the 'month' field"
+        );
+    }
+
     #[test]
     fn test_to_char() {
         let date = "2020-01-02T03:04:05"
@@ -799,4 +1257,341 @@ mod tests {
             "Execution error: Format for `to_char` must be non-null Utf8, received Timestamp(Nanosecond, None)"
         );
     }
+
+    #[test]
+    fn test_to_char_roman_numerals() {
+        let values = Int64Array::from(vec![
+            Some(1),
+            Some(4),
+            Some(9),
+            Some(14),
+            Some(40),
+            Some(90),
+            Some(400),
+            Some(1994),
+            Some(2024),
+            Some(3999),
+            None,
+        ]);
+        let result = to_char(&[
+            ColumnarValue::Array(Arc::new(values) as ArrayRef),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("RN".to_string()))),
+        ])
+        .unwrap();
+        let ColumnarValue::Array(result) = result else {
+            panic!("Expected an array value")
+        };
+        let expected = StringArray::from(vec![
+            Some("I"),
+            Some("IV"),
+            Some("IX"),
+            Some("XIV"),
+            Some("XL"),
+            Some("XC"),
+            Some("CD"),
+            Some("MCMXCIV"),
+            Some("MMXXIV"),
+            Some("MMMCMXCIX"),
+            None,
+        ]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        for value in [0, -1, 4000] {
+            let result = to_char(&[
+                ColumnarValue::Scalar(ScalarValue::Int64(Some(value))),
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some("RN".to_string()))),
+            ]);
+            assert_eq!(
+                result.err().unwrap().strip_backtrace(),
+                format!("Execution error: to_char RN format requires a value between 1 and 3999, received {value}")
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_char_iso_week() {
+        let days = |date: &str| {
+            date.parse::<NaiveDate>()
+                .unwrap()
+                .signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+                .num_days() as i32
+        };
+
+        // dates around year boundaries where the ISO year differs from the calendar year
+        let cases = [
+            ("2023-01-01", "2022-52"),
+            ("2023-01-02", "2023-01"),
+            ("2021-01-03", "2020-53"),
+            ("2020-12-31", "2020-53"),
+            ("2024-12-30", "2025-01"),
+            ("2019-12-30", "2020-01"),
+        ];
+        for (date, expected) in cases {
+            let result = to_char(&[
+                ColumnarValue::Scalar(ScalarValue::Date32(Some(days(date)))),
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some("IYYY-IW".to_string()))),
+            ])
+            .unwrap();
+            let ColumnarValue::Scalar(ScalarValue::Utf8(Some(result))) = result else {
+                panic!("Expected a scalar value")
+            };
+            assert_eq!(result, expected, "to_char({date}, 'IYYY-IW')");
+        }
+
+        // tokens mixed with Chrono specifiers and literal text, with array formats
+        let timestamp = "2023-01-01T03:04:05"
+            .parse::<NaiveDateTime>()
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap();
+        let formats =
+            StringArray::from(vec!["%Y-%m-%d is week IW of IYYY", "%IW", "IX IYY IWIW"]);
+        let values = TimestampNanosecondArray::from(vec![timestamp; 3]);
+        let result = to_char(&[
+            ColumnarValue::Array(Arc::new(values) as ArrayRef),
+            ColumnarValue::Array(Arc::new(formats) as ArrayRef),
+        ])
+        .unwrap();
+        let ColumnarValue::Array(result) = result else {
+            panic!("Expected an array value")
+        };
+        let expected = StringArray::from(vec![
+            "2023-01-01 is week 52 of 2022",
+            // `%I` is the 12-hour clock hour, followed by a literal `W`
+            "03W",
+            // unknown tokens are passed through literally
+            "IX IYY 5252",
+        ]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+    }
+
+    #[test]
+    fn test_to_char_week_and_day_numbers() {
+        let days = |date: &str| {
+            date.parse::<NaiveDate>()
+                .unwrap()
+                .signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+                .num_days() as i32
+        };
+
+        // %U and %W differ when the year starts on a Sunday or a Monday
+        let cases = [
+            ("2022-12-31", "52 52 6 6 365"),
+            ("2023-01-01", "01 00 7 0 001"),
+            ("2023-01-02", "01 01 1 1 002"),
+            ("2024-01-01", "00 01 1 1 001"),
+            ("2024-12-31", "52 53 2 2 366"),
+        ];
+        for (date, expected) in cases {
+            let result = to_char(&[
+                ColumnarValue::Scalar(ScalarValue::Date32(Some(days(date)))),
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some(
+                    "%U %W %u %w %j".to_string(),
+                ))),
+            ])
+            .unwrap();
+            let ColumnarValue::Scalar(ScalarValue::Utf8(Some(result))) = result else {
+                panic!("Expected a scalar value")
+            };
+            assert_eq!(result, expected, "to_char({date}, '%U %W %u %w %j')");
+        }
+    }
+
+    #[test]
+    fn test_to_char_quoted_literals() {
+        let timestamp = "2023-01-01T03:04:05"
+            .parse::<NaiveDateTime>()
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap();
+        let cases = [
+            (r#""Year" %Y"#, "Year 2023"),
+            // tokens and specifiers in quotes are not expanded
+            (r#""IYYY-IW %Y %3f" IYYY-IW"#, "IYYY-IW %Y %3f 2022-52"),
+            (r#""Day" %d"#, "Day 01"),
+            // `""` in quotes is an escaped quote
+            (r#""say ""hi""" %H"#, r#"say "hi" 03"#),
+            (r#"%H""%M"#, "0304"),
+            (r#""""" %S"#, r#"" 05"#),
+            // an unterminated quote makes the rest of the format literal
+            (r#"%H "at %M"#, "03 at %M"),
+        ];
+        for (format, expected) in cases {
+            let result = to_char(&[
+                ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+                    Some(timestamp),
+                    None,
+                )),
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some(format.to_string()))),
+            ])
+            .unwrap();
+            let ColumnarValue::Scalar(ScalarValue::Utf8(Some(result))) = result else {
+                panic!("Expected a scalar value")
+            };
+            assert_eq!(result, expected, "to_char(.., '{format}')");
+        }
+    }
+
+    #[test]
+    fn test_to_char_fractional_precision() {
+        // 2023-01-01T03:04:05 plus a fraction of a second in nanoseconds
+        let seconds = 1672542245_i64;
+        let format = "%S %3f %6f %9f %.3f|%4f %.2f %%3f";
+        for (fraction, expected_by_unit) in [
+            (
+                123_456_789_i64,
+                [
+                    "05 000 000000 000000000 .000|%4f %.2f %3f",
+                    "05 123 123000 123000000 .123|%4f %.2f %3f",
+                    "05 123 123456 123456000 .123|%4f %.2f %3f",
+                    "05 123 123456 123456789 .123|%4f %.2f %3f",
+                ],
+            ),
+            // the fraction is truncated, never rounded up into the next second
+            (
+                999_999_999,
+                [
+                    "05 000 000000 000000000 .000|%4f %.2f %3f",
+                    "05 999 999000 999000000 .999|%4f %.2f %3f",
+                    "05 999 999999 999999000 .999|%4f %.2f %3f",
+                    "05 999 999999 999999999 .999|%4f %.2f %3f",
+                ],
+            ),
+        ] {
+            let values: [ArrayRef; 4] = [
+                Arc::new(TimestampSecondArray::from(vec![seconds])),
+                Arc::new(TimestampMillisecondArray::from(vec![
+                    seconds * 1_000 + fraction / 1_000_000,
+                ])),
+                Arc::new(TimestampMicrosecondArray::from(vec![
+                    seconds * 1_000_000 + fraction / 1_000,
+                ])),
+                Arc::new(TimestampNanosecondArray::from(vec![
+                    seconds * 1_000_000_000 + fraction,
+                ])),
+            ];
+            for (value, expected) in values.into_iter().zip(expected_by_unit) {
+                let data_type = value.data_type().clone();
+                let expected = StringArray::from(vec![expected]);
+
+                // constant format
+                let result = to_char(&[
+                    ColumnarValue::Array(value.clone()),
+                    ColumnarValue::Scalar(ScalarValue::Utf8(Some(format.to_string()))),
+                ])
+                .unwrap();
+                let ColumnarValue::Array(result) = result else {
+                    panic!("Expected an array value")
+                };
+                assert_eq!(&expected as &dyn Array, result.as_ref(), "{data_type}");
+
+                // per row formats
+                let result = to_char(&[
+                    ColumnarValue::Array(value),
+                    ColumnarValue::Array(Arc::new(StringArray::from(vec![format]))),
+                ])
+                .unwrap();
+                let ColumnarValue::Array(result) = result else {
+                    panic!("Expected an array value")
+                };
+                assert_eq!(&expected as &dyn Array, result.as_ref(), "{data_type}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_char_constant_format_matches_per_row_format() {
+        let timestamp = "2026-07-08T09:10:11"
+            .parse::<NaiveDateTime>()
+            .unwrap()
+            .with_nanosecond(56789)
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap();
+        let values: Vec<(ArrayRef, &str)> = vec![
+            (
+                Arc::new(Date32Array::from(vec![Some(18506), None, Some(-1)])),
+                "%Y::%m::%d %a IW",
+            ),
+            (
+                Arc::new(Date64Array::from(vec![Some(1_700_000_000_000), None])),
+                "%Y-%m-%d %H:%M:%S%.3f",
+            ),
+            (
+                Arc::new(Time32SecondArray::from(vec![Some(31851), None])),
+                "%H-%M-%S",
+            ),
+            (
+                Arc::new(Time64NanosecondArray::from(vec![
+                    Some(12344567890000),
+                    None,
+                ])),
+                "%H-%M-%S %f",
+            ),
+            (
+                Arc::new(TimestampNanosecondArray::from(vec![
+                    Some(timestamp),
+                    None,
+                    Some(0),
+                ])),
+                "%d-%m-%Y %H:%M:%S%.f",
+            ),
+            (
+                Arc::new(
+                    TimestampSecondArray::from(vec![
+                        Some(timestamp / 1_000_000_000),
+                        None,
+                    ])
+                    .with_timezone("America/New_York"),
+                ),
+                "%d-%m-%Y %H:%M:%S %z %Z",
+            ),
+            (
+                Arc::new(
+                    TimestampMillisecondArray::from(vec![Some(timestamp / 1_000_000)])
+                        .with_timezone("+05:30"),
+                ),
+                "%+",
+            ),
+        ];
+
+        for (array, format) in values {
+            let len = array.len();
+            let constant = to_char(&[
+                ColumnarValue::Array(array.clone()),
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some(format.to_string()))),
+            ])
+            .unwrap()
+            .into_array(len)
+            .unwrap();
+            let per_row = to_char(&[
+                ColumnarValue::Array(array.clone()),
+                ColumnarValue::Array(Arc::new(StringArray::from(vec![format; len]))),
+            ])
+            .unwrap()
+            .into_array(len)
+            .unwrap();
+            assert_eq!(
+                constant.as_ref(),
+                per_row.as_ref(),
+                "to_char({}, '{format}')",
+                array.data_type()
+            );
+        }
+
+        // offsets are not available for timestamps without a timezone
+        let result = to_char(&[
+            ColumnarValue::Array(Arc::new(TimestampNanosecondArray::from(vec![
+                timestamp,
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%z".to_string()))),
+        ]);
+        assert_eq!(
+            result.unwrap_err().strip_backtrace(),
+            "Execution error: Cast error: Format error"
+        );
+    }
 }
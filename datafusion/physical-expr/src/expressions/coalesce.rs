@@ -0,0 +1,409 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Coalesce expression
+
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::physical_expr::{down_cast_any_ref, physical_exprs_equal};
+use crate::PhysicalExpr;
+
+use arrow::array::ArrayRef;
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{and, is_not_null, is_null};
+use arrow::datatypes::{DataType, Schema};
+use arrow::record_batch::RecordBatch;
+use datafusion_common::{exec_err, Result, ScalarValue};
+use datafusion_expr::{expr_vec_fmt, ColumnarValue};
+
+/// The `coalesce` function evaluates to the first of its arguments that is not NULL.
+///
+/// Unlike a regular scalar function, which evaluates all of its arguments up front,
+/// `CoalesceExpr` evaluates its arguments lazily: each argument is only evaluated for
+/// the rows that are still NULL after the previous arguments, and not at all once
+/// every row has a value.
+#[derive(Debug, Hash)]
+pub struct CoalesceExpr {
+    /// The arguments, in the order they are tried
+    args: Vec<Arc<dyn PhysicalExpr>>,
+    /// The (already coerced) data type produced by this expression
+    return_type: DataType,
+}
+
+impl CoalesceExpr {
+    /// Create a new coalesce expression
+    pub fn try_new(
+        args: Vec<Arc<dyn PhysicalExpr>>,
+        return_type: DataType,
+    ) -> Result<Self> {
+        if args.is_empty() {
+            return exec_err!(
+                "coalesce was called with 0 arguments. It requires at least 1."
+            );
+        }
+        Ok(Self { args, return_type })
+    }
+
+    /// Input arguments
+    pub fn args(&self) -> &[Arc<dyn PhysicalExpr>] {
+        &self.args
+    }
+
+    /// Data type produced by this expression
+    pub fn return_type(&self) -> &DataType {
+        &self.return_type
+    }
+}
+
+impl std::fmt::Display for CoalesceExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "coalesce({})", expr_vec_fmt!(self.args))
+    }
+}
+
+impl PhysicalExpr for CoalesceExpr {
+    /// Return a reference to Any that can be used for down-casting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn data_type(&self, _input_schema: &Schema) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn nullable(&self, input_schema: &Schema) -> Result<bool> {
+        // the result is only NULL if every argument can be NULL
+        for arg in &self.args {
+            if !arg.nullable(input_schema)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
+        let mut args = self.args.iter();
+
+        // Until the first array argument is seen every row is still unresolved, so a
+        // leading run of scalar arguments is resolved without materializing arrays.
+        let mut current_value: ArrayRef = loop {
+            let Some(arg) = args.next() else {
+                // all arguments are NULL scalars
+                return Ok(ColumnarValue::Scalar(ScalarValue::try_from(
+                    &self.return_type,
+                )?));
+            };
+            match arg.evaluate(batch)? {
+                ColumnarValue::Array(array) => break array,
+                ColumnarValue::Scalar(value) if value.is_null() => {}
+                value => return Ok(value),
+            }
+        };
+
+        // rows that are still NULL and need to be looked up in the next argument
+        let mut remainder = is_null(&current_value)?;
+        for arg in args {
+            if remainder.true_count() == 0 {
+                break;
+            }
+            match arg.evaluate_selection(batch, &remainder)? {
+                ColumnarValue::Array(array) => {
+                    let to_apply = and(&remainder, &is_not_null(&array)?)?;
+                    current_value = zip(&to_apply, &array, &current_value)?;
+                    remainder = and(&remainder, &is_null(&array)?)?;
+                }
+                ColumnarValue::Scalar(value) if value.is_null() => {}
+                ColumnarValue::Scalar(value) => {
+                    current_value = zip(&remainder, &value.to_scalar()?, &current_value)?;
+                    break;
+                }
+            }
+        }
+
+        Ok(ColumnarValue::Array(current_value))
+    }
+
+    fn children(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        self.args.clone()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        Ok(Arc::new(CoalesceExpr::try_new(
+            children,
+            self.return_type.clone(),
+        )?))
+    }
+
+    fn dyn_hash(&self, state: &mut dyn Hasher) {
+        let mut s = state;
+        self.hash(&mut s);
+    }
+}
+
+impl PartialEq<dyn Any> for CoalesceExpr {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| {
+                physical_exprs_equal(&self.args, &x.args)
+                    && self.return_type == x.return_type
+            })
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{cast, col, lit};
+
+    use arrow::array::{
+        Array, Float64Array, Int32Array, Int64Array, ListArray, StructArray,
+    };
+    use arrow::datatypes::{Field, Float64Type, Int64Type};
+    use datafusion_common::cast::as_int32_array;
+
+    fn batch() -> Result<RecordBatch> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+        ]);
+        let a = Int32Array::from(vec![Some(1), None, Some(3), None]);
+        let b = Int32Array::from(vec![Some(10), Some(20), None, None]);
+        Ok(RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(a), Arc::new(b)],
+        )?)
+    }
+
+    #[test]
+    fn coalesce_columns_and_literal() -> Result<()> {
+        let batch = batch()?;
+        let schema = batch.schema();
+        let expr = CoalesceExpr::try_new(
+            vec![
+                col("a", &schema)?,
+                col("b", &schema)?,
+                lit(ScalarValue::Int32(Some(100))),
+            ],
+            DataType::Int32,
+        )?;
+
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let result = as_int32_array(&result)?;
+        let expected = Int32Array::from(vec![1, 20, 3, 100]);
+        assert_eq!(result, &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn coalesce_all_null() -> Result<()> {
+        let batch = batch()?;
+        let schema = batch.schema();
+        let expr = CoalesceExpr::try_new(
+            vec![lit(ScalarValue::Int32(None)), col("a", &schema)?],
+            DataType::Int32,
+        )?;
+
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let result = as_int32_array(&result)?;
+        let expected = Int32Array::from(vec![Some(1), None, Some(3), None]);
+        assert_eq!(result, &expected);
+
+        let expr = CoalesceExpr::try_new(
+            vec![lit(ScalarValue::Int32(None)), lit(ScalarValue::Int32(None))],
+            DataType::Int32,
+        )?;
+        match expr.evaluate(&batch)? {
+            ColumnarValue::Scalar(value) => assert_eq!(value, ScalarValue::Int32(None)),
+            ColumnarValue::Array(_) => panic!("expected a scalar"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn coalesce_skips_unneeded_arguments() -> Result<()> {
+        let batch = batch()?;
+        let schema = batch.schema();
+
+        // this argument fails whenever it is evaluated
+        let failing = cast(lit("not a number"), &schema, DataType::Int32)?;
+        assert!(failing.evaluate(&batch).is_err());
+
+        // the first argument is never NULL, so the failing argument is never reached
+        let expr = CoalesceExpr::try_new(
+            vec![lit(ScalarValue::Int32(Some(7))), failing.clone()],
+            DataType::Int32,
+        )?;
+        match expr.evaluate(&batch)? {
+            ColumnarValue::Scalar(value) => {
+                assert_eq!(value, ScalarValue::Int32(Some(7)))
+            }
+            ColumnarValue::Array(_) => panic!("expected a scalar"),
+        }
+
+        // every NULL in `a` is filled by the literal before the failing argument
+        let expr = CoalesceExpr::try_new(
+            vec![
+                col("a", &schema)?,
+                lit(ScalarValue::Int32(Some(100))),
+                failing,
+            ],
+            DataType::Int32,
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        assert_eq!(result.null_count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn coalesce_nullable() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, false),
+        ]);
+
+        let expr = CoalesceExpr::try_new(
+            vec![col("a", &schema)?, lit(ScalarValue::Int32(None))],
+            DataType::Int32,
+        )?;
+        assert!(expr.nullable(&schema)?);
+
+        // any non-nullable argument makes the result non-nullable
+        let expr = CoalesceExpr::try_new(
+            vec![col("a", &schema)?, col("b", &schema)?],
+            DataType::Int32,
+        )?;
+        assert!(!expr.nullable(&schema)?);
+
+        let expr = CoalesceExpr::try_new(
+            vec![col("a", &schema)?, lit(ScalarValue::Int32(Some(0)))],
+            DataType::Int32,
+        )?;
+        assert!(!expr.nullable(&schema)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn coalesce_lists_of_different_types() -> Result<()> {
+        let a = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            None,
+            None,
+        ]);
+        let b = ListArray::from_iter_primitive::<Float64Type, _, _>(vec![
+            Some(vec![Some(10.5)]),
+            Some(vec![Some(20.5), None]),
+            None,
+            None,
+        ]);
+        let schema = Schema::new(vec![
+            Field::new("a", a.data_type().clone(), true),
+            Field::new("b", b.data_type().clone(), true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(a), Arc::new(b)],
+        )?;
+
+        // the arguments are cast to their common type when the expression is planned
+        let return_type =
+            DataType::List(Arc::new(Field::new("item", DataType::Float64, true)));
+        let fill = ScalarValue::List(Arc::new(ListArray::from_iter_primitive::<
+            Int64Type,
+            _,
+            _,
+        >(vec![Some(vec![Some(7)])])));
+        let expr = CoalesceExpr::try_new(
+            vec![
+                cast(col("a", &schema)?, &schema, return_type.clone())?,
+                col("b", &schema)?,
+                cast(lit(fill), &schema, return_type.clone())?,
+            ],
+            return_type,
+        )?;
+
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected = ListArray::from_iter_primitive::<Float64Type, _, _>(vec![
+            Some(vec![Some(1.0), Some(2.0)]),
+            Some(vec![Some(20.5), None]),
+            Some(vec![Some(7.0)]),
+            Some(vec![Some(7.0)]),
+        ]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        Ok(())
+    }
+
+    #[test]
+    fn coalesce_structs_of_different_types() -> Result<()> {
+        let a = StructArray::new(
+            vec![Field::new("x", DataType::Int64, false)].into(),
+            vec![Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef],
+            Some(vec![false, true].into()),
+        );
+        let b = StructArray::new(
+            vec![Field::new("x", DataType::Float64, true)].into(),
+            vec![Arc::new(Float64Array::from(vec![3.5, 4.5])) as ArrayRef],
+            None,
+        );
+        let schema = Schema::new(vec![
+            Field::new("a", a.data_type().clone(), true),
+            Field::new("b", b.data_type().clone(), true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(a), Arc::new(b)],
+        )?;
+
+        let return_type =
+            DataType::Struct(vec![Field::new("x", DataType::Float64, true)].into());
+        let expr = CoalesceExpr::try_new(
+            vec![
+                cast(col("a", &schema)?, &schema, return_type.clone())?,
+                col("b", &schema)?,
+            ],
+            return_type.clone(),
+        )?;
+
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        assert_eq!(result.data_type(), &return_type);
+        let result = result.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(result.null_count(), 0);
+        assert_eq!(
+            result.column(0).as_ref(),
+            &Float64Array::from(vec![3.5, 2.0]) as &dyn Array
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn coalesce_requires_arguments() {
+        assert!(CoalesceExpr::try_new(vec![], DataType::Int32).is_err());
+    }
+}
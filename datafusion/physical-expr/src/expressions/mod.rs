@@ -21,6 +21,7 @@
 mod binary;
 mod case;
 mod cast;
+mod coalesce;
 mod column;
 mod datum;
 mod in_list;
@@ -80,6 +81,7 @@ pub use crate::PhysicalSortExpr;
 pub use binary::{binary, BinaryExpr};
 pub use case::{case, CaseExpr};
 pub use cast::{cast, cast_with_options, CastExpr};
+pub use coalesce::CoalesceExpr;
 pub use column::{col, Column, UnKnownColumn};
 pub use in_list::{in_list, InListExpr};
 pub use is_not_null::{is_not_null, IsNotNullExpr};
@@ -30,6 +30,7 @@
 //! an argument i32 is passed to a function that supports f64, the
 //! argument is automatically is coerced to f64.
 
+use crate::expressions::{CoalesceExpr, Literal};
 use crate::sort_properties::SortProperties;
 use crate::{
     array_expressions, conditional_expressions, datetime_expressions, math_expressions,
@@ -40,9 +41,13 @@ use arrow::{
     compute::kernels::length::{bit_length, length},
     datatypes::{DataType, Int32Type, Int64Type, Schema},
 };
+use arrow_array::cast::AsArray;
 use arrow_array::Array;
-use datafusion_common::{exec_err, Result, ScalarValue};
+use datafusion_common::{
+    exec_datafusion_err, exec_err, internal_err, Result, ScalarValue,
+};
 use datafusion_expr::execution_props::ExecutionProps;
+use datafusion_expr::field_util::GetFieldAccessSchema;
 pub use datafusion_expr::FuncMonotonicity;
 use datafusion_expr::{
     type_coercion::functions::data_types, BuiltinScalarFunction, ColumnarValue,
@@ -67,7 +72,32 @@ pub fn create_physical_expr(
     // verify that input data types is consistent with function's `TypeSignature`
     data_types(&input_expr_types, &fun.signature())?;
 
-    let data_type = fun.return_type(&input_expr_types)?;
+    // a struct field named by a constant has the type of that field
+    let struct_field_name = match (fun, input_expr_types.first(), input_phy_exprs.get(1))
+    {
+        (BuiltinScalarFunction::ElementAt, Some(DataType::Struct(_)), Some(name)) => {
+            name.as_any().downcast_ref::<Literal>()
+        }
+        _ => None,
+    };
+    let data_type = match struct_field_name {
+        Some(name) => GetFieldAccessSchema::NamedStructField {
+            name: name.value().clone(),
+        }
+        .get_accessed_field(&input_expr_types[0])?
+        .data_type()
+        .clone(),
+        None => fun.return_type(&input_expr_types)?,
+    };
+
+    // coalesce only evaluates the arguments it needs, so it can not be
+    // expressed over pre-evaluated arguments
+    if fun == &BuiltinScalarFunction::Coalesce {
+        return Ok(Arc::new(CoalesceExpr::try_new(
+            input_phy_exprs.to_vec(),
+            data_type,
+        )?));
+    }
 
     let fun_expr: ScalarFunctionImplementation =
         create_physical_fun(fun, execution_props)?;
@@ -84,6 +114,30 @@ pub fn create_physical_expr(
     )))
 }
 
+/// Returns a copy of the function expression `expr` that produces `data_type`
+/// itself, for a function with an overload of that type, so that `expr` need not
+/// be cast to `data_type`. `chr` produces `LargeUtf8` in large string contexts.
+pub fn with_return_type(
+    expr: &Arc<dyn PhysicalExpr>,
+    data_type: &DataType,
+) -> Option<Arc<dyn PhysicalExpr>> {
+    let fun = expr.as_any().downcast_ref::<ScalarFunctionExpr>()?;
+    let fun_expr: ScalarFunctionImplementation = match (fun.name(), data_type) {
+        ("chr", DataType::LargeUtf8) if fun.args().len() == 1 => Arc::new(|args| {
+            make_scalar_function_inner(string_expressions::chr::<i64>)(args)
+        }),
+        _ => return None,
+    };
+    Some(Arc::new(ScalarFunctionExpr::new(
+        fun.name(),
+        fun_expr,
+        fun.args().to_vec(),
+        data_type.clone(),
+        fun.monotonicity().clone(),
+        false,
+    )))
+}
+
 #[cfg(feature = "unicode_expressions")]
 macro_rules! invoke_if_unicode_expressions_feature_flag {
     ($FUNC:ident, $T:tt, $NAME:expr) => {{
@@ -157,12 +211,16 @@ where
     Arc::new(move |args: &[ColumnarValue]| {
         // first, identify if any of the arguments is an Array. If yes, store its `len`,
         // as any scalar will need to be converted to an array of len `len`.
-        let len = args
-            .iter()
-            .fold(Option::<usize>::None, |acc, arg| match arg {
-                ColumnarValue::Scalar(_) => acc,
-                ColumnarValue::Array(a) => Some(a.len()),
-            });
+        // All array arguments must agree on their length.
+        let len =
+            args.iter()
+                .try_fold(Option::<usize>::None, |acc, arg| match (acc, arg) {
+                    (_, ColumnarValue::Scalar(_)) => Ok(acc),
+                    (Some(len), ColumnarValue::Array(a)) if len != a.len() => {
+                        internal_err!("argument length mismatch")
+                    }
+                    (_, ColumnarValue::Array(a)) => Ok(Some(a.len())),
+                })?;
 
         let is_scalar = len.is_none();
 
@@ -195,7 +253,7 @@ where
 /// Create a physical scalar function.
 pub fn create_physical_fun(
     fun: &BuiltinScalarFunction,
-    _execution_props: &ExecutionProps,
+    execution_props: &ExecutionProps,
 ) -> Result<ScalarFunctionImplementation> {
     Ok(match fun {
         // math functions
@@ -224,14 +282,21 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::Ln => Arc::new(math_expressions::ln),
         BuiltinScalarFunction::Log10 => Arc::new(math_expressions::log10),
         BuiltinScalarFunction::Log2 => Arc::new(math_expressions::log2),
+        BuiltinScalarFunction::Mod => {
+            Arc::new(|args| make_scalar_function_inner(math_expressions::modulo)(args))
+        }
         BuiltinScalarFunction::Nanvl => {
             Arc::new(|args| make_scalar_function_inner(math_expressions::nanvl)(args))
         }
+        BuiltinScalarFunction::CoalesceNan => Arc::new(|args| {
+            make_scalar_function_inner(math_expressions::coalesce_nan)(args)
+        }),
         BuiltinScalarFunction::Radians => Arc::new(math_expressions::to_radians),
         BuiltinScalarFunction::Random => Arc::new(math_expressions::random),
-        BuiltinScalarFunction::Round => {
-            Arc::new(|args| make_scalar_function_inner(math_expressions::round)(args))
-        }
+        BuiltinScalarFunction::Round => make_scalar_function_with_hints(
+            math_expressions::round,
+            vec![Hint::Pad, Hint::AcceptsSingular],
+        ),
         BuiltinScalarFunction::Signum => Arc::new(math_expressions::signum),
         BuiltinScalarFunction::Sin => Arc::new(math_expressions::sin),
         BuiltinScalarFunction::Sinh => Arc::new(math_expressions::sinh),
@@ -241,22 +306,45 @@ pub fn create_physical_fun(
             Arc::new(|args| make_scalar_function_inner(math_expressions::trunc)(args))
         }
         BuiltinScalarFunction::Pi => Arc::new(math_expressions::pi),
-        BuiltinScalarFunction::Power => {
-            Arc::new(|args| make_scalar_function_inner(math_expressions::power)(args))
+        BuiltinScalarFunction::Power => make_scalar_function_with_hints(
+            math_expressions::power,
+            vec![Hint::Pad, Hint::AcceptsSingular],
+        ),
+        BuiltinScalarFunction::Atan2 => make_scalar_function_with_hints(
+            math_expressions::atan2,
+            vec![Hint::Pad, Hint::AcceptsSingular],
+        ),
+        BuiltinScalarFunction::Log => make_scalar_function_with_hints(
+            math_expressions::log,
+            vec![Hint::AcceptsSingular, Hint::Pad],
+        ),
+        BuiltinScalarFunction::Cot => {
+            Arc::new(|args| make_scalar_function_inner(math_expressions::cot)(args))
         }
-        BuiltinScalarFunction::Atan2 => {
-            Arc::new(|args| make_scalar_function_inner(math_expressions::atan2)(args))
+        BuiltinScalarFunction::BitCount => {
+            Arc::new(|args| make_scalar_function_inner(math_expressions::bit_count)(args))
         }
-        BuiltinScalarFunction::Log => {
-            Arc::new(|args| make_scalar_function_inner(math_expressions::log)(args))
+        BuiltinScalarFunction::Gamma => {
+            Arc::new(|args| make_scalar_function_inner(math_expressions::gamma)(args))
         }
-        BuiltinScalarFunction::Cot => {
-            Arc::new(|args| make_scalar_function_inner(math_expressions::cot)(args))
+        BuiltinScalarFunction::Lgamma => {
+            Arc::new(|args| make_scalar_function_inner(math_expressions::lgamma)(args))
         }
 
         // array functions
-        BuiltinScalarFunction::ArrayElement => Arc::new(|args| {
-            make_scalar_function_inner(array_expressions::array_element)(args)
+        BuiltinScalarFunction::ArrayElement => {
+            let out_of_bounds_error = execution_props.array_out_of_bounds_error;
+            Arc::new(move |args| {
+                make_scalar_function_inner(move |args: &[ArrayRef]| {
+                    array_expressions::array_element_with_bounds_check(
+                        args,
+                        out_of_bounds_error,
+                    )
+                })(args)
+            })
+        }
+        BuiltinScalarFunction::ElementAt => Arc::new(|args| {
+            make_scalar_function_inner(array_expressions::element_at)(args)
         }),
         BuiltinScalarFunction::ArrayExcept => Arc::new(|args| {
             make_scalar_function_inner(array_expressions::array_except)(args)
@@ -279,24 +367,35 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::ArrayRemoveN => Arc::new(|args| {
             make_scalar_function_inner(array_expressions::array_remove_n)(args)
         }),
-        BuiltinScalarFunction::ArrayRemoveAll => Arc::new(|args| {
-            make_scalar_function_inner(array_expressions::array_remove_all)(args)
-        }),
+        // a constant element is compared to the elements of every row as a single value
+        BuiltinScalarFunction::ArrayRemoveAll => make_scalar_function_with_hints(
+            array_expressions::array_remove_all,
+            vec![Hint::Pad, Hint::AcceptsSingular],
+        ),
         BuiltinScalarFunction::ArrayReplace => Arc::new(|args| {
             make_scalar_function_inner(array_expressions::array_replace)(args)
         }),
         BuiltinScalarFunction::ArrayReplaceN => Arc::new(|args| {
             make_scalar_function_inner(array_expressions::array_replace_n)(args)
         }),
-        BuiltinScalarFunction::ArrayReplaceAll => Arc::new(|args| {
-            make_scalar_function_inner(array_expressions::array_replace_all)(args)
-        }),
+        BuiltinScalarFunction::ArrayReplaceAll => make_scalar_function_with_hints(
+            array_expressions::array_replace_all,
+            vec![Hint::Pad, Hint::AcceptsSingular, Hint::AcceptsSingular],
+        ),
         BuiltinScalarFunction::ArrayReverse => Arc::new(|args| {
             make_scalar_function_inner(array_expressions::array_reverse)(args)
         }),
-        BuiltinScalarFunction::ArraySlice => Arc::new(|args| {
-            make_scalar_function_inner(array_expressions::array_slice)(args)
-        }),
+        BuiltinScalarFunction::ArraySlice => {
+            let out_of_bounds_error = execution_props.array_out_of_bounds_error;
+            Arc::new(move |args| {
+                make_scalar_function_inner(move |args: &[ArrayRef]| {
+                    array_expressions::array_slice_with_bounds_check(
+                        args,
+                        out_of_bounds_error,
+                    )
+                })(args)
+            })
+        }
         BuiltinScalarFunction::ArrayIntersect => Arc::new(|args| {
             make_scalar_function_inner(array_expressions::array_intersect)(args)
         }),
@@ -306,6 +405,9 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::ArrayUnion => Arc::new(|args| {
             make_scalar_function_inner(array_expressions::array_union)(args)
         }),
+        BuiltinScalarFunction::ArrayFlattenDistinct => Arc::new(|args| {
+            make_scalar_function_inner(array_expressions::array_flatten_distinct)(args)
+        }),
 
         // string functions
         BuiltinScalarFunction::Ascii => Arc::new(|args| match args[0].data_type() {
@@ -315,17 +417,56 @@ pub fn create_physical_fun(
             DataType::LargeUtf8 => {
                 make_scalar_function_inner(string_expressions::ascii::<i64>)(args)
             }
+            DataType::Binary => {
+                make_scalar_function_inner(string_expressions::ascii_binary)(args)
+            }
             other => exec_err!("Unsupported data type {other:?} for function ascii"),
         }),
         BuiltinScalarFunction::BitLength => Arc::new(|args| match &args[0] {
-            ColumnarValue::Array(v) => Ok(ColumnarValue::Array(bit_length(v.as_ref())?)),
+            ColumnarValue::Array(v) => {
+                // the Int32 bit lengths of Utf8 and FixedSizeBinary values can overflow
+                // before their byte lengths do, so check the longest value first
+                let max_len = if let Some(v) = v.as_string_opt::<i32>() {
+                    v.iter().flatten().map(str::len).max()
+                } else {
+                    v.as_fixed_size_binary_opt()
+                        .map(|v| v.value_length() as usize)
+                };
+                if let Some(max_len) = max_len {
+                    checked_int32_length("bit_length", v.data_type(), max_len, 8)?;
+                }
+                Ok(ColumnarValue::Array(bit_length(v.as_ref())?))
+            }
             ColumnarValue::Scalar(v) => match v {
                 ScalarValue::Utf8(v) => Ok(ColumnarValue::Scalar(ScalarValue::Int32(
-                    v.as_ref().map(|x| (x.len() * 8) as i32),
+                    v.as_ref()
+                        .map(|x| {
+                            checked_int32_length(
+                                "bit_length",
+                                &DataType::Utf8,
+                                x.len(),
+                                8,
+                            )
+                        })
+                        .transpose()?,
                 ))),
                 ScalarValue::LargeUtf8(v) => Ok(ColumnarValue::Scalar(
                     ScalarValue::Int64(v.as_ref().map(|x| (x.len() * 8) as i64)),
                 )),
+                ScalarValue::FixedSizeBinary(width, v) => {
+                    Ok(ColumnarValue::Scalar(ScalarValue::Int32(
+                        v.as_ref()
+                            .map(|_| {
+                                checked_int32_length(
+                                    "bit_length",
+                                    &DataType::FixedSizeBinary(*width),
+                                    *width as usize,
+                                    8,
+                                )
+                            })
+                            .transpose()?,
+                    )))
+                }
                 _ => unreachable!(),
             },
         }),
@@ -361,9 +502,10 @@ pub fn create_physical_fun(
                 ),
             })
         }
-        BuiltinScalarFunction::Chr => {
-            Arc::new(|args| make_scalar_function_inner(string_expressions::chr)(args))
-        }
+        BuiltinScalarFunction::Chr => Arc::new(|args| match args.len() {
+            1 => make_scalar_function_inner(string_expressions::chr::<i32>)(args),
+            _ => make_scalar_function_inner(string_expressions::chr_with_encoding)(args),
+        }),
         BuiltinScalarFunction::Coalesce => Arc::new(conditional_expressions::coalesce),
         BuiltinScalarFunction::Concat => Arc::new(string_expressions::concat),
         BuiltinScalarFunction::ConcatWithSeparator => Arc::new(|args| {
@@ -418,11 +560,23 @@ pub fn create_physical_fun(
             ColumnarValue::Array(v) => Ok(ColumnarValue::Array(length(v.as_ref())?)),
             ColumnarValue::Scalar(v) => match v {
                 ScalarValue::Utf8(v) => Ok(ColumnarValue::Scalar(ScalarValue::Int32(
-                    v.as_ref().map(|x| x.len() as i32),
+                    v.as_ref()
+                        .map(|x| {
+                            checked_int32_length(
+                                "octet_length",
+                                &DataType::Utf8,
+                                x.len(),
+                                1,
+                            )
+                        })
+                        .transpose()?,
                 ))),
                 ScalarValue::LargeUtf8(v) => Ok(ColumnarValue::Scalar(
                     ScalarValue::Int64(v.as_ref().map(|x| x.len() as i64)),
                 )),
+                ScalarValue::FixedSizeBinary(width, v) => Ok(ColumnarValue::Scalar(
+                    ScalarValue::Int32(v.as_ref().map(|_| *width)),
+                )),
                 _ => unreachable!(),
             },
         }),
@@ -506,23 +660,27 @@ pub fn create_physical_fun(
             }
         }),
         BuiltinScalarFunction::StartsWith => Arc::new(|args| match args[0].data_type() {
-            DataType::Utf8 => {
-                make_scalar_function_inner(string_expressions::starts_with::<i32>)(args)
-            }
-            DataType::LargeUtf8 => {
-                make_scalar_function_inner(string_expressions::starts_with::<i64>)(args)
-            }
+            DataType::Utf8 => make_scalar_function_with_hints(
+                string_expressions::starts_with::<i32>,
+                vec![Hint::Pad, Hint::AcceptsSingular],
+            )(args),
+            DataType::LargeUtf8 => make_scalar_function_with_hints(
+                string_expressions::starts_with::<i64>,
+                vec![Hint::Pad, Hint::AcceptsSingular],
+            )(args),
             other => {
                 exec_err!("Unsupported data type {other:?} for function starts_with")
             }
         }),
         BuiltinScalarFunction::EndsWith => Arc::new(|args| match args[0].data_type() {
-            DataType::Utf8 => {
-                make_scalar_function_inner(string_expressions::ends_with::<i32>)(args)
-            }
-            DataType::LargeUtf8 => {
-                make_scalar_function_inner(string_expressions::ends_with::<i64>)(args)
-            }
+            DataType::Utf8 => make_scalar_function_with_hints(
+                string_expressions::ends_with::<i32>,
+                vec![Hint::Pad, Hint::AcceptsSingular],
+            )(args),
+            DataType::LargeUtf8 => make_scalar_function_with_hints(
+                string_expressions::ends_with::<i64>,
+                vec![Hint::Pad, Hint::AcceptsSingular],
+            )(args),
             other => {
                 exec_err!("Unsupported data type {other:?} for function ends_with")
             }
@@ -532,13 +690,19 @@ pub fn create_physical_fun(
                 let func = invoke_if_unicode_expressions_feature_flag!(
                     strpos, Int32Type, "strpos"
                 );
-                make_scalar_function_inner(func)(args)
+                make_scalar_function_with_hints(
+                    func,
+                    vec![Hint::Pad, Hint::AcceptsSingular],
+                )(args)
             }
             DataType::LargeUtf8 => {
                 let func = invoke_if_unicode_expressions_feature_flag!(
                     strpos, Int64Type, "strpos"
                 );
-                make_scalar_function_inner(func)(args)
+                make_scalar_function_with_hints(
+                    func,
+                    vec![Hint::Pad, Hint::AcceptsSingular],
+                )(args)
             }
             other => exec_err!("Unsupported data type {other:?} for function strpos"),
         }),
@@ -665,6 +829,29 @@ pub fn create_physical_fun(
     })
 }
 
+/// Returns `len * multiplier` as the Int32 length returned by `name` for
+/// Utf8 and FixedSizeBinary inputs of `input_type`, or an error if it does not fit
+/// in an Int32
+fn checked_int32_length(
+    name: &str,
+    input_type: &DataType,
+    len: usize,
+    multiplier: usize,
+) -> Result<i32> {
+    // the large type of the input returns an Int64 length
+    let large_type = match input_type {
+        DataType::FixedSizeBinary(_) | DataType::Binary => DataType::LargeBinary,
+        _ => DataType::LargeUtf8,
+    };
+    len.checked_mul(multiplier)
+        .and_then(|length| i32::try_from(length).ok())
+        .ok_or_else(|| {
+            exec_datafusion_err!(
+                "{name} of a value of {len} bytes overflows Int32, cast it to {large_type} instead"
+            )
+        })
+}
+
 #[deprecated(
     since = "32.0.0",
     note = "Moved to `expr` crate. Please use `BuiltinScalarFunction::monotonicity()` instead"
@@ -729,21 +916,24 @@ fn func_order_in_one_dimension(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::expressions::lit;
     use crate::expressions::try_cast;
+    use crate::expressions::{col, lit, Column};
     use arrow::{
         array::{
-            Array, ArrayRef, BooleanArray, Float32Array, Float64Array, Int32Array,
-            StringArray, UInt64Array,
+            Array, ArrayRef, BinaryArray, BooleanArray, Float32Array, Float64Array,
+            Int32Array, LargeStringArray, ListArray, StringArray, UInt64Array,
         },
-        datatypes::Field,
+        datatypes::{Field, Int64Type},
         record_batch::RecordBatch,
     };
-    use datafusion_common::cast::as_uint64_array;
+    use datafusion_common::cast::{
+        as_float32_array, as_int32_array, as_int64_array, as_list_array, as_uint64_array,
+    };
     use datafusion_common::{exec_err, internal_err, plan_err};
     use datafusion_common::{DataFusionError, Result, ScalarValue};
     use datafusion_expr::type_coercion::functions::data_types;
     use datafusion_expr::Signature;
+    use std::str::FromStr;
 
     /// $FUNC function to test
     /// $ARGS arguments (vec) to pass to function
@@ -823,6 +1013,71 @@ mod tests {
             Int32,
             Int32Array
         );
+        test_function!(
+            Ascii,
+            &[lit("ésoj"), lit("latin1")],
+            Ok(Some(233)),
+            i32,
+            Int32,
+            Int32Array
+        );
+        test_function!(
+            Ascii,
+            &[lit("💯"), lit("UTF8")],
+            Ok(Some(128175)),
+            i32,
+            Int32,
+            Int32Array
+        );
+        test_function!(
+            Ascii,
+            &[lit("💯"), lit("latin1")],
+            exec_err!("character '💯' has no equivalent in encoding latin1"),
+            i32,
+            Int32,
+            Int32Array
+        );
+        test_function!(
+            Ascii,
+            &[lit("x"), lit("ebcdic")],
+            exec_err!("There is no character encoding named 'ebcdic', currently supported encodings are: utf8, latin1"),
+            i32,
+            Int32,
+            Int32Array
+        );
+        test_function!(
+            Ascii,
+            &[
+                lit(ScalarValue::Binary(Some(vec![0xE9, 0x61]))),
+                lit("latin1")
+            ],
+            Ok(Some(233)),
+            i32,
+            Int32,
+            Int32Array
+        );
+        test_function!(
+            Ascii,
+            &[
+                lit(ScalarValue::Binary(Some(vec![0xC3, 0xA9]))),
+                lit("utf8")
+            ],
+            Ok(Some(233)),
+            i32,
+            Int32,
+            Int32Array
+        );
+        test_function!(
+            Ascii,
+            &[
+                lit(ScalarValue::Binary(Some(vec![0xE9, 0x61]))),
+                lit("utf8")
+            ],
+            exec_err!("invalid byte sequence for encoding utf8"),
+            i32,
+            Int32,
+            Int32Array
+        );
         test_function!(
             BitLength,
             &[lit("chars")],
@@ -840,6 +1095,14 @@ mod tests {
             Int32Array
         );
         test_function!(BitLength, &[lit("")], Ok(Some(0)), i32, Int32, Int32Array);
+        test_function!(
+            BitLength,
+            &[lit(ScalarValue::FixedSizeBinary(16, Some(vec![0xab; 16])))],
+            Ok(Some(128)),
+            i32,
+            Int32,
+            Int32Array
+        );
         test_function!(
             Btrim,
             &[lit(" trim ")],
@@ -1007,6 +1270,46 @@ mod tests {
             Utf8,
             StringArray
         );
+        test_function!(
+            Chr,
+            &[lit(ScalarValue::Int64(Some(233))), lit("latin1")],
+            Ok(Some([0xE9].as_slice())),
+            &[u8],
+            Binary,
+            BinaryArray
+        );
+        test_function!(
+            Chr,
+            &[lit(ScalarValue::Int64(Some(233))), lit("utf8")],
+            Ok(Some([0xC3, 0xA9].as_slice())),
+            &[u8],
+            Binary,
+            BinaryArray
+        );
+        test_function!(
+            Chr,
+            &[lit(ScalarValue::Int64(None)), lit("latin1")],
+            Ok(None),
+            &[u8],
+            Binary,
+            BinaryArray
+        );
+        test_function!(
+            Chr,
+            &[lit(ScalarValue::Int64(Some(0))), lit("latin1")],
+            exec_err!("null character not permitted."),
+            &[u8],
+            Binary,
+            BinaryArray
+        );
+        test_function!(
+            Chr,
+            &[lit(ScalarValue::Int64(Some(256))), lit("latin1")],
+            exec_err!("character number 256 is out of range for encoding latin1"),
+            &[u8],
+            Binary,
+            BinaryArray
+        );
         test_function!(
             Concat,
             &[lit("aa"), lit("bb"), lit("cc"),],
@@ -1068,6 +1371,38 @@ mod tests {
             Utf8,
             StringArray
         );
+        test_function!(
+            Cot,
+            &[lit(ScalarValue::Float64(Some(0.0)))],
+            Ok(Some(f64::INFINITY)),
+            f64,
+            Float64,
+            Float64Array
+        );
+        test_function!(
+            Cot,
+            &[lit(ScalarValue::Float64(Some(-0.0)))],
+            Ok(Some(f64::INFINITY)),
+            f64,
+            Float64,
+            Float64Array
+        );
+        test_function!(
+            Cot,
+            &[lit(ScalarValue::Float32(Some(0.0)))],
+            Ok(Some(f32::INFINITY)),
+            f32,
+            Float32,
+            Float32Array
+        );
+        test_function!(
+            Cot,
+            &[lit(ScalarValue::Float64(None))],
+            Ok(None),
+            f64,
+            Float64,
+            Float64Array
+        );
         test_function!(
             Exp,
             &[lit(ScalarValue::Int32(Some(1)))],
@@ -1118,6 +1453,22 @@ mod tests {
         );
         test_function!(InitCap, &[lit("")], Ok(Some("")), &str, Utf8, StringArray);
         test_function!(InitCap, &[lit("")], Ok(Some("")), &str, Utf8, StringArray);
+        test_function!(
+            InitCap,
+            &[lit("ßtraße ﬁsh")],
+            Ok(Some("Sstraße Fish")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            InitCap,
+            &[lit("élan VITAL")],
+            Ok(Some("Élan Vital")),
+            &str,
+            Utf8,
+            StringArray
+        );
         test_function!(
             InitCap,
             &[lit(ScalarValue::Utf8(None))],
@@ -1126,6 +1477,30 @@ mod tests {
             Utf8,
             StringArray
         );
+        test_function!(
+            InitCap,
+            &[lit(ScalarValue::LargeUtf8(Some("hi THOMAS".to_string())))],
+            Ok(Some("Hi Thomas")),
+            &str,
+            LargeUtf8,
+            LargeStringArray
+        );
+        test_function!(
+            InitCap,
+            &[lit(ScalarValue::LargeUtf8(Some("élan VITAL".to_string())))],
+            Ok(Some("Élan Vital")),
+            &str,
+            LargeUtf8,
+            LargeStringArray
+        );
+        test_function!(
+            InitCap,
+            &[lit(ScalarValue::LargeUtf8(None))],
+            Ok(None),
+            &str,
+            LargeUtf8,
+            LargeStringArray
+        );
         #[cfg(feature = "unicode_expressions")]
         test_function!(
             Left,
@@ -1252,6 +1627,51 @@ mod tests {
             StringArray
         );
         #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Lpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(-3))),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Lpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(i64::MIN))),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Lpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(-3))), lit("xy"),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Lpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(-3))), lit(""),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Lpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(0))), lit(""),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
         test_function!(
             Lpad,
             &[lit("hi"), lit(ScalarValue::Int64(None)),],
@@ -1291,6 +1711,15 @@ mod tests {
             StringArray
         );
         #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Lpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(5))), lit("éñ"),],
+            Ok(Some("éñéhi")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
         test_function!(
             Lpad,
             &[lit("hi"), lit(ScalarValue::Int64(Some(5))), lit(" "),],
@@ -1440,6 +1869,22 @@ mod tests {
             Int32Array
         );
         test_function!(OctetLength, &[lit("")], Ok(Some(0)), i32, Int32, Int32Array);
+        test_function!(
+            OctetLength,
+            &[lit(ScalarValue::FixedSizeBinary(16, Some(vec![0xab; 16])))],
+            Ok(Some(16)),
+            i32,
+            Int32,
+            Int32Array
+        );
+        test_function!(
+            OctetLength,
+            &[lit(ScalarValue::FixedSizeBinary(16, None))],
+            Ok(None),
+            i32,
+            Int32,
+            Int32Array
+        );
         test_function!(
             OctetLength,
             &[lit(ScalarValue::Utf8(None))],
@@ -1456,6 +1901,22 @@ mod tests {
             Utf8,
             StringArray
         );
+        test_function!(
+            Repeat,
+            &[lit("josé"), lit(ScalarValue::Int64(Some(2))),],
+            Ok(Some("joséjosé")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            Repeat,
+            &[lit("Pg"), lit(ScalarValue::Int64(Some(-1))),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
         test_function!(
             Repeat,
             &[
@@ -1475,11 +1936,21 @@ mod tests {
             Utf8,
             StringArray
         );
-        #[cfg(feature = "unicode_expressions")]
         test_function!(
-            Reverse,
-            &[lit("abcde")],
-            Ok(Some("edcba")),
+            Repeat,
+            &[lit("Pg"), lit(ScalarValue::Int64(Some(i64::MAX))),],
+            exec_err!(
+                "repeat of a string of 2 bytes 9223372036854775807 times overflows"
+            ),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Reverse,
+            &[lit("abcde")],
+            Ok(Some("edcba")),
             &str,
             Utf8,
             StringArray
@@ -1648,6 +2119,51 @@ mod tests {
             StringArray
         );
         #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Rpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(-3))),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Rpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(i64::MIN))),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Rpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(-3))), lit("xy"),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Rpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(-3))), lit(""),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Rpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(0))), lit(""),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
         test_function!(
             Rpad,
             &[lit("hi"), lit(ScalarValue::Int64(None)),],
@@ -1687,6 +2203,15 @@ mod tests {
             StringArray
         );
         #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Rpad,
+            &[lit("hi"), lit(ScalarValue::Int64(Some(5))), lit("éñ"),],
+            Ok(Some("hiéñé")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
         test_function!(
             Rpad,
             &[lit("hi"), lit(ScalarValue::Int64(Some(5))), lit(" "),],
@@ -1855,6 +2380,165 @@ mod tests {
             Utf8,
             StringArray
         );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(Some(2))),
+                lit(ScalarValue::Int64(Some(3))),
+            ],
+            Ok(Some("b-c")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a~@~b~@~c"),
+                lit("~@~"),
+                lit(ScalarValue::Int64(Some(1))),
+                lit(ScalarValue::Int64(Some(3))),
+            ],
+            Ok(Some("a~@~b~@~c")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(Some(-2))),
+                lit(ScalarValue::Int64(Some(-1))),
+            ],
+            Ok(Some("c-d")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(Some(2))),
+                lit(ScalarValue::Int64(Some(-2))),
+            ],
+            Ok(Some("b-c")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(Some(3))),
+                lit(ScalarValue::Int64(Some(2))),
+            ],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[lit("abc"), lit(""), lit(ScalarValue::Int64(Some(2)))],
+            Ok(Some("b")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[lit("abc"), lit(""), lit(ScalarValue::Int64(Some(4)))],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(Some(3))),
+                lit(ScalarValue::Int64(Some(10))),
+            ],
+            Ok(Some("c-d")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(Some(-10))),
+                lit(ScalarValue::Int64(Some(1))),
+            ],
+            Ok(Some("a")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(Some(5))),
+                lit(ScalarValue::Int64(Some(10))),
+            ],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(Some(-10))),
+                lit(ScalarValue::Int64(Some(-5))),
+            ],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(Some(0))),
+                lit(ScalarValue::Int64(Some(2))),
+            ],
+            exec_err!("field position must not be zero"),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            SplitPart,
+            &[
+                lit("a-b-c-d"),
+                lit("-"),
+                lit(ScalarValue::Int64(None)),
+                lit(ScalarValue::Int64(Some(2))),
+            ],
+            Ok(None),
+            &str,
+            Utf8,
+            StringArray
+        );
         test_function!(
             StartsWith,
             &[lit("alphabet"), lit("alph"),],
@@ -2133,6 +2817,32 @@ mod tests {
             Utf8,
             StringArray
         );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Substr,
+            &[
+                lit("alphabet"),
+                lit(ScalarValue::Int64(Some(3))),
+                lit(ScalarValue::Int64(Some(i64::MAX))),
+            ],
+            Ok(Some("phabet")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            Substr,
+            &[
+                lit("alphabet"),
+                lit(ScalarValue::Int64(Some(i64::MIN))),
+                lit(ScalarValue::Int64(Some(3))),
+            ],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
         #[cfg(not(feature = "unicode_expressions"))]
         test_function!(
             Substr,
@@ -2148,6 +2858,115 @@ mod tests {
             StringArray
         );
         #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[lit("a.b.c.d"), lit("."), lit(ScalarValue::Int64(Some(2))),],
+            Ok(Some("a.b")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[lit("a.b.c.d"), lit("."), lit(ScalarValue::Int64(Some(-2))),],
+            Ok(Some("c.d")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[lit("a.b.c.d"), lit("."), lit(ScalarValue::Int64(Some(10))),],
+            Ok(Some("a.b.c.d")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[lit("a.b.c.d"), lit("."), lit(ScalarValue::Int64(Some(-10))),],
+            Ok(Some("a.b.c.d")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[
+                lit("www.apache.org"),
+                lit("ap"),
+                lit(ScalarValue::Int64(Some(1))),
+            ],
+            Ok(Some("www.")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[lit("a::b::c"), lit("::"), lit(ScalarValue::Int64(Some(2))),],
+            Ok(Some("a::b")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[lit("a::b::c"), lit("::"), lit(ScalarValue::Int64(Some(-1))),],
+            Ok(Some("c")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[lit("a.b.c.d"), lit(""), lit(ScalarValue::Int64(Some(1))),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[lit("a.b.c.d"), lit("."), lit(ScalarValue::Int64(Some(0))),],
+            Ok(Some("")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
+        test_function!(
+            SubstrIndex,
+            &[lit("a.b.c.d"), lit("."), lit(ScalarValue::Int64(None)),],
+            Ok(None),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(not(feature = "unicode_expressions"))]
+        test_function!(
+            SubstrIndex,
+            &[
+                lit("a.b.c.d"),
+                lit("."),
+                lit(ScalarValue::Int64(Some(1))),
+            ],
+            internal_err!(
+                "function substr_index requires compilation with feature flag: unicode_expressions."
+            ),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "unicode_expressions")]
         test_function!(
             Translate,
             &[lit("12345"), lit("143"), lit("ax"),],
@@ -2263,9 +3082,139 @@ mod tests {
             Utf8,
             StringArray
         );
+        test_function!(
+            Upper,
+            &[lit("straße")],
+            Ok(Some("STRASSE")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            Upper,
+            &[lit("ﬁsh")],
+            Ok(Some("FISH")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            Lower,
+            &[lit("STRASSE")],
+            Ok(Some("strasse")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            Lower,
+            &[lit("ÀÉÎ")],
+            Ok(Some("àéî")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_case_conversion_preserves_whitespace() -> Result<()> {
+        test_function!(
+            InitCap,
+            &[lit("  hello  world  ")],
+            Ok(Some("  Hello  World  ")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            InitCap,
+            &[lit("\thello\nWORLD\r\n")],
+            Ok(Some("\tHello\nWorld\r\n")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            InitCap,
+            &[lit("1st-place o'neil_x")],
+            Ok(Some("1st-Place O'Neil_X")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            InitCap,
+            &[lit("   ")],
+            Ok(Some("   ")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            Upper,
+            &[lit("  hello, \tworld! 42  ")],
+            Ok(Some("  HELLO, \tWORLD! 42  ")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            Lower,
+            &[lit("  HELLO, \tWORLD! 42  ")],
+            Ok(Some("  hello, \tworld! 42  ")),
+            &str,
+            Utf8,
+            StringArray
+        );
         Ok(())
     }
 
+    #[test]
+    fn test_checked_int32_length() {
+        // the longest Utf8 value whose bit length fits in an Int32
+        let max_bit_len = i32::MAX as usize / 8;
+        assert_eq!(
+            checked_int32_length("bit_length", &DataType::Utf8, max_bit_len, 8).unwrap(),
+            2147483640
+        );
+        let err = checked_int32_length("bit_length", &DataType::Utf8, max_bit_len + 1, 8)
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: bit_length of a value of 268435456 bytes overflows Int32, cast it to LargeUtf8 instead"
+        );
+
+        // binary values are cast to LargeBinary instead
+        let err = checked_int32_length(
+            "bit_length",
+            &DataType::FixedSizeBinary(i32::MAX),
+            i32::MAX as usize,
+            8,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: bit_length of a value of 2147483647 bytes overflows Int32, cast it to LargeBinary instead"
+        );
+
+        assert_eq!(
+            checked_int32_length("octet_length", &DataType::Utf8, i32::MAX as usize, 1)
+                .unwrap(),
+            i32::MAX
+        );
+        assert!(checked_int32_length(
+            "octet_length",
+            &DataType::Utf8,
+            i32::MAX as usize + 1,
+            1
+        )
+        .is_err());
+        assert!(
+            checked_int32_length("bit_length", &DataType::Utf8, usize::MAX, 8).is_err()
+        );
+    }
+
     #[test]
     fn test_empty_arguments_error() -> Result<()> {
         let execution_props = ExecutionProps::new();
@@ -2317,6 +3266,277 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_array_out_of_bounds_policy() -> Result<()> {
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![
+            Some(1),
+            Some(2),
+            Some(3),
+        ])]);
+        let schema = Schema::new(vec![Field::new("a", list.data_type().clone(), true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(list)])?;
+        let element_args = [col("a", &schema)?, lit(100_i64)];
+        let slice_args = [col("a", &schema)?, lit(5_i64), lit(10_i64)];
+
+        // by default an out of bounds index is NULL, or an empty array for array_slice
+        let execution_props = ExecutionProps::new();
+        let expr = create_physical_expr_with_type_coercion(
+            &BuiltinScalarFunction::ArrayElement,
+            &element_args,
+            &schema,
+            &execution_props,
+        )?;
+        assert!(expr.evaluate(&batch)?.into_array(1)?.is_null(0));
+        let expr = create_physical_expr_with_type_coercion(
+            &BuiltinScalarFunction::ArraySlice,
+            &slice_args,
+            &schema,
+            &execution_props,
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(1)?;
+        assert_eq!(as_list_array(&result)?.value_length(0), 0);
+
+        // in strict mode it is an error
+        let execution_props = ExecutionProps::new().with_array_out_of_bounds_error(true);
+        let expr = create_physical_expr_with_type_coercion(
+            &BuiltinScalarFunction::ArrayElement,
+            &element_args,
+            &schema,
+            &execution_props,
+        )?;
+        let err = expr.evaluate(&batch).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: array_element index 100 is out of bounds for array of length 3"
+        );
+        let expr = create_physical_expr_with_type_coercion(
+            &BuiltinScalarFunction::ArraySlice,
+            &slice_args,
+            &schema,
+            &execution_props,
+        )?;
+        let err = expr.evaluate(&batch).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: array_slice range [5, 10] is out of bounds for array of length 3"
+        );
+
+        // in bounds indexes are unaffected
+        let expr = create_physical_expr_with_type_coercion(
+            &BuiltinScalarFunction::ArrayElement,
+            &[col("a", &schema)?, lit(-1_i64)],
+            &schema,
+            &execution_props,
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(1)?;
+        assert_eq!(as_int64_array(&result)?.value(0), 3);
+
+        // arrays are 1-based, so index 0 is out of bounds
+        let expr = create_physical_expr_with_type_coercion(
+            &BuiltinScalarFunction::ArrayElement,
+            &[col("a", &schema)?, lit(0_i64)],
+            &schema,
+            &execution_props,
+        )?;
+        let err = expr.evaluate(&batch).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: array_element index 0 is out of bounds for array of length 3"
+        );
+
+        // slice bounds before the first element are clamped to it, like in substr
+        let expr = create_physical_expr_with_type_coercion(
+            &BuiltinScalarFunction::ArraySlice,
+            &[col("a", &schema)?, lit(-10_i64), lit(2_i64)],
+            &schema,
+            &execution_props,
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(1)?;
+        assert_eq!(as_list_array(&result)?.value_length(0), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_character_length_aliases() -> Result<()> {
+        let execution_props = ExecutionProps::new();
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let columns: Vec<ArrayRef> = vec![Arc::new(Int32Array::from(vec![1]))];
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+
+        // every alias must resolve to the same function and behave identically
+        for alias in BuiltinScalarFunction::CharacterLength.aliases() {
+            let fun = BuiltinScalarFunction::from_str(alias)?;
+            assert_eq!(fun, BuiltinScalarFunction::CharacterLength);
+
+            let expr = create_physical_expr_with_type_coercion(
+                &fun,
+                &[lit("josé")],
+                &schema,
+                &execution_props,
+            )?;
+            assert_eq!(expr.data_type(&schema)?, DataType::Int32);
+
+            let result = expr.evaluate(&batch);
+            #[cfg(feature = "unicode_expressions")]
+            {
+                let result = result?.into_array(batch.num_rows())?;
+                assert_eq!(as_int32_array(&result)?.value(0), 4, "alias {alias}");
+            }
+            #[cfg(not(feature = "unicode_expressions"))]
+            {
+                let error = result.expect_err(alias).strip_backtrace();
+                assert!(
+                    error.contains(
+                        "requires compilation with feature flag: unicode_expressions"
+                    ),
+                    "alias {alias}: {error}"
+                );
+            }
+        }
+        assert_eq!(
+            BuiltinScalarFunction::CharacterLength.to_string(),
+            "character_length"
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "unicode_expressions")]
+    fn test_left_right_substr_ascii_and_multibyte() -> Result<()> {
+        use crate::unicode_expressions::{left, right, substr};
+        use arrow::array::Int64Array;
+        use datafusion_common::cast::as_string_array;
+
+        // an all-ASCII array takes the byte offset path, the mixed one decodes characters
+        let ascii: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("abcde"),
+            None,
+            Some(""),
+            Some("xy"),
+        ]));
+        let mixed: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("abcde"),
+            None,
+            Some(""),
+            Some("éy"),
+        ]));
+        let n: ArrayRef = Arc::new(Int64Array::from(vec![2, 2, 2, -1]));
+        let count: ArrayRef = Arc::new(Int64Array::from(vec![3, 3, 3, 1]));
+
+        for (strings, last) in [
+            (ascii, ["x", "y", "xy", "x"]),
+            (mixed, ["é", "y", "éy", "é"]),
+        ] {
+            let lefts = left::<i32>(&[strings.clone(), n.clone()])?;
+            let rights = right::<i32>(&[strings.clone(), n.clone()])?;
+            let substrs = substr::<i32>(&[strings.clone(), n.clone(), count.clone()])?;
+            let substrs_from = substr::<i32>(&[strings.clone(), n.clone()])?;
+
+            for (result, expected) in [
+                (
+                    lefts,
+                    StringArray::from(vec![Some("ab"), None, Some(""), Some(last[0])]),
+                ),
+                (
+                    rights,
+                    StringArray::from(vec![Some("de"), None, Some(""), Some(last[1])]),
+                ),
+                (
+                    substrs,
+                    StringArray::from(vec![Some("bcd"), None, Some(""), Some("")]),
+                ),
+                (
+                    substrs_from,
+                    StringArray::from(vec![Some("bcde"), None, Some(""), Some(last[2])]),
+                ),
+            ] {
+                assert_eq!(as_string_array(&result)?, &expected);
+            }
+
+            // a slice of the array still resolves offsets relative to the values buffer
+            let sliced = strings.slice(3, 1);
+            let result =
+                left::<i32>(&[sliced, Arc::new(Int64Array::from(vec![1])) as ArrayRef])?;
+            assert_eq!(as_string_array(&result)?, &StringArray::from(vec![last[3]]));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rounding_functions_preserve_float32() -> Result<()> {
+        let execution_props = ExecutionProps::new();
+        let schema = Schema::new(vec![Field::new("a", DataType::Float32, true)]);
+        let columns: Vec<ArrayRef> = vec![Arc::new(Float32Array::from(vec![
+            Some(1.25),
+            None,
+            Some(-2.75),
+        ]))];
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+
+        let cases: Vec<(BuiltinScalarFunction, Vec<Arc<dyn PhysicalExpr>>)> = vec![
+            (BuiltinScalarFunction::Round, vec![col("a", &schema)?]),
+            (
+                BuiltinScalarFunction::Round,
+                vec![col("a", &schema)?, lit(ScalarValue::Int64(Some(1)))],
+            ),
+            (BuiltinScalarFunction::Trunc, vec![col("a", &schema)?]),
+            (
+                BuiltinScalarFunction::Trunc,
+                vec![col("a", &schema)?, lit(ScalarValue::Int64(Some(1)))],
+            ),
+            (
+                BuiltinScalarFunction::Round,
+                vec![col("a", &schema)?, lit(ScalarValue::Int32(Some(1)))],
+            ),
+            (
+                BuiltinScalarFunction::Trunc,
+                vec![col("a", &schema)?, lit(ScalarValue::Int32(Some(1)))],
+            ),
+            (BuiltinScalarFunction::Ceil, vec![col("a", &schema)?]),
+            (BuiltinScalarFunction::Floor, vec![col("a", &schema)?]),
+        ];
+
+        for (fun, args) in cases {
+            let expr = create_physical_expr_with_type_coercion(
+                &fun,
+                &args,
+                &schema,
+                &execution_props,
+            )?;
+            assert_eq!(expr.data_type(&schema)?, DataType::Float32, "{fun}");
+
+            // array input
+            let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+            let result = as_float32_array(&result)?;
+            assert_eq!(result.len(), 3, "{fun}");
+            assert!(result.is_null(1), "{fun}");
+
+            // scalar input
+            let scalar_args = args
+                .iter()
+                .map(|arg| {
+                    if arg.as_any().is::<Column>() {
+                        lit(ScalarValue::Float32(Some(1.25)))
+                    } else {
+                        arg.clone()
+                    }
+                })
+                .collect::<Vec<_>>();
+            let expr = create_physical_expr_with_type_coercion(
+                &fun,
+                &scalar_args,
+                &schema,
+                &execution_props,
+            )?;
+            match expr.evaluate(&batch)? {
+                ColumnarValue::Scalar(ScalarValue::Float32(Some(_))) => {}
+                other => panic!("{fun}: expected a Float32 scalar, got {other:?}"),
+            }
+        }
+        Ok(())
+    }
+
     // Helper function just for testing.
     // Returns `expressions` coerced to types compatible with
     // `signature`, if possible.
@@ -2511,4 +3731,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_make_scalar_function_with_mismatched_array_lengths() {
+        let adapter_func = make_scalar_function_with_hints(dummy_function, vec![]);
+
+        let short_arg = ColumnarValue::Array(
+            ScalarValue::Int64(Some(1))
+                .to_array_of_size(3)
+                .expect("Failed to convert to array of size"),
+        );
+        let long_arg = ColumnarValue::Array(
+            ScalarValue::Int64(Some(1))
+                .to_array_of_size(5)
+                .expect("Failed to convert to array of size"),
+        );
+        let scalar_arg = ColumnarValue::Scalar(ScalarValue::Int64(Some(1)));
+
+        let error = adapter_func(&[short_arg, scalar_arg, long_arg])
+            .expect_err("mismatched array lengths should error");
+        assert!(error
+            .strip_backtrace()
+            .starts_with("Internal error: argument length mismatch"));
+    }
 }
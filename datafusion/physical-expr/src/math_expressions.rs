@@ -18,18 +18,30 @@
 //! Math expressions
 
 use std::any::type_name;
+use std::fmt::Display;
 use std::iter;
 use std::mem::swap;
+use std::str::FromStr;
 use std::sync::Arc;
 
-use arrow::array::ArrayRef;
-use arrow::array::{BooleanArray, Float32Array, Float64Array, Int64Array};
-use arrow::datatypes::DataType;
+use arrow::array::{
+    new_null_array, ArrayRef, ArrowNativeTypeOp, AsArray, PrimitiveArray,
+};
+use arrow::array::{
+    BooleanArray, Decimal128Array, Float32Array, Float64Array, Int64Array,
+};
+use arrow::compute::cast;
+use arrow::datatypes::{
+    ArrowPrimitiveType, DataType, Decimal128Type, DecimalType, Float32Type, Float64Type,
+    Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type, UInt32Type, UInt64Type,
+    UInt8Type, DECIMAL128_MAX_PRECISION,
+};
 use arrow_array::Array;
-use rand::{thread_rng, Rng};
+use rand::rngs::StdRng;
+use rand::{thread_rng, Rng, SeedableRng};
 
 use datafusion_common::ScalarValue::{Float32, Int64};
-use datafusion_common::{exec_err, ScalarValue};
+use datafusion_common::{exec_datafusion_err, exec_err, internal_err, ScalarValue};
 use datafusion_common::{DataFusionError, Result};
 use datafusion_expr::ColumnarValue;
 
@@ -115,28 +127,49 @@ macro_rules! make_function_scalar_inputs {
     }};
 }
 
+/// Returns the number of pairs of values of two arguments of lengths `len1` and
+/// `len2`. An argument with a single value, such as a constant passed as a single
+/// value (see `Hint::AcceptsSingular`), is paired with every value of the other
+/// argument, and it is an internal error for the arguments to have other
+/// differing lengths.
+fn paired_len(len1: usize, len2: usize, name1: &str, name2: &str) -> Result<usize> {
+    match (len1, len2) {
+        (1, len) | (len, 1) => Ok(len),
+        _ if len1 == len2 => Ok(len1),
+        _ => {
+            internal_err!("{name1} and {name2} have different lengths: {len1} and {len2}")
+        }
+    }
+}
+
+/// Applies `$FUNC` to each pair of values of two arguments, producing null when
+/// either is null, pairing them as described in [`paired_len`].
 macro_rules! make_function_inputs2 {
     ($ARG1: expr, $ARG2: expr, $NAME1:expr, $NAME2: expr, $ARRAY_TYPE:ident, $FUNC: block) => {{
-        let arg1 = downcast_arg!($ARG1, $NAME1, $ARRAY_TYPE);
-        let arg2 = downcast_arg!($ARG2, $NAME2, $ARRAY_TYPE);
-
-        arg1.iter()
-            .zip(arg2.iter())
-            .map(|(a1, a2)| match (a1, a2) {
-                (Some(a1), Some(a2)) => Some($FUNC(a1, a2.try_into().ok()?)),
-                _ => None,
-            })
-            .collect::<$ARRAY_TYPE>()
+        make_function_inputs2!(
+            $ARG1,
+            $ARG2,
+            $NAME1,
+            $NAME2,
+            $ARRAY_TYPE,
+            $ARRAY_TYPE,
+            $FUNC
+        )
     }};
     ($ARG1: expr, $ARG2: expr, $NAME1:expr, $NAME2: expr, $ARRAY_TYPE1:ident, $ARRAY_TYPE2:ident, $FUNC: block) => {{
         let arg1 = downcast_arg!($ARG1, $NAME1, $ARRAY_TYPE1);
         let arg2 = downcast_arg!($ARG2, $NAME2, $ARRAY_TYPE2);
 
-        arg1.iter()
-            .zip(arg2.iter())
-            .map(|(a1, a2)| match (a1, a2) {
-                (Some(a1), Some(a2)) => Some($FUNC(a1, a2.try_into().ok()?)),
-                _ => None,
+        let (len1, len2) = (arg1.len(), arg2.len());
+        let len = paired_len(len1, len2, $NAME1, $NAME2)?;
+        (0..len)
+            .map(|i| {
+                let i1 = if len1 == 1 { 0 } else { i };
+                let i2 = if len2 == 1 { 0 } else { i };
+                if arg1.is_null(i1) || arg2.is_null(i2) {
+                    return None;
+                }
+                Some($FUNC(arg1.value(i1), arg2.value(i2).try_into().ok()?))
             })
             .collect::<$ARRAY_TYPE1>()
     }};
@@ -167,9 +200,6 @@ math_unary_function!("atan", atan);
 math_unary_function!("asinh", asinh);
 math_unary_function!("acosh", acosh);
 math_unary_function!("atanh", atanh);
-math_unary_function!("floor", floor);
-math_unary_function!("ceil", ceil);
-math_unary_function!("signum", signum);
 math_unary_function!("exp", exp);
 math_unary_function!("ln", ln);
 math_unary_function!("log2", log2);
@@ -177,19 +207,122 @@ math_unary_function!("log10", log10);
 math_unary_function!("degrees", to_degrees);
 math_unary_function!("radians", to_radians);
 
+/// Floor SQL function, which also accepts decimals
+pub fn floor(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if let DataType::Decimal128(_, _) = args[0].data_type() {
+        return round_decimal128_to_integer(&args[0], DecimalRounding::Floor, "floor");
+    }
+    unary_primitive_array_op!(&args[0], "floor", floor)
+}
+
+/// Ceil SQL function, which also accepts decimals
+pub fn ceil(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if let DataType::Decimal128(_, _) = args[0].data_type() {
+        return round_decimal128_to_integer(&args[0], DecimalRounding::Ceil, "ceil");
+    }
+    unary_primitive_array_op!(&args[0], "ceil", ceil)
+}
+
+/// Signum SQL function
+///
+/// Returns -1 or 1 for negative or positive numbers, including infinities, and NaN
+/// for NaN. Both zeros return 0, so `signum(-0.0)` is 0 rather than -1.
+pub fn signum(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    match &args[0] {
+        ColumnarValue::Array(array) => match array.data_type() {
+            DataType::Float32 => Ok(ColumnarValue::Array(Arc::new(
+                array
+                    .as_primitive::<Float32Type>()
+                    .unary::<_, Float32Type>(compute_signum32),
+            ))),
+            DataType::Float64 => Ok(ColumnarValue::Array(Arc::new(
+                array
+                    .as_primitive::<Float64Type>()
+                    .unary::<_, Float64Type>(compute_signum64),
+            ))),
+            other => exec_err!("Unsupported data type {other:?} for function signum"),
+        },
+        ColumnarValue::Scalar(ScalarValue::Float32(a)) => Ok(ColumnarValue::Scalar(
+            ScalarValue::Float32(a.map(compute_signum32)),
+        )),
+        ColumnarValue::Scalar(ScalarValue::Float64(a)) => Ok(ColumnarValue::Scalar(
+            ScalarValue::Float64(a.map(compute_signum64)),
+        )),
+        ColumnarValue::Scalar(other) => exec_err!(
+            "Unsupported data type {:?} for function signum",
+            other.data_type()
+        ),
+    }
+}
+
+fn compute_signum32(x: f32) -> f32 {
+    if x == 0.0 {
+        0.0
+    } else {
+        x.signum()
+    }
+}
+
+fn compute_signum64(x: f64) -> f64 {
+    if x == 0.0 {
+        0.0
+    } else {
+        x.signum()
+    }
+}
+
 /// Factorial SQL function
 pub fn factorial(args: &[ArrayRef]) -> Result<ArrayRef> {
     match args[0].data_type() {
-        DataType::Int64 => Ok(Arc::new(make_function_scalar_inputs!(
-            &args[0],
-            "value",
-            Int64Array,
-            { |value: i64| { (1..=value).product() } }
-        )) as ArrayRef),
+        DataType::Int64 => {
+            let values = args[0].as_primitive::<Int64Type>();
+            let result = values
+                .iter()
+                .map(|value| value.map(compute_factorial).transpose())
+                .collect::<Result<Int64Array>>()?;
+            Ok(Arc::new(result) as ArrayRef)
+        }
         other => exec_err!("Unsupported data type {other:?} for function factorial."),
     }
 }
 
+/// Computes `value!`, which is 1 for values less than 2, or errors if it does not
+/// fit in an Int64 (from 21! onwards)
+fn compute_factorial(value: i64) -> Result<i64> {
+    (1..=value)
+        .try_fold(1_i64, i64::checked_mul)
+        .ok_or_else(|| {
+            exec_datafusion_err!("factorial overflow: {value}! exceeds Int64 range")
+        })
+}
+
+macro_rules! bit_count_op {
+    ($ARRAY:expr, $TYPE:ty) => {{
+        let result = $ARRAY
+            .as_primitive::<$TYPE>()
+            .unary::<_, Int32Type>(|value| value.count_ones() as i32);
+        Ok(Arc::new(result) as ArrayRef)
+    }};
+}
+
+/// Bit_count SQL function, the number of set bits in an integer
+///
+/// Negative values count the bits of their two's complement representation, so
+/// `bit_count(-1)` of an Int64 is 64.
+pub fn bit_count(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Int8 => bit_count_op!(args[0], Int8Type),
+        DataType::Int16 => bit_count_op!(args[0], Int16Type),
+        DataType::Int32 => bit_count_op!(args[0], Int32Type),
+        DataType::Int64 => bit_count_op!(args[0], Int64Type),
+        DataType::UInt8 => bit_count_op!(args[0], UInt8Type),
+        DataType::UInt16 => bit_count_op!(args[0], UInt16Type),
+        DataType::UInt32 => bit_count_op!(args[0], UInt32Type),
+        DataType::UInt64 => bit_count_op!(args[0], UInt64Type),
+        other => exec_err!("Unsupported data type {other:?} for function bit_count"),
+    }
+}
+
 /// Computes greatest common divisor using Binary GCD algorithm.
 fn compute_gcd(x: i64, y: i64) -> i64 {
     let mut a = x.wrapping_abs();
@@ -263,6 +396,48 @@ pub fn lcm(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// Mod SQL function, the remainder of dividing `x` by `y`
+///
+/// The remainder has the sign of `x`, so `mod(-7, 3)` is -1. Dividing an integer
+/// by zero is an error, while dividing a float by zero returns NaN.
+pub fn modulo(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Int8 => compute_mod::<Int8Type>(&args[0], &args[1], true),
+        DataType::Int16 => compute_mod::<Int16Type>(&args[0], &args[1], true),
+        DataType::Int32 => compute_mod::<Int32Type>(&args[0], &args[1], true),
+        DataType::Int64 => compute_mod::<Int64Type>(&args[0], &args[1], true),
+        DataType::UInt8 => compute_mod::<UInt8Type>(&args[0], &args[1], true),
+        DataType::UInt16 => compute_mod::<UInt16Type>(&args[0], &args[1], true),
+        DataType::UInt32 => compute_mod::<UInt32Type>(&args[0], &args[1], true),
+        DataType::UInt64 => compute_mod::<UInt64Type>(&args[0], &args[1], true),
+        DataType::Float32 => compute_mod::<Float32Type>(&args[0], &args[1], false),
+        DataType::Float64 => compute_mod::<Float64Type>(&args[0], &args[1], false),
+        other => exec_err!("Unsupported data type {other:?} for function mod"),
+    }
+}
+
+/// Computes `x % y` for each pair of non-null values, erroring on a zero `y` if
+/// `error_on_zero` is set. `MIN % -1` of a signed integer wraps to 0.
+fn compute_mod<T: ArrowPrimitiveType>(
+    x: &ArrayRef,
+    y: &ArrayRef,
+    error_on_zero: bool,
+) -> Result<ArrayRef> {
+    let result = x
+        .as_primitive::<T>()
+        .iter()
+        .zip(y.as_primitive::<T>().iter())
+        .map(|(x, y)| match (x, y) {
+            (Some(x), Some(y)) if error_on_zero && y.is_zero() => {
+                exec_err!("Division by zero in mod({x:?}, {y:?})")
+            }
+            (Some(x), Some(y)) => Ok(Some(x.mod_wrapping(y))),
+            _ => Ok(None),
+        })
+        .collect::<Result<PrimitiveArray<T>>>()?;
+    Ok(Arc::new(result) as ArrayRef)
+}
+
 /// Nanvl SQL function
 pub fn nanvl(args: &[ArrayRef]) -> Result<ArrayRef> {
     match args[0].data_type() {
@@ -308,6 +483,42 @@ pub fn nanvl(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// coalesce_nan SQL function
+///
+/// Returns the value of the first argument that is neither NULL nor NaN, or NULL if
+/// there is none. All the arguments have the same type, Float32 or Float64.
+pub fn coalesce_nan(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Float64 => coalesce_nan_impl::<Float64Type>(args, |x: f64| x.is_nan()),
+        DataType::Float32 => coalesce_nan_impl::<Float32Type>(args, |x: f32| x.is_nan()),
+        other => exec_err!("Unsupported data type {other:?} for function coalesce_nan"),
+    }
+}
+
+fn coalesce_nan_impl<T: ArrowPrimitiveType>(
+    args: &[ArrayRef],
+    is_nan: impl Fn(T::Native) -> bool,
+) -> Result<ArrayRef> {
+    let arrays = args
+        .iter()
+        .map(|arg| arg.as_primitive_opt::<T>())
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| {
+            exec_datafusion_err!("coalesce_nan requires arguments of the same type")
+        })?;
+
+    let result = (0..arrays[0].len())
+        .map(|i| {
+            arrays
+                .iter()
+                .find(|array| array.is_valid(i) && !is_nan(array.value(i)))
+                .map(|array| array.value(i))
+        })
+        .collect::<PrimitiveArray<T>>();
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
 /// Isnan SQL function
 pub fn isnan(args: &[ArrayRef]) -> Result<ArrayRef> {
     match args[0].data_type() {
@@ -364,14 +575,37 @@ pub fn pi(args: &[ColumnarValue]) -> Result<ColumnarValue> {
 }
 
 /// Random SQL function
+///
+/// `random(seed)` generates the values of a batch from a generator seeded with
+/// the seed of its first row, so that the same seed and batch size always
+/// produce the same values. A `NULL` seed is the same as no seed.
 pub fn random(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    let len: usize = match &args[0] {
-        ColumnarValue::Array(array) => array.len(),
-        _ => return exec_err!("Expect random function to take no param"),
+    let array = match &args[0] {
+        ColumnarValue::Array(array) => array,
+        _ => return exec_err!("Expect random function to take no param or a seed"),
+    };
+    let len = array.len();
+    let seed = match array.data_type() {
+        DataType::Int64 => {
+            let seeds = array.as_primitive::<Int64Type>();
+            (!seeds.is_empty() && seeds.is_valid(0)).then(|| seeds.value(0))
+        }
+        _ => None,
+    };
+    let array = match seed {
+        Some(seed) => {
+            let mut rng = StdRng::seed_from_u64(seed as u64);
+            Float64Array::from_iter_values(
+                iter::repeat_with(|| rng.gen_range(0.0..1.0)).take(len),
+            )
+        }
+        None => {
+            let mut rng = thread_rng();
+            Float64Array::from_iter_values(
+                iter::repeat_with(|| rng.gen_range(0.0..1.0)).take(len),
+            )
+        }
     };
-    let mut rng = thread_rng();
-    let values = iter::repeat_with(|| rng.gen_range(0.0..1.0)).take(len);
-    let array = Float64Array::from_iter_values(values);
     Ok(ColumnarValue::Array(Arc::new(array)))
 }
 
@@ -387,25 +621,30 @@ pub fn round(args: &[ArrayRef]) -> Result<ArrayRef> {
     let mut decimal_places = ColumnarValue::Scalar(ScalarValue::Int64(Some(0)));
 
     if args.len() == 2 {
-        decimal_places = ColumnarValue::Array(args[1].clone());
+        // the decimal places may also be passed as Int32
+        let decimal_places_array = cast(&args[1], &DataType::Int64)?;
+        decimal_places = if decimal_places_array.len() == 1 {
+            // constant decimal places, passed as a single value (see `Hint::AcceptsSingular`)
+            ColumnarValue::Scalar(ScalarValue::try_from_array(&decimal_places_array, 0)?)
+        } else {
+            ColumnarValue::Array(decimal_places_array)
+        };
+    }
+
+    if let ColumnarValue::Scalar(ScalarValue::Int64(None)) = decimal_places {
+        return Ok(new_null_array(args[0].data_type(), args[0].len()));
     }
 
     match args[0].data_type() {
         DataType::Float64 => match decimal_places {
             ColumnarValue::Scalar(ScalarValue::Int64(Some(decimal_places))) => {
-                let decimal_places = decimal_places.try_into().unwrap();
-
-                Ok(Arc::new(make_function_scalar_inputs!(
-                    &args[0],
-                    "value",
-                    Float64Array,
-                    {
-                        |value: f64| {
-                            (value * 10.0_f64.powi(decimal_places)).round()
-                                / 10.0_f64.powi(decimal_places)
-                        }
-                    }
-                )) as ArrayRef)
+                let factor = 10.0_f64.powi(decimal_places_exponent(decimal_places));
+
+                // downcast once and apply the kernel to the whole batch
+                let value = downcast_arg!(&args[0], "value", Float64Array);
+                let result: Float64Array =
+                    value.unary(|value| round_with_factor64(value, factor));
+                Ok(Arc::new(result) as ArrayRef)
             }
             ColumnarValue::Array(decimal_places) => Ok(Arc::new(make_function_inputs2!(
                 &args[0],
@@ -416,9 +655,10 @@ pub fn round(args: &[ArrayRef]) -> Result<ArrayRef> {
                 Int64Array,
                 {
                     |value: f64, decimal_places: i64| {
-                        (value * 10.0_f64.powi(decimal_places.try_into().unwrap()))
-                            .round()
-                            / 10.0_f64.powi(decimal_places.try_into().unwrap())
+                        round_with_factor64(
+                            value,
+                            10.0_f64.powi(decimal_places_exponent(decimal_places)),
+                        )
                     }
                 }
             )) as ArrayRef),
@@ -429,19 +669,13 @@ pub fn round(args: &[ArrayRef]) -> Result<ArrayRef> {
 
         DataType::Float32 => match decimal_places {
             ColumnarValue::Scalar(ScalarValue::Int64(Some(decimal_places))) => {
-                let decimal_places = decimal_places.try_into().unwrap();
-
-                Ok(Arc::new(make_function_scalar_inputs!(
-                    &args[0],
-                    "value",
-                    Float32Array,
-                    {
-                        |value: f32| {
-                            (value * 10.0_f32.powi(decimal_places)).round()
-                                / 10.0_f32.powi(decimal_places)
-                        }
-                    }
-                )) as ArrayRef)
+                let factor = 10.0_f32.powi(decimal_places_exponent(decimal_places));
+
+                // downcast once and apply the kernel to the whole batch
+                let value = downcast_arg!(&args[0], "value", Float32Array);
+                let result: Float32Array =
+                    value.unary(|value| round_with_factor32(value, factor));
+                Ok(Arc::new(result) as ArrayRef)
             }
             ColumnarValue::Array(decimal_places) => Ok(Arc::new(make_function_inputs2!(
                 &args[0],
@@ -452,9 +686,10 @@ pub fn round(args: &[ArrayRef]) -> Result<ArrayRef> {
                 Int64Array,
                 {
                     |value: f32, decimal_places: i64| {
-                        (value * 10.0_f32.powi(decimal_places.try_into().unwrap()))
-                            .round()
-                            / 10.0_f32.powi(decimal_places.try_into().unwrap())
+                        round_with_factor32(
+                            value,
+                            10.0_f32.powi(decimal_places_exponent(decimal_places)),
+                        )
                     }
                 }
             )) as ArrayRef),
@@ -463,11 +698,21 @@ pub fn round(args: &[ArrayRef]) -> Result<ArrayRef> {
             }
         },
 
+        DataType::Decimal128(_, _) => round_decimal128(
+            &args[0],
+            &decimal_places,
+            DecimalRounding::HalfAwayFromZero,
+            "round",
+        ),
+
         other => exec_err!("Unsupported data type {other:?} for function round"),
     }
 }
 
 /// Power SQL function
+///
+/// Float results that overflow are infinite, while Int64 results that overflow are
+/// an error, as is a negative Int64 exponent of a base other than 1 and -1.
 pub fn power(args: &[ArrayRef]) -> Result<ArrayRef> {
     match args[0].data_type() {
         DataType::Float64 => Ok(Arc::new(make_function_inputs2!(
@@ -479,19 +724,55 @@ pub fn power(args: &[ArrayRef]) -> Result<ArrayRef> {
             { f64::powf }
         )) as ArrayRef),
 
-        DataType::Int64 => Ok(Arc::new(make_function_inputs2!(
-            &args[0],
-            &args[1],
-            "base",
-            "exponent",
-            Int64Array,
-            { i64::pow }
-        )) as ArrayRef),
+        DataType::Int64 => {
+            let bases = downcast_arg!(&args[0], "base", Int64Array);
+            let exponents = downcast_arg!(&args[1], "exponent", Int64Array);
+
+            let (len1, len2) = (bases.len(), exponents.len());
+            let len = paired_len(len1, len2, "base", "exponent")?;
+            let result = (0..len)
+                .map(|i| {
+                    let i1 = if len1 == 1 { 0 } else { i };
+                    let i2 = if len2 == 1 { 0 } else { i };
+                    if bases.is_null(i1) || exponents.is_null(i2) {
+                        return Ok(None);
+                    }
+                    compute_power(bases.value(i1), exponents.value(i2))
+                })
+                .collect::<Result<Int64Array>>()?;
+            Ok(Arc::new(result) as ArrayRef)
+        }
 
         other => exec_err!("Unsupported data type {other:?} for function power"),
     }
 }
 
+/// Computes `base` to the power of `exponent`, or errors if the result does not fit
+/// in an Int64. Negative exponents only have an integer result for 1 and -1, and
+/// are an error for other bases.
+fn compute_power(base: i64, exponent: i64) -> Result<Option<i64>> {
+    let result = match u32::try_from(exponent) {
+        Ok(exponent) => base.checked_pow(exponent),
+        // only 0, 1 and -1 stay in range for exponents this large, and only 1 and -1
+        // have an integer result for negative exponents
+        Err(_) => match base {
+            0 if exponent > 0 => Some(0),
+            1 => Some(1),
+            -1 if exponent % 2 == 0 => Some(1),
+            -1 => Some(-1),
+            _ if exponent < 0 => {
+                return exec_err!(
+                    "power({base}, {exponent}) has no Int64 result, cast the base to a float for a fractional result"
+                )
+            }
+            _ => None,
+        },
+    };
+    result.map(Some).ok_or_else(|| {
+        exec_datafusion_err!("power overflow: {base}^{exponent} exceeds Int64 range")
+    })
+}
+
 /// Atan2 SQL function
 pub fn atan2(args: &[ArrayRef]) -> Result<ArrayRef> {
     match args[0].data_type() {
@@ -518,13 +799,17 @@ pub fn atan2(args: &[ArrayRef]) -> Result<ArrayRef> {
 }
 
 /// Log SQL function
+///
+/// `log(base, x)` is the logarithm of `x` in `base`, so `log(2, 8)` is 3, and
+/// `log(x)` is `log(10, x)`. It is an error for a base to be zero, negative or 1,
+/// for which the logarithm is not defined.
 pub fn log(args: &[ArrayRef]) -> Result<ArrayRef> {
-    // Support overloaded log(base, x) and log(x) which defaults to log(10, x)
     // note in f64::log params order is different than in sql. e.g in sql log(base, x) == f64::log(x, base)
     let mut base = ColumnarValue::Scalar(Float32(Some(10.0)));
 
     let mut x = &args[0];
     if args.len() == 2 {
+        validate_log_base(&args[0])?;
         x = &args[1];
         base = ColumnarValue::Array(args[0].clone());
     }
@@ -571,6 +856,54 @@ pub fn log(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// Returns an error if a non-null `base` of `log(base, x)` is not positive or is 1
+fn validate_log_base(base: &ArrayRef) -> Result<()> {
+    let base = cast(base, &DataType::Float64)?;
+    let invalid = base
+        .as_primitive::<Float64Type>()
+        .iter()
+        .flatten()
+        .find(|base| *base <= 0.0 || *base == 1.0);
+    match invalid {
+        Some(base) => exec_err!("log base must be positive and not 1, got {base}"),
+        None => Ok(()),
+    }
+}
+
+/// Gamma SQL function
+///
+/// Follows C's `tgamma`: `gamma(0)` is infinite, with the sign of the zero, and
+/// `gamma` of a negative integer is NaN.
+pub fn gamma(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Float64 => Ok(Arc::new(make_function_scalar_inputs!(
+            &args[0],
+            "x",
+            Float64Array,
+            { libm::tgamma }
+        )) as ArrayRef),
+
+        other => exec_err!("Unsupported data type {other:?} for function gamma"),
+    }
+}
+
+/// Lgamma SQL function, the natural logarithm of the absolute value of the gamma function
+///
+/// Stays finite for inputs far beyond the point where `gamma` overflows. `lgamma`
+/// of zero or of a negative integer is infinite.
+pub fn lgamma(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Float64 => Ok(Arc::new(make_function_scalar_inputs!(
+            &args[0],
+            "x",
+            Float64Array,
+            { libm::lgamma }
+        )) as ArrayRef),
+
+        other => exec_err!("Unsupported data type {other:?} for function lgamma"),
+    }
+}
+
 ///cot SQL function
 pub fn cot(args: &[ArrayRef]) -> Result<ArrayRef> {
     match args[0].data_type() {
@@ -592,14 +925,24 @@ pub fn cot(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+// cot is undefined where tan is zero. Like PostgreSQL, `cot(0)` is positive
+// infinity, whatever the sign of the zero, rather than NaN or `-inf`.
 fn compute_cot32(x: f32) -> f32 {
     let a = f32::tan(x);
-    1.0 / a
+    if a == 0.0 {
+        f32::INFINITY
+    } else {
+        1.0 / a
+    }
 }
 
 fn compute_cot64(x: f64) -> f64 {
     let a = f64::tan(x);
-    1.0 / a
+    if a == 0.0 {
+        f64::INFINITY
+    } else {
+        1.0 / a
+    }
 }
 
 /// Truncate(numeric, decimalPrecision) and trunc(numeric) SQL function
@@ -617,7 +960,8 @@ pub fn trunc(args: &[ArrayRef]) -> Result<ArrayRef> {
     let precision = if args.len() == 1 {
         ColumnarValue::Scalar(Int64(Some(0)))
     } else {
-        ColumnarValue::Array(args[1].clone())
+        // the precision may also be passed as Int32
+        ColumnarValue::Array(cast(&args[1], &DataType::Int64)?)
     };
 
     match args[0].data_type() {
@@ -632,7 +976,7 @@ pub fn trunc(args: &[ArrayRef]) -> Result<ArrayRef> {
                 "y",
                 Float64Array,
                 Int64Array,
-                { compute_truncate64 }
+                { compute_truncate::<f64> }
             )) as ArrayRef),
             _ => exec_err!("trunc function requires a scalar or array for precision"),
         },
@@ -647,30 +991,257 @@ pub fn trunc(args: &[ArrayRef]) -> Result<ArrayRef> {
                 "y",
                 Float32Array,
                 Int64Array,
-                { compute_truncate32 }
+                { compute_truncate::<f32> }
             )) as ArrayRef),
             _ => exec_err!("trunc function requires a scalar or array for precision"),
         },
+        DataType::Decimal128(_, _) => {
+            round_decimal128(num, &precision, DecimalRounding::TowardZero, "trunc")
+        }
         other => exec_err!("Unsupported data type {other:?} for function trunc"),
     }
 }
 
-fn compute_truncate32(x: f32, y: i64) -> f32 {
-    let factor = 10.0_f32.powi(y as i32);
-    (x * factor).round() / factor
+/// Truncates `x` to `y` decimal places, or to a multiple of `10^-y` for a negative
+/// `y`, so `trunc(1234.5678, -2)` is 1200. NaN and infinities are returned
+/// unchanged, and values with no digits left to keep are truncated to zero.
+///
+/// The digits are dropped from the shortest decimal representation of `x`, which
+/// is the value as written, rather than from `x` scaled by a power of ten: `0.29 *
+/// 100.0` is `28.999999999999996`, which would truncate to 0.28, and rounding the
+/// scaled value instead could move it away from zero.
+fn compute_truncate<T: Display + FromStr>(x: T, y: i64) -> T {
+    let repr = x.to_string();
+    let (sign, digits) = match repr.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", repr.as_str()),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if !(whole.bytes().all(|b| b.is_ascii_digit())
+        && fraction.bytes().all(|b| b.is_ascii_digit()))
+    {
+        // NaN or an infinity
+        return x;
+    }
+
+    let keep = usize::try_from(y.unsigned_abs()).unwrap_or(usize::MAX);
+    let truncated = if y >= 0 {
+        format!("{sign}{whole}.{}0", &fraction[..keep.min(fraction.len())])
+    } else if keep < whole.len() {
+        format!("{sign}{}{}", &whole[..whole.len() - keep], "0".repeat(keep))
+    } else {
+        format!("{sign}0")
+    };
+    truncated.parse().unwrap_or(x)
+}
+
+/// Converts a number of decimal places to an exponent for `powi`. Values outside
+/// the `i32` range are clamped, as the power of ten already overflows to infinity
+/// or underflows to zero long before that.
+fn decimal_places_exponent(decimal_places: i64) -> i32 {
+    decimal_places.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// Rounds `value` to the decimal places whose power of ten is `factor`.
+/// NaN and infinities are returned unchanged, even when `factor` itself
+/// overflows to infinity or underflows to zero. A value too large to scale has
+/// no digits to round, and a `factor` of zero rounds every value to zero.
+fn round_with_factor64(value: f64, factor: f64) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+    let scaled = value * factor;
+    if !scaled.is_finite() {
+        value
+    } else if factor == 0.0 {
+        0.0
+    } else {
+        scaled.round() / factor
+    }
+}
+
+/// [`round_with_factor64`] for `f32` values
+fn round_with_factor32(value: f32, factor: f32) -> f32 {
+    if !value.is_finite() {
+        return value;
+    }
+    let scaled = value * factor;
+    if !scaled.is_finite() {
+        value
+    } else if factor == 0.0 {
+        0.0
+    } else {
+        scaled.round() / factor
+    }
 }
 
-fn compute_truncate64(x: f64, y: i64) -> f64 {
-    let factor = 10.0_f64.powi(y as i32);
-    (x * factor).round() / factor
+/// How the digits dropped when rounding a decimal are rounded
+#[derive(Debug, Clone, Copy)]
+enum DecimalRounding {
+    /// `round`: a dropped half rounds away from zero
+    HalfAwayFromZero,
+    /// `trunc`: the dropped digits are discarded
+    TowardZero,
+    /// `floor`: rounds towards negative infinity
+    Floor,
+    /// `ceil`: rounds towards positive infinity
+    Ceil,
+}
+
+impl DecimalRounding {
+    /// Rounds the unscaled `value` of a decimal with `scale` digits after the
+    /// point to `decimal_places` digits after the point, keeping its scale.
+    /// A negative `decimal_places` rounds to tens, hundreds and so on.
+    ///
+    /// Returns `None` if the rounded value overflows an `i128`.
+    fn apply(self, value: i128, scale: i8, decimal_places: i64) -> Option<i128> {
+        let dropped_digits = i64::from(scale) - decimal_places;
+        if dropped_digits <= 0 {
+            return Some(value);
+        }
+        // every `i128` is less than half of 10^39, so all its digits are dropped
+        let Some(factor) = u32::try_from(dropped_digits)
+            .ok()
+            .and_then(|digits| 10_i128.checked_pow(digits))
+        else {
+            return match self {
+                DecimalRounding::Floor if value < 0 => None,
+                DecimalRounding::Ceil if value > 0 => None,
+                _ => Some(0),
+            };
+        };
+        let quotient = value / factor;
+        let remainder = value % factor;
+        let quotient = match self {
+            DecimalRounding::HalfAwayFromZero
+                if remainder.unsigned_abs() * 2 >= factor.unsigned_abs() =>
+            {
+                quotient + remainder.signum()
+            }
+            DecimalRounding::Floor if remainder < 0 => quotient - 1,
+            DecimalRounding::Ceil if remainder > 0 => quotient + 1,
+            _ => quotient,
+        };
+        quotient.checked_mul(factor)
+    }
+}
+
+/// Rounds the values of a `Decimal128` array to `decimal_places` digits after
+/// the decimal point, at the decimal level without converting to floats.
+///
+/// The result keeps the scale of `array` and has one more digit of precision,
+/// up to the maximum of 38, so that a value such as `999` can round up to
+/// `1000`. It is an error for a rounded value to exceed the maximum precision.
+fn round_decimal128(
+    array: &ArrayRef,
+    decimal_places: &ColumnarValue,
+    rounding: DecimalRounding,
+    name: &str,
+) -> Result<ArrayRef> {
+    let DataType::Decimal128(precision, scale) = *array.data_type() else {
+        return exec_err!(
+            "{name} expected a Decimal128 array, got {:?}",
+            array.data_type()
+        );
+    };
+    let result_precision = (precision + 1).min(DECIMAL128_MAX_PRECISION);
+    let values = array.as_primitive::<Decimal128Type>();
+    let round = |value: i128, decimal_places: i64| -> Result<i128> {
+        rounding
+            .apply(value, scale, decimal_places)
+            .filter(|rounded| {
+                Decimal128Type::validate_decimal_precision(*rounded, result_precision)
+                    .is_ok()
+            })
+            .ok_or_else(|| {
+                exec_datafusion_err!(
+                    "{name}({}, {decimal_places}) overflows Decimal128({result_precision}, {scale})",
+                    Decimal128Type::format_decimal(value, precision, scale)
+                )
+            })
+    };
+
+    let result: Decimal128Array = match decimal_places {
+        ColumnarValue::Scalar(ScalarValue::Int64(Some(decimal_places))) => {
+            values.try_unary(|value| round(value, *decimal_places))?
+        }
+        ColumnarValue::Scalar(ScalarValue::Int64(None)) => {
+            return Ok(new_null_array(
+                &DataType::Decimal128(result_precision, scale),
+                array.len(),
+            ));
+        }
+        ColumnarValue::Array(decimal_places) if decimal_places.len() == 1 => {
+            // constant decimal places, passed as a single value (see `Hint::AcceptsSingular`)
+            let decimal_places =
+                ColumnarValue::Scalar(ScalarValue::try_from_array(decimal_places, 0)?);
+            return round_decimal128(array, &decimal_places, rounding, name);
+        }
+        ColumnarValue::Array(decimal_places) => {
+            let decimal_places = decimal_places.as_primitive::<Int64Type>();
+            if values.len() != decimal_places.len() {
+                return internal_err!(
+                    "{name} values and decimal places have different lengths: {} and {}",
+                    values.len(),
+                    decimal_places.len()
+                );
+            }
+            values
+                .iter()
+                .zip(decimal_places.iter())
+                .map(|(value, decimal_places)| match (value, decimal_places) {
+                    (Some(value), Some(decimal_places)) => {
+                        round(value, decimal_places).map(Some)
+                    }
+                    _ => Ok(None),
+                })
+                .collect::<Result<_>>()?
+        }
+        _ => {
+            return exec_err!(
+                "{name} function requires a scalar or array for the decimal places"
+            )
+        }
+    };
+    Ok(Arc::new(
+        result.with_precision_and_scale(result_precision, scale)?,
+    ))
+}
+
+/// Rounds a `Decimal128` argument of `floor` or `ceil` to an integral value
+fn round_decimal128_to_integer(
+    arg: &ColumnarValue,
+    rounding: DecimalRounding,
+    name: &str,
+) -> Result<ColumnarValue> {
+    let decimal_places = ColumnarValue::Scalar(ScalarValue::Int64(Some(0)));
+    match arg {
+        ColumnarValue::Array(array) => Ok(ColumnarValue::Array(round_decimal128(
+            array,
+            &decimal_places,
+            rounding,
+            name,
+        )?)),
+        ColumnarValue::Scalar(scalar) => {
+            let array =
+                round_decimal128(&scalar.to_array()?, &decimal_places, rounding, name)?;
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &array, 0,
+            )?))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use arrow::array::{Float64Array, NullArray};
+    use arrow::array::{
+        Float64Array, Int16Array, Int32Array, Int8Array, NullArray, UInt16Array,
+        UInt32Array, UInt64Array, UInt8Array,
+    };
 
     use datafusion_common::cast::{
-        as_boolean_array, as_float32_array, as_float64_array, as_int64_array,
+        as_boolean_array, as_float32_array, as_float64_array, as_int32_array,
+        as_int64_array,
     };
 
     use super::*;
@@ -689,6 +1260,33 @@ mod tests {
         assert!(0.0 <= floats.value(0) && floats.value(0) < 1.0);
     }
 
+    #[test]
+    fn test_random_expression_with_seed() {
+        let seeded = |seed: Option<i64>| {
+            let seeds: ArrayRef = Arc::new(Int64Array::from(vec![seed; 5]));
+            let array = random(&[ColumnarValue::Array(seeds)])
+                .expect("failed to initialize function random")
+                .into_array(5)
+                .expect("Failed to convert to array");
+            as_float64_array(&array).unwrap().clone()
+        };
+
+        let first = seeded(Some(42));
+        let second = seeded(Some(42));
+        assert_eq!(first.len(), 5);
+        assert_eq!(first, second);
+        assert!(first.values().iter().all(|v| (0.0..1.0).contains(v)));
+        // the values of a batch are a sequence, not one value repeated
+        assert_ne!(first.value(0), first.value(1));
+
+        assert_ne!(seeded(Some(43)), first);
+
+        // a null seed generates unseeded values
+        let unseeded = seeded(None);
+        assert_eq!(unseeded.len(), 5);
+        assert_eq!(unseeded.null_count(), 0);
+    }
+
     #[test]
     fn test_power_f64() {
         let args: Vec<ArrayRef> = vec![
@@ -707,6 +1305,27 @@ mod tests {
         assert_eq!(floats.value(3), 625.0);
     }
 
+    #[test]
+    fn test_power_length_mismatch() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![2.0, 2.0, 3.0])), // base
+            Arc::new(Float64Array::from(vec![3.0, 2.0])),      // exponent
+        ];
+        let err = power(&args).unwrap_err();
+        assert!(err.strip_backtrace().starts_with(
+            "Internal error: base and exponent have different lengths: 3 and 2"
+        ));
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![2, 2, 3])), // base
+            Arc::new(Int64Array::from(vec![3, 2])),    // exponent
+        ];
+        let err = power(&args).unwrap_err();
+        assert!(err.strip_backtrace().starts_with(
+            "Internal error: base and exponent have different lengths: 3 and 2"
+        ));
+    }
+
     #[test]
     fn test_power_i64() {
         let args: Vec<ArrayRef> = vec![
@@ -725,6 +1344,113 @@ mod tests {
         assert_eq!(floats.value(3), 625);
     }
 
+    #[test]
+    fn test_power_overflow() {
+        // float overflow is infinite
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![10.0, -10.0, 10.0])), // base
+            Arc::new(Float64Array::from(vec![400.0, 401.0, -400.0])), // exponent
+        ];
+        let result = power(&args).unwrap();
+        let floats = as_float64_array(&result).unwrap();
+        assert_eq!(floats.value(0), f64::INFINITY);
+        assert_eq!(floats.value(1), f64::NEG_INFINITY);
+        assert_eq!(floats.value(2), 0.0);
+
+        // Int64 overflow is an error
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![2, 2])),   // base
+            Arc::new(Int64Array::from(vec![62, 63])), // exponent
+        ];
+        let err = power(&args).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("power overflow: 2^63 exceeds Int64 range"));
+
+        // large exponents only fit for 0, 1 and -1, as do negative ones for 1 and -1
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![2, 0, 1, -1, -1, 1, -1, -1])), // base
+            Arc::new(Int64Array::from(vec![
+                62,
+                5_000_000_000,
+                5_000_000_000,
+                5_000_000_000,
+                5_000_000_001,
+                -3,
+                -2,
+                -3,
+            ])), // exponent
+        ];
+        let result = power(&args).unwrap();
+        let expected = Int64Array::from(vec![1 << 62, 0, 1, 1, -1, 1, 1, -1]);
+        assert_eq!(as_int64_array(&result).unwrap(), &expected);
+
+        // other negative exponents have no integer result
+        for base in [2, 0] {
+            let args: Vec<ArrayRef> = vec![
+                Arc::new(Int64Array::from(vec![base])), // base
+                Arc::new(Int64Array::from(vec![-1])),   // exponent
+            ];
+            let err = power(&args).unwrap_err();
+            assert_eq!(
+                err.strip_backtrace(),
+                format!("Execution error: power({base}, -1) has no Int64 result, cast the base to a float for a fractional result")
+            );
+        }
+    }
+
+    #[test]
+    fn test_exp_overflow() {
+        let args = [ColumnarValue::Array(Arc::new(Float64Array::from(vec![
+            1000.0, -1000.0,
+        ])))];
+        let result = exp(&args).unwrap().into_array(2).unwrap();
+        let floats = as_float64_array(&result).unwrap();
+        assert_eq!(floats.value(0), f64::INFINITY);
+        assert_eq!(floats.value(1), 0.0);
+    }
+
+    #[test]
+    fn test_binary_math_single_value_argument() {
+        // a constant argument is passed as a single value (see `Hint::AcceptsSingular`)
+        let values =
+            Arc::new(Float64Array::from(vec![Some(2.0), None, Some(100.0)])) as ArrayRef;
+        let single =
+            |value: Option<f64>| Arc::new(Float64Array::from(vec![value; 1])) as ArrayRef;
+
+        let result = power(&[values.clone(), single(Some(2.0))]).unwrap();
+        let expected = Float64Array::from(vec![Some(4.0), None, Some(10000.0)]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        let result = atan2(&[values.clone(), single(Some(1.0))]).unwrap();
+        let expected = Float64Array::from(vec![
+            Some(2.0_f64.atan2(1.0)),
+            None,
+            Some(100.0_f64.atan2(1.0)),
+        ]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        let result = log(&[single(Some(10.0)), values.clone()]).unwrap();
+        let expected = Float64Array::from(vec![
+            Some(2.0_f64.log(10.0)),
+            None,
+            Some(100.0_f64.log(10.0)),
+        ]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        // a null constant makes every value null
+        let result = power(&[values.clone(), single(None)]).unwrap();
+        assert_eq!(result.null_count(), 3);
+
+        let result = power(&[
+            Arc::new(Int64Array::from(vec![2, 3])),
+            Arc::new(Int64Array::from(vec![3])),
+        ])
+        .unwrap();
+        let expected = Int64Array::from(vec![8, 27]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+    }
+
     #[test]
     fn test_atan2_f64() {
         let args: Vec<ArrayRef> = vec![
@@ -779,6 +1505,53 @@ mod tests {
         assert_eq!(floats.value(3), 4.0);
     }
 
+    #[test]
+    fn test_log_base_first() {
+        // log(2, 8) and log(10, 100)
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![2.0, 10.0])), // base
+            Arc::new(Float64Array::from(vec![8.0, 100.0])), // x
+        ];
+        let result = log(&args).expect("failed to initialize function log");
+        let floats =
+            as_float64_array(&result).expect("failed to initialize function log");
+        assert_eq!(floats, &Float64Array::from(vec![3.0, 2.0]));
+
+        // a null base is not validated
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![None, Some(2.0)])),
+            Arc::new(Float64Array::from(vec![8.0, 8.0])),
+        ];
+        let result = log(&args).expect("failed to initialize function log");
+        assert_eq!(
+            as_float64_array(&result).unwrap(),
+            &Float64Array::from(vec![None, Some(3.0)])
+        );
+    }
+
+    #[test]
+    fn test_log_invalid_base() {
+        for base in [0.0, -2.0, 1.0] {
+            let args: Vec<ArrayRef> = vec![
+                Arc::new(Float64Array::from(vec![2.0, base])),
+                Arc::new(Float64Array::from(vec![8.0, 8.0])),
+            ];
+            let err = log(&args).unwrap_err();
+            assert_eq!(
+                err.strip_backtrace(),
+                format!(
+                    "Execution error: log base must be positive and not 1, got {base}"
+                )
+            );
+
+            let args: Vec<ArrayRef> = vec![
+                Arc::new(Float32Array::from(vec![base as f32])),
+                Arc::new(Float32Array::from(vec![8.0, 8.0])),
+            ];
+            assert!(log(&args).is_err(), "log({base}, x)");
+        }
+    }
+
     #[test]
     fn test_log_f32() {
         let args: Vec<ArrayRef> = vec![
@@ -833,6 +1606,39 @@ mod tests {
         assert_eq!(floats, &expected);
     }
 
+    #[test]
+    fn test_round_int32_decimal_places() {
+        let decimal_places: ArrayRef = Arc::new(Int32Array::from(vec![0, 2, -1]));
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float32Array::from(vec![125.2345; 3])),
+            decimal_places.clone(),
+        ];
+        let result = round(&args).expect("failed to initialize function round");
+        let floats =
+            as_float32_array(&result).expect("failed to initialize function round");
+        assert_eq!(floats, &Float32Array::from(vec![125.0, 125.23, 130.0]));
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![125.2345; 3])),
+            decimal_places,
+        ];
+        let result = round(&args).expect("failed to initialize function round");
+        let floats =
+            as_float64_array(&result).expect("failed to initialize function round");
+        assert_eq!(floats, &Float64Array::from(vec![125.0, 125.23, 130.0]));
+
+        // a single (constant) Int32 value
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![125.2345; 3])),
+            Arc::new(Int32Array::from(vec![1])),
+        ];
+        let result = round(&args).expect("failed to initialize function round");
+        let floats =
+            as_float64_array(&result).expect("failed to initialize function round");
+        assert_eq!(floats, &Float64Array::from(vec![125.2; 3]));
+    }
+
     #[test]
     fn test_round_f32_one_input() {
         let args: Vec<ArrayRef> = vec![
@@ -863,6 +1669,74 @@ mod tests {
         assert_eq!(floats, &expected);
     }
 
+    #[test]
+    fn test_round_f64_singular_decimal_places() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![
+                Some(125.2345),
+                None,
+                Some(1.235),
+                Some(-0.126),
+            ])), // input
+            Arc::new(Int64Array::from(vec![2])), // decimal_places
+        ];
+
+        let result = round(&args).expect("failed to initialize function round");
+        let floats =
+            as_float64_array(&result).expect("failed to initialize function round");
+
+        let expected =
+            Float64Array::from(vec![Some(125.23), None, Some(1.24), Some(-0.13)]);
+
+        assert_eq!(floats, &expected);
+
+        // null decimal places
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![125.2345, 1.235])), // input
+            Arc::new(Int64Array::from(vec![None])),              // decimal_places
+        ];
+
+        let result = round(&args).expect("failed to initialize function round");
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.null_count(), 2);
+    }
+
+    #[test]
+    fn test_round_out_of_range_decimal_places() {
+        // decimal places beyond the i32 range keep every digit, or drop all of them
+        for (places, expected) in [
+            (i64::MAX, vec![125.2345, -0.126]),
+            (i32::MAX as i64 + 1, vec![125.2345, -0.126]),
+            (400, vec![125.2345, -0.126]),
+            (i64::MIN, vec![0.0, 0.0]),
+            (-400, vec![0.0, 0.0]),
+        ] {
+            let values: ArrayRef = Arc::new(Float64Array::from(vec![125.2345, -0.126]));
+
+            // as a single value
+            let result =
+                round(&[values.clone(), Arc::new(Int64Array::from(vec![places]))])
+                    .expect("failed to initialize function round");
+            let floats = as_float64_array(&result).unwrap();
+            assert_eq!(floats, &Float64Array::from(expected.clone()), "{places}");
+
+            // per row
+            let result = round(&[values, Arc::new(Int64Array::from(vec![places; 2]))])
+                .expect("failed to initialize function round");
+            let floats = as_float64_array(&result).unwrap();
+            assert_eq!(floats, &Float64Array::from(expected), "{places}");
+
+            let result = round(&[
+                Arc::new(Float32Array::from(vec![125.25_f32])),
+                Arc::new(Int64Array::from(vec![places])),
+            ])
+            .expect("failed to initialize function round");
+            let floats = as_float32_array(&result).unwrap();
+            let expected = if places > 0 { 125.25 } else { 0.0 };
+            assert_eq!(floats.value(0), expected, "{places}");
+        }
+    }
+
     #[test]
     fn test_factorial_i64() {
         let args: Vec<ArrayRef> = vec![
@@ -878,6 +1752,164 @@ mod tests {
         assert_eq!(ints, &expected);
     }
 
+    #[test]
+    fn test_factorial_overflow() {
+        let args: Vec<ArrayRef> =
+            vec![Arc::new(Int64Array::from(vec![Some(20), None, Some(-3)]))];
+        let result = factorial(&args).expect("failed to initialize function factorial");
+        let ints =
+            as_int64_array(&result).expect("failed to initialize function factorial");
+        assert_eq!(
+            ints,
+            &Int64Array::from(vec![Some(2_432_902_008_176_640_000), None, Some(1)])
+        );
+
+        for value in [21, i64::MAX] {
+            let args: Vec<ArrayRef> =
+                vec![Arc::new(Int64Array::from(vec![Some(20), Some(value)]))];
+            let err = factorial(&args).unwrap_err();
+            assert_eq!(
+                err.strip_backtrace(),
+                format!(
+                    "Execution error: factorial overflow: {value}! exceeds Int64 range"
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_signum() {
+        let args = [ColumnarValue::Array(Arc::new(Float64Array::from(vec![
+            Some(f64::INFINITY),
+            Some(f64::NEG_INFINITY),
+            Some(f64::NAN),
+            Some(0.0),
+            Some(-0.0),
+            Some(-2.5),
+            None,
+        ])))];
+        let ColumnarValue::Array(result) = signum(&args).expect("failed to run signum")
+        else {
+            unreachable!()
+        };
+        let floats = as_float64_array(&result).expect("failed to run signum");
+        assert_eq!(floats.value(0), 1.0);
+        assert_eq!(floats.value(1), -1.0);
+        assert!(floats.value(2).is_nan());
+        assert!(floats.value(3) == 0.0 && floats.value(3).is_sign_positive());
+        assert!(floats.value(4) == 0.0 && floats.value(4).is_sign_positive());
+        assert_eq!(floats.value(5), -1.0);
+        assert!(floats.is_null(6));
+
+        for (value, expected) in [(f32::NEG_INFINITY, -1.0), (-0.0, 0.0), (3.0, 1.0)] {
+            let args = [ColumnarValue::Scalar(ScalarValue::Float32(Some(value)))];
+            let result = signum(&args).expect("failed to run signum");
+            assert!(matches!(
+                result,
+                ColumnarValue::Scalar(ScalarValue::Float32(Some(v))) if v == expected
+            ));
+        }
+        let args = [ColumnarValue::Scalar(ScalarValue::Float32(Some(f32::NAN)))];
+        assert!(matches!(
+            signum(&args).expect("failed to run signum"),
+            ColumnarValue::Scalar(ScalarValue::Float32(Some(v))) if v.is_nan()
+        ));
+    }
+
+    #[test]
+    fn test_bit_count() {
+        let args: Vec<ArrayRef> = vec![Arc::new(Int64Array::from(vec![
+            Some(0),
+            Some(7),
+            None,
+            Some(-1),
+            Some(i64::MIN),
+        ]))];
+        let result = bit_count(&args).expect("failed to initialize function bit_count");
+        let ints =
+            as_int32_array(&result).expect("failed to initialize function bit_count");
+        assert_eq!(
+            ints,
+            &Int32Array::from(vec![Some(0), Some(3), None, Some(64), Some(1)])
+        );
+
+        let cases: Vec<(ArrayRef, i32)> = vec![
+            (Arc::new(Int8Array::from(vec![-1])), 8),
+            (Arc::new(Int16Array::from(vec![-1])), 16),
+            (Arc::new(Int32Array::from(vec![-1])), 32),
+            (Arc::new(UInt8Array::from(vec![u8::MAX])), 8),
+            (Arc::new(UInt16Array::from(vec![u16::MAX])), 16),
+            (Arc::new(UInt32Array::from(vec![u32::MAX])), 32),
+            (Arc::new(UInt64Array::from(vec![u64::MAX])), 64),
+        ];
+        for (array, expected) in cases {
+            let result = bit_count(&[array]).expect("failed to run bit_count");
+            let ints = as_int32_array(&result).expect("failed to run bit_count");
+            assert_eq!(ints, &Int32Array::from(vec![expected]));
+        }
+    }
+
+    #[test]
+    fn test_mod() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![
+                Some(-7),
+                Some(7),
+                Some(7),
+                None,
+                Some(i64::MIN),
+            ])),
+            Arc::new(Int64Array::from(vec![
+                Some(3),
+                Some(-3),
+                Some(3),
+                Some(2),
+                Some(-1),
+            ])),
+        ];
+        let result = modulo(&args).expect("failed to initialize function mod");
+        let ints = as_int64_array(&result).expect("failed to initialize function mod");
+        assert_eq!(
+            ints,
+            &Int64Array::from(vec![Some(-1), Some(1), Some(1), None, Some(0)])
+        );
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(UInt8Array::from(vec![250, 7])),
+            Arc::new(UInt8Array::from(vec![7, 250])),
+        ];
+        let result = modulo(&args).expect("failed to initialize function mod");
+        assert_eq!(
+            result.as_primitive::<UInt8Type>(),
+            &UInt8Array::from(vec![5, 7])
+        );
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![-7.5, 7.5, 1.0, f64::INFINITY])),
+            Arc::new(Float64Array::from(vec![2.0, 0.0, -0.0, 2.0])),
+        ];
+        let result = modulo(&args).expect("failed to initialize function mod");
+        let floats =
+            as_float64_array(&result).expect("failed to initialize function mod");
+        assert_eq!(floats.value(0), -1.5);
+        assert!(floats.value(1).is_nan());
+        assert!(floats.value(2).is_nan());
+        assert!(floats.value(3).is_nan());
+    }
+
+    #[test]
+    fn test_mod_integer_by_zero() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![Some(5), None, Some(6)])),
+            Arc::new(Int32Array::from(vec![Some(2), Some(0), Some(0)])),
+        ];
+        let err = modulo(&args).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: Division by zero in mod(6, 0)"
+        );
+    }
+
     #[test]
     fn test_gcd_i64() {
         let args: Vec<ArrayRef> = vec![
@@ -977,9 +2009,9 @@ mod tests {
 
         assert_eq!(floats.len(), 5);
         assert_eq!(floats.value(0), 15.0);
-        assert_eq!(floats.value(1), 1_234.268);
+        assert_eq!(floats.value(1), 1_234.267);
         assert_eq!(floats.value(2), 1_233.12);
-        assert_eq!(floats.value(3), 3.312_98);
+        assert_eq!(floats.value(3), 3.312_97);
         assert_eq!(floats.value(4), -21.123_4);
     }
 
@@ -1002,12 +2034,122 @@ mod tests {
 
         assert_eq!(floats.len(), 5);
         assert_eq!(floats.value(0), 5.0);
-        assert_eq!(floats.value(1), 234.268);
+        assert_eq!(floats.value(1), 234.267);
         assert_eq!(floats.value(2), 123.12);
-        assert_eq!(floats.value(3), 123.312_98);
+        assert_eq!(floats.value(3), 123.312_97);
         assert_eq!(floats.value(4), -321.123_1);
     }
 
+    #[test]
+    fn test_truncate_inexact_values() {
+        // values whose scaled floats fall just below the digits kept
+        let values = vec![0.29, 0.57, 1.15, 4.35, 8.2];
+        let precision: ArrayRef = Arc::new(Int64Array::from(vec![2, 2, 2, 2, 1]));
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(values.clone())),
+            precision.clone(),
+        ];
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let floats =
+            as_float64_array(&result).expect("failed to initialize function truncate");
+        assert_eq!(floats, &Float64Array::from(values));
+
+        let values = vec![0.29_f32, 0.57, 1.15, 4.35, 8.2];
+        let args: Vec<ArrayRef> =
+            vec![Arc::new(Float32Array::from(values.clone())), precision];
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let floats =
+            as_float32_array(&result).expect("failed to initialize function truncate");
+        assert_eq!(floats, &Float32Array::from(values));
+    }
+
+    #[test]
+    fn test_truncate_never_rounds_up() {
+        // the largest floats below 2 and 1, which must not be rounded up
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![
+                1.999_999_999_999_999_8,
+                0.999_999_999_999_99,
+            ])),
+            Arc::new(Int64Array::from(vec![0, 2])),
+        ];
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let floats =
+            as_float64_array(&result).expect("failed to initialize function truncate");
+        assert_eq!(floats, &Float64Array::from(vec![1.0, 0.99]));
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float32Array::from(vec![1.999_999_9_f32, -1.999_999_9])),
+            Arc::new(Int64Array::from(vec![0, 6])),
+        ];
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let floats =
+            as_float32_array(&result).expect("failed to initialize function truncate");
+        assert_eq!(floats, &Float32Array::from(vec![1.0, -1.999_999]));
+    }
+
+    #[test]
+    fn test_truncate_int32_precision() {
+        let precision: ArrayRef = Arc::new(Int32Array::from(vec![0, 2, 3]));
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float32Array::from(vec![15.0, 1_233.123_4, -21.123_4])),
+            precision.clone(),
+        ];
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let floats =
+            as_float32_array(&result).expect("failed to initialize function truncate");
+        assert_eq!(floats, &Float32Array::from(vec![15.0, 1_233.12, -21.123]));
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![5.0, 123.123_456_789, -321.123_1])),
+            precision,
+        ];
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let floats =
+            as_float64_array(&result).expect("failed to initialize function truncate");
+        assert_eq!(floats, &Float64Array::from(vec![5.0, 123.12, -321.123]));
+    }
+
+    #[test]
+    fn test_truncate_negative_and_null_precision() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![1_234.567_8; 6])),
+            Arc::new(Int64Array::from(vec![
+                Some(2),
+                Some(0),
+                Some(-2),
+                Some(-4),
+                Some(-400),
+                None,
+            ])),
+        ];
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let floats =
+            as_float64_array(&result).expect("failed to initialize function truncate");
+        assert_eq!(
+            floats,
+            &Float64Array::from(vec![
+                Some(1_234.56),
+                Some(1_234.0),
+                Some(1_200.0),
+                Some(0.0),
+                Some(0.0),
+                None
+            ])
+        );
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float32Array::from(vec![-1_234.567_8, 1e30, 0.0])),
+            Arc::new(Int64Array::from(vec![-2, 400, 400])),
+        ];
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let floats =
+            as_float32_array(&result).expect("failed to initialize function truncate");
+        assert_eq!(floats, &Float32Array::from(vec![-1_200.0, 1e30, 0.0]));
+    }
+
     #[test]
     fn test_truncate_64_one_arg() {
         let args: Vec<ArrayRef> = vec![Arc::new(Float64Array::from(vec![
@@ -1030,6 +2172,351 @@ mod tests {
         assert_eq!(floats.value(4), -321.0);
     }
 
+    #[test]
+    fn test_truncate_32_one_arg() {
+        let args: Vec<ArrayRef> = vec![Arc::new(Float32Array::from(vec![
+            Some(5.0),
+            Some(234.267_8),
+            None,
+            Some(-321.123),
+        ]))];
+
+        let result = trunc(&args).expect("failed to initialize function truncate");
+        let floats =
+            as_float32_array(&result).expect("failed to initialize function truncate");
+
+        let expected =
+            Float32Array::from(vec![Some(5.0), Some(234.0), None, Some(-321.0)]);
+        assert_eq!(floats, &expected);
+    }
+
+    #[test]
+    fn test_round_trunc_non_finite() {
+        let values = vec![f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+        // large precisions overflow the factor to infinity, and negative ones underflow it to zero
+        let places = [0, 2, -1, 400, -400];
+
+        for places in places {
+            let args: Vec<ArrayRef> = vec![
+                Arc::new(Float64Array::from(values.clone())),
+                Arc::new(Int64Array::from(vec![places])),
+            ];
+            let result = round(&args).expect("failed to initialize function round");
+            let floats = as_float64_array(&result).unwrap();
+            assert!(floats.value(0).is_nan(), "round(NaN, {places})");
+            assert_eq!(floats.value(1), f64::INFINITY, "round(inf, {places})");
+            assert_eq!(floats.value(2), f64::NEG_INFINITY, "round(-inf, {places})");
+
+            let args: Vec<ArrayRef> = vec![
+                Arc::new(Float32Array::from(vec![
+                    f32::NAN,
+                    f32::INFINITY,
+                    f32::NEG_INFINITY,
+                ])),
+                Arc::new(Int64Array::from(vec![places; 3])),
+            ];
+            let result = trunc(&args).expect("failed to initialize function truncate");
+            let floats = as_float32_array(&result).unwrap();
+            assert!(floats.value(0).is_nan(), "trunc(NaN, {places})");
+            assert_eq!(floats.value(1), f32::INFINITY, "trunc(inf, {places})");
+            assert_eq!(floats.value(2), f32::NEG_INFINITY, "trunc(-inf, {places})");
+        }
+
+        for function in [ceil, floor] {
+            let result = function(&[ColumnarValue::Array(Arc::new(Float64Array::from(
+                values.clone(),
+            )))])
+            .unwrap()
+            .into_array(3)
+            .unwrap();
+            let floats = as_float64_array(&result).unwrap();
+            assert!(floats.value(0).is_nan());
+            assert_eq!(floats.value(1), f64::INFINITY);
+            assert_eq!(floats.value(2), f64::NEG_INFINITY);
+        }
+    }
+
+    fn decimal_array(values: Vec<Option<i128>>, precision: u8, scale: i8) -> ArrayRef {
+        Arc::new(
+            Decimal128Array::from(values)
+                .with_precision_and_scale(precision, scale)
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_round_trunc_decimal128() {
+        // 1.005, 1.015, -1.005, 2.494, 0.000 and NULL as DECIMAL(10, 3)
+        let values = vec![
+            Some(1005),
+            Some(1015),
+            Some(-1005),
+            Some(2494),
+            Some(0),
+            None,
+        ];
+        let cases = [
+            // decimal places, round, trunc
+            (
+                2,
+                vec![
+                    Some(1010),
+                    Some(1020),
+                    Some(-1010),
+                    Some(2490),
+                    Some(0),
+                    None,
+                ],
+                vec![
+                    Some(1000),
+                    Some(1010),
+                    Some(-1000),
+                    Some(2490),
+                    Some(0),
+                    None,
+                ],
+            ),
+            (
+                0,
+                vec![
+                    Some(1000),
+                    Some(1000),
+                    Some(-1000),
+                    Some(2000),
+                    Some(0),
+                    None,
+                ],
+                vec![
+                    Some(1000),
+                    Some(1000),
+                    Some(-1000),
+                    Some(2000),
+                    Some(0),
+                    None,
+                ],
+            ),
+            (
+                // at least as many places as the scale leaves the value unchanged
+                5,
+                values.clone(),
+                values.clone(),
+            ),
+            (
+                -1,
+                vec![Some(0), Some(0), Some(0), Some(0), Some(0), None],
+                vec![Some(0), Some(0), Some(0), Some(0), Some(0), None],
+            ),
+        ];
+
+        for (places, rounded, truncated) in cases {
+            let args = vec![
+                decimal_array(values.clone(), 10, 3),
+                Arc::new(Int64Array::from(vec![places])) as ArrayRef,
+            ];
+            let result = round(&args).unwrap();
+            assert_eq!(&result, &decimal_array(rounded, 11, 3), "round({places})");
+
+            let args = vec![
+                decimal_array(values.clone(), 10, 3),
+                Arc::new(Int64Array::from(vec![places; 6])) as ArrayRef,
+            ];
+            let result = trunc(&args).unwrap();
+            assert_eq!(&result, &decimal_array(truncated, 11, 3), "trunc({places})");
+        }
+
+        // 1234.5678 to tens and -1255.5678 to hundreds
+        let args = vec![
+            decimal_array(vec![Some(12345678), Some(-12555678)], 10, 4),
+            Arc::new(Int64Array::from(vec![-1, -2])) as ArrayRef,
+        ];
+        assert_eq!(
+            &round(&args).unwrap(),
+            &decimal_array(vec![Some(12300000), Some(-13000000)], 11, 4)
+        );
+        assert_eq!(
+            &trunc(&args).unwrap(),
+            &decimal_array(vec![Some(12300000), Some(-12000000)], 11, 4)
+        );
+
+        // far more places than digits rounds everything to zero
+        let args = vec![
+            decimal_array(vec![Some(i128::from(i64::MAX))], 38, 0),
+            Arc::new(Int64Array::from(vec![-50])) as ArrayRef,
+        ];
+        assert_eq!(&round(&args).unwrap(), &decimal_array(vec![Some(0)], 38, 0));
+    }
+
+    #[test]
+    fn test_round_decimal128_negative_scale() {
+        // 12300 and -15500 as DECIMAL(5, -2)
+        let args = vec![
+            decimal_array(vec![Some(123), Some(-155), None], 5, -2),
+            Arc::new(Int64Array::from(vec![-3])) as ArrayRef,
+        ];
+        // 12000 and -16000, rounding the half away from zero
+        assert_eq!(
+            &round(&args).unwrap(),
+            &decimal_array(vec![Some(120), Some(-160), None], 6, -2)
+        );
+        assert_eq!(
+            &trunc(&args).unwrap(),
+            &decimal_array(vec![Some(120), Some(-150), None], 6, -2)
+        );
+
+        // places for some of the rows only are not silently paired with fewer rows
+        let args = vec![
+            decimal_array(vec![Some(123), Some(-155), None], 5, -2),
+            Arc::new(Int64Array::from(vec![-3, -3])) as ArrayRef,
+        ];
+        assert!(trunc(&args).unwrap_err().strip_backtrace().starts_with(
+            "Internal error: trunc values and decimal places have different lengths: 3 and 2"
+        ));
+
+        // places within the scale leave the value unchanged
+        let args = vec![
+            decimal_array(vec![Some(123)], 5, -2),
+            Arc::new(Int64Array::from(vec![0])) as ArrayRef,
+        ];
+        assert_eq!(
+            &round(&args).unwrap(),
+            &decimal_array(vec![Some(123)], 6, -2)
+        );
+    }
+
+    #[test]
+    fn test_round_decimal128_overflow() {
+        // 999.5 as DECIMAL(4, 1) rounds to 1000.0 as DECIMAL(5, 1)
+        let args = vec![
+            decimal_array(vec![Some(9995)], 4, 1),
+            Arc::new(Int64Array::from(vec![0])) as ArrayRef,
+        ];
+        assert_eq!(
+            &round(&args).unwrap(),
+            &decimal_array(vec![Some(10000)], 5, 1)
+        );
+        let result = ceil(&[ColumnarValue::Array(decimal_array(vec![Some(5)], 1, 1))])
+            .unwrap()
+            .into_array(1)
+            .unwrap();
+        assert_eq!(&result, &decimal_array(vec![Some(10)], 2, 1));
+
+        // the largest DECIMAL(38, 1) cannot round up to a further digit
+        let max = 10_i128.pow(38) - 1;
+        let args = vec![
+            decimal_array(vec![Some(max)], 38, 1),
+            Arc::new(Int64Array::from(vec![0])) as ArrayRef,
+        ];
+        let err = round(&args).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            format!(
+                "Execution error: round({}, 0) overflows Decimal128(38, 1)",
+                Decimal128Type::format_decimal(max, 38, 1)
+            )
+        );
+    }
+
+    #[test]
+    fn test_floor_ceil_decimal128() {
+        // 1.5, -1.5, 2.0, -0.1 and NULL as DECIMAL(5, 1)
+        let values = vec![Some(15), Some(-15), Some(20), Some(-1), None];
+        let floored = vec![Some(10), Some(-20), Some(20), Some(-10), None];
+        let ceiled = vec![Some(20), Some(-10), Some(20), Some(0), None];
+
+        let arg = ColumnarValue::Array(decimal_array(values, 5, 1));
+        let result = floor(&[arg.clone()]).unwrap().into_array(5).unwrap();
+        assert_eq!(&result, &decimal_array(floored, 6, 1));
+        let result = ceil(&[arg]).unwrap().into_array(5).unwrap();
+        assert_eq!(&result, &decimal_array(ceiled, 6, 1));
+
+        // scalars stay scalars
+        let arg = ColumnarValue::Scalar(ScalarValue::Decimal128(Some(-15), 5, 1));
+        match floor(&[arg.clone()]).unwrap() {
+            ColumnarValue::Scalar(scalar) => {
+                assert_eq!(scalar, ScalarValue::Decimal128(Some(-20), 6, 1))
+            }
+            other => panic!("expected a scalar, got {other:?}"),
+        }
+        match ceil(&[arg]).unwrap() {
+            ColumnarValue::Scalar(scalar) => {
+                assert_eq!(scalar, ScalarValue::Decimal128(Some(-10), 6, 1))
+            }
+            other => panic!("expected a scalar, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_ceil_floor_f32() {
+        let input = Float32Array::from(vec![Some(1.25), None, Some(-2.75)]);
+
+        let result = ceil(&[ColumnarValue::Array(Arc::new(input.clone()))])
+            .expect("failed to initialize function ceil")
+            .into_array(3)
+            .unwrap();
+        let floats =
+            as_float32_array(&result).expect("failed to initialize function ceil");
+        assert_eq!(
+            floats,
+            &Float32Array::from(vec![Some(2.0), None, Some(-2.0)])
+        );
+
+        let result = floor(&[ColumnarValue::Array(Arc::new(input))])
+            .expect("failed to initialize function floor")
+            .into_array(3)
+            .unwrap();
+        let floats =
+            as_float32_array(&result).expect("failed to initialize function floor");
+        assert_eq!(
+            floats,
+            &Float32Array::from(vec![Some(1.0), None, Some(-3.0)])
+        );
+
+        let result = floor(&[ColumnarValue::Scalar(ScalarValue::Float32(Some(1.25)))])
+            .expect("failed to initialize function floor");
+        assert!(matches!(
+            result,
+            ColumnarValue::Scalar(ScalarValue::Float32(Some(v))) if v == 1.0
+        ));
+    }
+
+    #[test]
+    fn test_gamma_lgamma() {
+        let args: Vec<ArrayRef> = vec![Arc::new(Float64Array::from(vec![
+            Some(5.0),
+            Some(0.5),
+            None,
+            Some(0.0),
+            Some(-0.0),
+            Some(-2.0),
+            Some(1e300),
+        ]))];
+
+        let result = gamma(&args).expect("failed to initialize function gamma");
+        let floats = as_float64_array(&result).unwrap();
+        // gamma(n) = (n - 1)!
+        assert!((floats.value(0) - 24.0).abs() < 1e-9);
+        assert!((floats.value(1) - std::f64::consts::PI.sqrt()).abs() < 1e-12);
+        assert!(floats.is_null(2));
+        assert_eq!(floats.value(3), f64::INFINITY);
+        assert_eq!(floats.value(4), f64::NEG_INFINITY);
+        assert!(floats.value(5).is_nan());
+        assert_eq!(floats.value(6), f64::INFINITY);
+
+        let result = lgamma(&args).expect("failed to initialize function lgamma");
+        let floats = as_float64_array(&result).unwrap();
+        assert!((floats.value(0) - 24.0_f64.ln()).abs() < 1e-12);
+        assert!((floats.value(1) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-12);
+        assert!(floats.is_null(2));
+        assert_eq!(floats.value(3), f64::INFINITY);
+        assert_eq!(floats.value(4), f64::INFINITY);
+        assert_eq!(floats.value(5), f64::INFINITY);
+        // Stirling: ln(gamma(x)) ~ x * (ln(x) - 1)
+        let x = 1e300_f64;
+        let stirling = x * (x.ln() - 1.0);
+        assert!(floats.value(6).is_finite());
+        assert!((floats.value(6) - stirling).abs() / stirling < 1e-6);
+    }
+
     #[test]
     fn test_nanvl_f64() {
         let args: Vec<ArrayRef> = vec![
@@ -1048,6 +2535,47 @@ mod tests {
         assert!(floats.value(3).is_nan());
     }
 
+    #[test]
+    fn test_coalesce_nan() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![
+                None,
+                Some(f64::NAN),
+                Some(1.0),
+                None,
+            ])),
+            Arc::new(Float64Array::from(vec![
+                Some(f64::NAN),
+                None,
+                Some(2.0),
+                None,
+            ])),
+            Arc::new(Float64Array::from(vec![Some(3.0), Some(4.0), None, None])),
+        ];
+
+        let result =
+            coalesce_nan(&args).expect("failed to initialize function coalesce_nan");
+        let floats = as_float64_array(&result)
+            .expect("failed to initialize function coalesce_nan");
+
+        assert_eq!(
+            floats,
+            &Float64Array::from(vec![Some(3.0), Some(4.0), Some(1.0), None])
+        );
+
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float32Array::from(vec![Some(f32::NAN), Some(f32::NAN)])),
+            Arc::new(Float32Array::from(vec![Some(1.5), Some(f32::NAN)])),
+        ];
+
+        let result =
+            coalesce_nan(&args).expect("failed to initialize function coalesce_nan");
+        let floats = as_float32_array(&result)
+            .expect("failed to initialize function coalesce_nan");
+
+        assert_eq!(floats, &Float32Array::from(vec![Some(1.5), None]));
+    }
+
     #[test]
     fn test_nanvl_f32() {
         let args: Vec<ArrayRef> = vec![
@@ -200,11 +200,14 @@ pub fn create_physical_expr(
                 };
             Ok(expressions::case(expr, when_then_expr, else_expr)?)
         }
-        Expr::Cast(Cast { expr, data_type }) => expressions::cast(
-            create_physical_expr(expr, input_dfschema, execution_props)?,
-            input_schema,
-            data_type.clone(),
-        ),
+        Expr::Cast(Cast { expr, data_type }) => {
+            let expr = create_physical_expr(expr, input_dfschema, execution_props)?;
+            // a function with an overload of the type produces it without a cast
+            match functions::with_return_type(&expr, data_type) {
+                Some(expr) => Ok(expr),
+                None => expressions::cast(expr, input_schema, data_type.clone()),
+            }
+        }
         Expr::TryCast(TryCast { expr, data_type }) => expressions::try_cast(
             create_physical_expr(expr, input_dfschema, execution_props)?,
             input_schema,
@@ -328,10 +331,12 @@ pub fn create_physical_expr(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow_array::{ArrayRef, BooleanArray, RecordBatch, StringArray};
+    use arrow_array::{
+        ArrayRef, BooleanArray, Int64Array, LargeStringArray, RecordBatch, StringArray,
+    };
     use arrow_schema::{DataType, Field, Schema};
     use datafusion_common::{DFSchema, Result};
-    use datafusion_expr::{col, left, Literal};
+    use datafusion_expr::{cast, chr, col, left, Literal};
 
     #[test]
     fn test_create_physical_expr_scalar_input_output() -> Result<()> {
@@ -357,4 +362,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_create_physical_expr_chr_in_large_string_context() -> Result<()> {
+        let expr = cast(chr(col("n")), DataType::LargeUtf8);
+
+        let schema = Schema::new(vec![Field::new("n", DataType::Int64, false)]);
+        let df_schema = DFSchema::try_from_qualified_schema("data", &schema)?;
+        let p = create_physical_expr(&expr, &df_schema, &ExecutionProps::new())?;
+        // chr produces LargeUtf8 itself rather than being cast
+        assert!(p.as_any().is::<crate::ScalarFunctionExpr>());
+        assert_eq!(p.data_type(&schema)?, DataType::LargeUtf8);
+
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(Int64Array::from(vec![65, 233]))],
+        )?;
+        let result = p.evaluate(&batch)?.into_array(2)?;
+        assert_eq!(
+            &result,
+            &(Arc::new(LargeStringArray::from(vec!["A", "é"])) as ArrayRef)
+        );
+
+        Ok(())
+    }
 }
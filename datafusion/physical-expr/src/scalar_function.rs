@@ -164,6 +164,16 @@ impl PhysicalExpr for ScalarFunctionExpr {
             {
                 vec![ColumnarValue::create_null_array(batch.num_rows())]
             }
+            // `random` produces a value for every row of the batch, so its seed is
+            // passed as an array of the batch size even when it is a constant
+            (false, Ok(BuiltinScalarFunction::Random)) => self
+                .args
+                .iter()
+                .map(|e| {
+                    let array = e.evaluate(batch)?.into_array(batch.num_rows())?;
+                    Ok(ColumnarValue::Array(array))
+                })
+                .collect::<Result<Vec<_>>>()?,
             _ => self
                 .args
                 .iter()
@@ -23,27 +23,35 @@
 
 use std::sync::Arc;
 use std::{
-    fmt::{Display, Formatter},
+    fmt::{Display, Formatter, Write},
     iter,
+    str::FromStr,
 };
 
 use arrow::{
     array::{
-        Array, ArrayRef, GenericStringArray, Int32Array, Int64Array, OffsetSizeTrait,
-        StringArray,
+        make_array, Array, ArrayRef, BinaryArray, BooleanArray, Datum,
+        GenericStringArray, Int32Array, Int64Array, OffsetSizeTrait, Scalar, StringArray,
+        StringBuilder, UInt32Array,
     },
-    datatypes::{ArrowNativeType, ArrowPrimitiveType, DataType},
+    compute::{cast, take},
+    datatypes::{
+        ArrowNativeType, ArrowPrimitiveType, DataType, Int16Type, Int32Type, Int64Type,
+        RunEndIndexType,
+    },
+    error::ArrowError,
 };
 use uuid::Uuid;
 
 use datafusion_common::utils::datafusion_strsim;
-use datafusion_common::Result;
 use datafusion_common::{
     cast::{
-        as_generic_string_array, as_int64_array, as_primitive_array, as_string_array,
+        as_binary_array, as_generic_string_array, as_int64_array, as_primitive_array,
+        as_run_array, as_string_array,
     },
     exec_err, ScalarValue,
 };
+use datafusion_common::{DataFusionError, Result};
 use datafusion_expr::ColumnarValue;
 
 /// applies a unary expression to `args[0]` that is expected to be downcastable to
@@ -104,6 +112,33 @@ where
                     &[a.as_ref()], op, name
                 )?)))
             }
+            DataType::RunEndEncoded(_, _) => {
+                // apply the function once per run rather than once per row
+                let values = run_end_encoded_values(a.as_ref())?;
+                let values: ArrayRef = match values.data_type() {
+                    DataType::Utf8 => Arc::new(unary_string_function::<i32, i32, _, _>(
+                        &[values.as_ref()],
+                        op,
+                        name,
+                    )?),
+                    DataType::LargeUtf8 => {
+                        Arc::new(unary_string_function::<i64, i64, _, _>(
+                            &[values.as_ref()],
+                            op,
+                            name,
+                        )?)
+                    }
+                    other => {
+                        return exec_err!(
+                            "Unsupported data type {other:?} for function {name}"
+                        )
+                    }
+                };
+                Ok(ColumnarValue::Array(with_run_end_encoded_values(
+                    a.as_ref(),
+                    values,
+                )?))
+            }
             other => exec_err!("Unsupported data type {other:?} for function {name}"),
         },
         ColumnarValue::Scalar(scalar) => match scalar {
@@ -120,27 +155,205 @@ where
     }
 }
 
+/// Returns the values of the run-end encoded `array`, one per run
+fn run_end_encoded_values(array: &dyn Array) -> Result<&ArrayRef> {
+    match array.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+            DataType::Int16 => Ok(as_run_array::<Int16Type>(array)?.values()),
+            DataType::Int32 => Ok(as_run_array::<Int32Type>(array)?.values()),
+            DataType::Int64 => Ok(as_run_array::<Int64Type>(array)?.values()),
+            other => exec_err!("Unsupported run end type {other:?}"),
+        },
+        other => exec_err!("Expected a run-end encoded array, got {other:?}"),
+    }
+}
+
+/// Returns, for each row of the run-end encoded `array`, the index of its value in
+/// [`run_end_encoded_values`]
+fn run_end_encoded_indices(array: &dyn Array) -> Result<UInt32Array> {
+    fn indices<R: RunEndIndexType>(array: &dyn Array) -> Result<UInt32Array> {
+        let run_array = as_run_array::<R>(array)?;
+        Ok(UInt32Array::from_iter_values(
+            (0..run_array.len()).map(|index| run_array.get_physical_index(index) as u32),
+        ))
+    }
+
+    match array.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+            DataType::Int16 => indices::<Int16Type>(array),
+            DataType::Int32 => indices::<Int32Type>(array),
+            DataType::Int64 => indices::<Int64Type>(array),
+            other => exec_err!("Unsupported run end type {other:?}"),
+        },
+        other => exec_err!("Expected a run-end encoded array, got {other:?}"),
+    }
+}
+
+/// Returns the run-end encoded `array` with its values replaced by `values`, which
+/// must have one value per run. The run ends, and any slice of them, are kept.
+fn with_run_end_encoded_values(array: &dyn Array, values: ArrayRef) -> Result<ArrayRef> {
+    let data = array.to_data();
+    let run_ends = data.child_data()[0].clone();
+    let data = data
+        .into_builder()
+        .child_data(vec![run_ends, values.to_data()])
+        .build()?;
+    Ok(make_array(data))
+}
+
+/// A character encoding accepted by the optional encoding argument of [`ascii`],
+/// [`ascii_binary`] and [`chr_with_encoding`]
+#[derive(Debug, Copy, Clone)]
+enum CharacterEncoding {
+    Utf8,
+    Latin1,
+}
+
+impl Display for CharacterEncoding {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Utf8 => write!(f, "utf8"),
+            Self::Latin1 => write!(f, "latin1"),
+        }
+    }
+}
+
+impl FromStr for CharacterEncoding {
+    type Err = DataFusionError;
+    fn from_str(name: &str) -> Result<CharacterEncoding> {
+        Ok(match name.to_ascii_lowercase().as_str() {
+            "utf8" | "utf-8" => Self::Utf8,
+            "latin1" | "latin-1" | "iso-8859-1" | "iso_8859_1" => Self::Latin1,
+            _ => {
+                let options = [Self::Utf8, Self::Latin1]
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return exec_err!(
+                    "There is no character encoding named '{name}', currently supported encodings are: {options}"
+                );
+            }
+        })
+    }
+}
+
+impl CharacterEncoding {
+    /// Returns the code of `c` in this encoding
+    fn code(self, c: char) -> Result<i32> {
+        match self {
+            Self::Utf8 => Ok(c as i32),
+            Self::Latin1 => match u8::try_from(c) {
+                Ok(byte) => Ok(byte as i32),
+                Err(_) => {
+                    exec_err!("character '{c}' has no equivalent in encoding {self}")
+                }
+            },
+        }
+    }
+
+    /// Returns the code of the first character encoded in `bytes`, or 0 if there are none
+    fn first_code(self, bytes: &[u8]) -> Result<i32> {
+        match self {
+            Self::Utf8 => {
+                // a character is at most 4 bytes long in UTF-8
+                let prefix = &bytes[..bytes.len().min(4)];
+                let valid_len = match std::str::from_utf8(prefix) {
+                    Ok(_) => prefix.len(),
+                    Err(e) => e.valid_up_to(),
+                };
+                let first = std::str::from_utf8(&prefix[..valid_len])
+                    .ok()
+                    .and_then(|prefix| prefix.chars().next());
+                match first {
+                    Some(c) => Ok(c as i32),
+                    None if bytes.is_empty() => Ok(0),
+                    None => exec_err!("invalid byte sequence for encoding {self}"),
+                }
+            }
+            Self::Latin1 => Ok(bytes.first().map_or(0, |byte| *byte as i32)),
+        }
+    }
+
+    /// Returns the bytes of the character with the given code in this encoding
+    fn encode(self, code: i64) -> Result<Vec<u8>> {
+        let bytes = match self {
+            Self::Utf8 => u32::try_from(code)
+                .ok()
+                .and_then(char::from_u32)
+                .map(|c| c.to_string().into_bytes()),
+            Self::Latin1 => u8::try_from(code).ok().map(|byte| vec![byte]),
+        };
+        match bytes {
+            Some(bytes) => Ok(bytes),
+            None => {
+                exec_err!("character number {code} is out of range for encoding {self}")
+            }
+        }
+    }
+}
+
 /// Returns the numeric code of the first character of the argument.
+/// An optional second argument names the encoding the code is given in, `utf8`
+/// (the default) or `latin1`. It is an error if the character has no code in
+/// that encoding.
 /// ascii('x') = 120
+/// ascii('é', 'latin1') = 233
 pub fn ascii<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let string_array = as_generic_string_array::<T>(&args[0])?;
 
-    let result = string_array
-        .iter()
-        .map(|string| {
-            string.map(|string: &str| {
-                let mut chars = string.chars();
-                chars.next().map_or(0, |v| v as i32)
+    let result = match args.get(1) {
+        None => string_array
+            .iter()
+            .map(|string| {
+                string.map(|string: &str| {
+                    let mut chars = string.chars();
+                    chars.next().map_or(0, |v| v as i32)
+                })
             })
+            .collect::<Int32Array>(),
+        Some(encodings) => string_array
+            .iter()
+            .zip(as_string_array(encodings)?.iter())
+            .map(|(string, encoding)| match (string, encoding) {
+                (Some(string), Some(encoding)) => {
+                    let encoding = encoding.parse::<CharacterEncoding>()?;
+                    let code = string.chars().next().map(|c| encoding.code(c));
+                    code.unwrap_or(Ok(0)).map(Some)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Int32Array>>()?,
+    };
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Returns the numeric code of the first character of the binary argument,
+/// decoded with the encoding named by the second argument.
+/// ascii(chr(233, 'latin1'), 'latin1') = 233
+pub fn ascii_binary(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let binary_array = as_binary_array(&args[0])?;
+    let encodings = as_string_array(&args[1])?;
+
+    let result = binary_array
+        .iter()
+        .zip(encodings.iter())
+        .map(|(bytes, encoding)| match (bytes, encoding) {
+            (Some(bytes), Some(encoding)) => encoding
+                .parse::<CharacterEncoding>()?
+                .first_code(bytes)
+                .map(Some),
+            _ => Ok(None),
         })
-        .collect::<Int32Array>();
+        .collect::<Result<Int32Array>>()?;
 
     Ok(Arc::new(result) as ArrayRef)
 }
 
 /// Returns the character with the given code. chr(0) is disallowed because text data types cannot store that character.
 /// chr(65) = 'A'
-pub fn chr(args: &[ArrayRef]) -> Result<ArrayRef> {
+pub fn chr<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let integer_array = as_int64_array(&args[0])?;
 
     // first map is the iterator, second is for the `Option<_>`
@@ -162,7 +375,30 @@ pub fn chr(args: &[ArrayRef]) -> Result<ArrayRef> {
                 })
                 .transpose()
         })
-        .collect::<Result<StringArray>>()?;
+        .collect::<Result<GenericStringArray<T>>>()?;
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Returns the bytes of the character with the given code in the encoding named
+/// by the second argument, `utf8` or `latin1`. chr(0) is disallowed as for [`chr`].
+/// chr(233, 'latin1') = X'E9'
+pub fn chr_with_encoding(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let integer_array = as_int64_array(&args[0])?;
+    let encodings = as_string_array(&args[1])?;
+
+    let result = integer_array
+        .iter()
+        .zip(encodings.iter())
+        .map(|(integer, encoding)| match (integer, encoding) {
+            (Some(0), Some(_)) => exec_err!("null character not permitted."),
+            (Some(integer), Some(encoding)) => encoding
+                .parse::<CharacterEncoding>()?
+                .encode(integer)
+                .map(Some),
+            _ => Ok(None),
+        })
+        .collect::<Result<BinaryArray>>()?;
 
     Ok(Arc::new(result) as ArrayRef)
 }
@@ -184,30 +420,60 @@ pub fn concat(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         _ => None,
     });
     if let Some(size) = return_array.next() {
-        let result = (0..size)
-            .map(|index| {
-                let mut owned_string: String = "".to_owned();
-                for arg in args {
-                    match arg {
-                        ColumnarValue::Scalar(ScalarValue::Utf8(maybe_value)) => {
-                            if let Some(value) = maybe_value {
-                                owned_string.push_str(value);
-                            }
+        if args.iter().any(|arg| {
+            matches!(arg, ColumnarValue::Array(array)
+                if matches!(array.data_type(), DataType::RunEndEncoded(_, _)))
+        }) {
+            return concat_run_end_encoded(args);
+        }
+
+        // NULL arguments are ignored, so skip entirely NULL columns and scalars
+        // up front rather than checking each of their rows
+        let args = args
+            .iter()
+            .filter(|arg| match arg {
+                ColumnarValue::Array(array) => array.null_count() < array.len(),
+                ColumnarValue::Scalar(scalar) => !scalar.is_null(),
+            })
+            .collect::<Vec<_>>();
+
+        // reserve the size of all the inputs up front, so that the output buffer is
+        // never reallocated
+        let mut data_size = 0;
+        for arg in &args {
+            match arg {
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some(value))) => {
+                    data_size += value.len() * size;
+                }
+                ColumnarValue::Array(array) => {
+                    data_size += value_data_len(as_string_array(array)?);
+                }
+                _ => {}
+            }
+        }
+
+        let mut builder = StringBuilder::with_capacity(size, data_size);
+        for index in 0..size {
+            for arg in &args {
+                match arg {
+                    ColumnarValue::Scalar(ScalarValue::Utf8(maybe_value)) => {
+                        if let Some(value) = maybe_value {
+                            builder.write_str(value)?;
                         }
-                        ColumnarValue::Array(v) => {
-                            if v.is_valid(index) {
-                                let v = as_string_array(v).unwrap();
-                                owned_string.push_str(v.value(index));
-                            }
+                    }
+                    ColumnarValue::Array(v) => {
+                        if v.is_valid(index) {
+                            let v = as_string_array(v)?;
+                            builder.write_str(v.value(index))?;
                         }
-                        _ => unreachable!(),
                     }
+                    _ => unreachable!(),
                 }
-                Some(owned_string)
-            })
-            .collect::<StringArray>();
+            }
+            builder.append_value("");
+        }
 
-        Ok(ColumnarValue::Array(Arc::new(result)))
+        Ok(ColumnarValue::Array(Arc::new(builder.finish())))
     } else {
         // short avenue with only scalars
         let initial = Some("".to_string());
@@ -227,6 +493,47 @@ pub fn concat(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     }
 }
 
+/// [`concat`] with at least one run-end encoded array argument, which is returned
+/// as a plain string array.
+///
+/// When the run-end encoded array is the only array argument the scalars are
+/// concatenated once per run, and the result expanded to one value per row.
+/// Otherwise the run-end encoded arrays are expanded up front.
+fn concat_run_end_encoded(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let mut arrays = args.iter().filter_map(|arg| match arg {
+        ColumnarValue::Array(array) => Some(array),
+        ColumnarValue::Scalar(_) => None,
+    });
+    if let (Some(array), None) = (arrays.next(), arrays.next()) {
+        let values = run_end_encoded_values(array.as_ref())?;
+        let args = args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Array(_) => ColumnarValue::Array(values.clone()),
+                scalar => scalar.clone(),
+            })
+            .collect::<Vec<_>>();
+        let concatenated = concat(&args)?.into_array(values.len())?;
+        let indices = run_end_encoded_indices(array.as_ref())?;
+        return Ok(ColumnarValue::Array(take(&concatenated, &indices, None)?));
+    }
+
+    let args = args
+        .iter()
+        .map(|arg| match arg {
+            ColumnarValue::Array(array)
+                if matches!(array.data_type(), DataType::RunEndEncoded(_, _)) =>
+            {
+                let values = run_end_encoded_values(array.as_ref())?;
+                let indices = run_end_encoded_indices(array.as_ref())?;
+                Ok(ColumnarValue::Array(take(values, &indices, None)?))
+            }
+            arg => Ok(arg.clone()),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    concat(&args)
+}
+
 /// Concatenates all but the first argument, with separators. The first argument is used as the separator string, and should not be NULL. Other NULL arguments are ignored.
 /// concat_ws(',', 'abcde', 2, NULL, 22) = 'abcde,2,22'
 pub fn concat_ws(args: &[ArrayRef]) -> Result<ArrayRef> {
@@ -244,31 +551,55 @@ pub fn concat_ws(args: &[ArrayRef]) -> Result<ArrayRef> {
         );
     }
 
-    // first map is the iterator, second is for the `Option<_>`
-    let result = args[0]
+    // NULL values are ignored, so skip entirely NULL columns up front rather than
+    // checking each of their rows
+    let separators = args[0];
+    let values = args[1..]
         .iter()
-        .enumerate()
-        .map(|(index, x)| {
-            x.map(|sep: &str| {
-                let string_vec = args[1..]
-                    .iter()
-                    .flat_map(|arg| {
-                        if !arg.is_null(index) {
-                            Some(arg.value(index))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<Vec<&str>>();
-                string_vec.join(sep)
-            })
-        })
-        .collect::<StringArray>();
+        .filter(|arg| arg.null_count() < arg.len())
+        .collect::<Vec<_>>();
 
-    Ok(Arc::new(result) as ArrayRef)
+    // reserve the size of all the values plus a separator between each of them, so
+    // that the output buffer is never reallocated
+    let separators_size = separators
+        .iter()
+        .map(|sep| sep.map_or(0, |sep| sep.len() * values.len().saturating_sub(1)))
+        .sum::<usize>();
+    let data_size =
+        values.iter().map(|arg| value_data_len(arg)).sum::<usize>() + separators_size;
+
+    let mut builder = StringBuilder::with_capacity(separators.len(), data_size);
+    for (index, sep) in separators.iter().enumerate() {
+        let Some(sep) = sep else {
+            builder.append_null();
+            continue;
+        };
+        let mut first = true;
+        for arg in &values {
+            if arg.is_null(index) {
+                continue;
+            }
+            if !first {
+                builder.write_str(sep)?;
+            }
+            builder.write_str(arg.value(index))?;
+            first = false;
+        }
+        builder.append_value("");
+    }
+
+    Ok(Arc::new(builder.finish()) as ArrayRef)
+}
+
+/// Returns the number of bytes of string data referenced by `array`
+fn value_data_len<O: OffsetSizeTrait>(array: &GenericStringArray<O>) -> usize {
+    let offsets = array.value_offsets();
+    (offsets[array.len()] - offsets[0]).as_usize()
 }
 
 /// Converts the first letter of each word to upper case and the rest to lower case. Words are sequences of alphanumeric characters separated by non-alphanumeric characters.
+/// Case mapping follows the full Unicode rules, so a letter may map to several
+/// characters: a leading `ß` becomes `Ss` and a leading `ﬁ` ligature becomes `Fi`.
 /// initcap('hi THOMAS') = 'Hi Thomas'
 pub fn initcap<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let string_array = as_generic_string_array::<T>(&args[0])?;
@@ -278,19 +609,21 @@ pub fn initcap<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
         .iter()
         .map(|string| {
             string.map(|string: &str| {
-                let mut char_vector = Vec::<char>::new();
+                let mut result = String::with_capacity(string.len());
                 let mut previous_character_letter_or_number = false;
                 for c in string.chars() {
                     if previous_character_letter_or_number {
-                        char_vector.push(c.to_ascii_lowercase());
+                        result.extend(c.to_lowercase());
                     } else {
-                        char_vector.push(c.to_ascii_uppercase());
+                        // approximate title case: only the first character of a
+                        // multi-character upper case mapping stays upper case
+                        let mut upper = c.to_uppercase();
+                        result.extend(upper.next());
+                        upper.for_each(|c| result.extend(c.to_lowercase()));
                     }
-                    previous_character_letter_or_number = c.is_ascii_uppercase()
-                        || c.is_ascii_lowercase()
-                        || c.is_ascii_digit();
+                    previous_character_letter_or_number = c.is_alphanumeric();
                 }
-                char_vector.iter().collect::<String>()
+                result
             })
         })
         .collect::<GenericStringArray<T>>();
@@ -342,7 +675,7 @@ pub fn instr<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
-/// Converts the string to all lower case.
+/// Converts the string to all lower case using full Unicode case mapping.
 /// lower('TOM') = 'tom'
 pub fn lower(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     handle(args, |string| string.to_lowercase(), "lower")
@@ -437,20 +770,64 @@ pub fn rtrim<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     general_trim::<T>(args, TrimType::Right)
 }
 
+/// Appends `fill` to `buffer` repeatedly until `length` characters were appended.
+/// The last repetition is cut short (on a character boundary) if `length` is not a
+/// multiple of the number of characters in `fill`. Nothing is appended if `fill`
+/// is empty.
+/// push_repeated(buffer, 'abc', 7) appends 'abcabca'
+pub(crate) fn push_repeated(buffer: &mut String, fill: &str, length: usize) {
+    let fill_len = fill.chars().count();
+    if fill_len == 0 {
+        return;
+    }
+
+    let repetitions = length / fill_len;
+    let partial = match fill.char_indices().nth(length % fill_len) {
+        Some((end, _)) => &fill[..end],
+        None => "",
+    };
+    buffer.reserve(repetitions * fill.len() + partial.len());
+    for _ in 0..repetitions {
+        buffer.push_str(fill);
+    }
+    buffer.push_str(partial);
+}
+
 /// Repeats string the specified number of times.
 /// repeat('Pg', 4) = 'PgPgPgPg'
 pub fn repeat<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let string_array = as_generic_string_array::<T>(&args[0])?;
     let number_array = as_int64_array(&args[1])?;
 
+    // the bytes of the results so far, which must fit the offsets of the array
+    let mut total_len = 0_usize;
     let result = string_array
         .iter()
         .zip(number_array.iter())
         .map(|(string, number)| match (string, number) {
-            (Some(string), Some(number)) => Some(string.repeat(number as usize)),
-            _ => None,
+            (Some(string), Some(number)) => {
+                // negative numbers produce an empty string
+                let number = usize::try_from(number).unwrap_or(0);
+                let Some(len) = string
+                    .len()
+                    .checked_mul(number)
+                    .and_then(|len| len.checked_add(total_len))
+                    .filter(|len| T::from_usize(*len).is_some())
+                else {
+                    return exec_err!(
+                        "repeat of a string of {} bytes {number} times overflows",
+                        string.len()
+                    );
+                };
+                total_len = len;
+
+                let mut result = String::new();
+                push_repeated(&mut result, string, string.chars().count() * number);
+                Ok(Some(result))
+            }
+            _ => Ok(None),
         })
-        .collect::<GenericStringArray<T>>();
+        .collect::<Result<GenericStringArray<T>>>()?;
 
     Ok(Arc::new(result) as ArrayRef)
 }
@@ -477,9 +854,24 @@ pub fn replace<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
 
 /// Splits string at occurrences of delimiter and returns the n'th field (counting from one).
 /// split_part('abc~@~def~@~ghi', '~@~', 2) = 'def'
+///
+/// With a fourth argument, returns the fields `from` to `to` inclusive, re-joined with the
+/// delimiter. Negative positions count from the end.
+/// split_part('a-b-c-d', '-', 2, 3) = 'b-c'
+/// split_part('a-b-c-d', '-', -2, -1) = 'c-d'
+///
+/// An empty delimiter splits the string into its characters, like an empty
+/// delimiter in `string_to_array`.
+/// split_part('abc', '', 2) = 'b'
 pub fn split_part<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let string_array = as_generic_string_array::<T>(&args[0])?;
-    let delimiter_array = as_generic_string_array::<T>(&args[1])?;
+    // the delimiter may use a different offset size than the string
+    let delimiter = arrow::compute::cast(&args[1], args[0].data_type())?;
+    let delimiter_array = as_generic_string_array::<T>(&delimiter)?;
+    if args.len() == 4 {
+        return split_part_range(string_array, delimiter_array, &args[2], &args[3]);
+    }
+
     let n_array = as_int64_array(&args[2])?;
     let result = string_array
         .iter()
@@ -490,7 +882,7 @@ pub fn split_part<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
                 if n <= 0 {
                     exec_err!("field position must be greater than zero")
                 } else {
-                    let split_string: Vec<&str> = string.split(delimiter).collect();
+                    let split_string = split_on_delimiter(string, delimiter);
                     match split_string.get(n as usize - 1) {
                         Some(s) => Ok(Some(*s)),
                         None => Ok(Some("")),
@@ -504,47 +896,141 @@ pub fn split_part<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     Ok(Arc::new(result) as ArrayRef)
 }
 
+/// The four argument form of [`split_part`]
+fn split_part_range<T: OffsetSizeTrait>(
+    string_array: &GenericStringArray<T>,
+    delimiter_array: &GenericStringArray<T>,
+    from: &ArrayRef,
+    to: &ArrayRef,
+) -> Result<ArrayRef> {
+    let from_array = as_int64_array(from)?;
+    let to_array = as_int64_array(to)?;
+    let result = string_array
+        .iter()
+        .zip(delimiter_array.iter())
+        .zip(from_array.iter())
+        .zip(to_array.iter())
+        .map(
+            |(((string, delimiter), from), to)| match (string, delimiter, from, to) {
+                (Some(string), Some(delimiter), Some(from), Some(to)) => {
+                    if from == 0 || to == 0 {
+                        return exec_err!("field position must not be zero");
+                    }
+                    let split_string = split_on_delimiter(string, delimiter);
+                    let len = split_string.len() as i64;
+                    // zero based positions, negative positions count from the end
+                    let resolve = |n: i64| if n > 0 { n - 1 } else { len + n };
+                    let (from, to) = (resolve(from).max(0), resolve(to).min(len - 1));
+                    if from > to {
+                        Ok(Some(String::new()))
+                    } else {
+                        Ok(Some(
+                            split_string[from as usize..=to as usize].join(delimiter),
+                        ))
+                    }
+                }
+                _ => Ok(None),
+            },
+        )
+        .collect::<Result<GenericStringArray<T>>>()?;
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Splits `string` at occurrences of `delimiter`, or into its characters if
+/// `delimiter` is empty
+fn split_on_delimiter<'a>(string: &'a str, delimiter: &str) -> Vec<&'a str> {
+    if delimiter.is_empty() {
+        string
+            .char_indices()
+            .map(|(i, c)| &string[i..i + c.len_utf8()])
+            .collect()
+    } else {
+        string.split(delimiter).collect()
+    }
+}
+
 /// Returns true if string starts with prefix.
 /// starts_with('alphabet', 'alph') = 't'
 pub fn starts_with<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let left = as_generic_string_array::<T>(&args[0])?;
-    let right = as_generic_string_array::<T>(&args[1])?;
-
-    let result = arrow::compute::kernels::comparison::starts_with(left, right)?;
-
-    Ok(Arc::new(result) as ArrayRef)
+    anchored_match::<T>(args, arrow_string::like::starts_with)
 }
 
 /// Returns true if string ends with suffix.
 /// ends_with('alphabet', 'abet') = 't'
 pub fn ends_with<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let left = as_generic_string_array::<T>(&args[0])?;
-    let right = as_generic_string_array::<T>(&args[1])?;
+    anchored_match::<T>(args, arrow_string::like::ends_with)
+}
 
-    let result = arrow::compute::kernels::comparison::ends_with(left, right)?;
+/// Applies `starts_with` or `ends_with` to the strings in `args[0]` and the
+/// affixes in `args[1]`. A constant affix, passed as a single value (see
+/// `Hint::AcceptsSingular`), is matched as a scalar against the whole array.
+fn anchored_match<T: OffsetSizeTrait>(
+    args: &[ArrayRef],
+    op: fn(&dyn Datum, &dyn Datum) -> std::result::Result<BooleanArray, ArrowError>,
+) -> Result<ArrayRef> {
+    let string = as_generic_string_array::<T>(&args[0])?;
+
+    // the signature allows mixing Utf8 and LargeUtf8, so promote the affix to
+    // the offset type of the string
+    let affix = if args[1].data_type() == args[0].data_type() {
+        args[1].clone()
+    } else {
+        cast(&args[1], args[0].data_type())?
+    };
+    let affix = as_generic_string_array::<T>(&affix)?;
+
+    let result = if affix.len() == 1 && string.len() != 1 {
+        op(string, &Scalar::new(affix))?
+    } else {
+        op(string, affix)?
+    };
 
     Ok(Arc::new(result) as ArrayRef)
 }
 
 /// Converts the number to its equivalent hexadecimal representation.
 /// to_hex(2147483647) = '7fffffff'
+///
+/// An optional second argument is a comma separated list of options: `lower` (the
+/// default) or `upper` for the case of the digits, and `0x` to prepend a `0x` prefix.
+/// to_hex(255, 'upper') = 'FF'
+/// to_hex(255, 'upper,0x') = '0xFF'
 pub fn to_hex<T: ArrowPrimitiveType>(args: &[ArrayRef]) -> Result<ArrayRef>
 where
     T::Native: OffsetSizeTrait,
 {
     let integer_array = as_primitive_array::<T>(&args[0])?;
+    let options_array = args
+        .get(1)
+        .map(|options| as_string_array(options))
+        .transpose()?;
 
     let result = integer_array
         .iter()
-        .map(|integer| {
+        .enumerate()
+        .map(|(i, integer)| {
+            let (uppercase, prefix) = match options_array {
+                Some(options) if options.is_null(i) => return Ok(None),
+                Some(options) => to_hex_options(options.value(i))?,
+                None => (false, false),
+            };
             if let Some(value) = integer {
-                if let Some(value_usize) = value.to_usize() {
-                    Ok(Some(format!("{value_usize:x}")))
-                } else if let Some(value_isize) = value.to_isize() {
-                    Ok(Some(format!("{value_isize:x}")))
+                // negative numbers are formatted as their 64 bit two's complement
+                let Some(value) = value
+                    .to_usize()
+                    .or_else(|| value.to_isize().map(|value| value as usize))
+                else {
+                    return exec_err!(
+                        "Unsupported data type {integer:?} for function to_hex"
+                    );
+                };
+                let prefix = if prefix { "0x" } else { "" };
+                Ok(Some(if uppercase {
+                    format!("{prefix}{value:X}")
                 } else {
-                    exec_err!("Unsupported data type {integer:?} for function to_hex")
-                }
+                    format!("{prefix}{value:x}")
+                }))
             } else {
                 Ok(None)
             }
@@ -554,8 +1040,30 @@ where
     Ok(Arc::new(result) as ArrayRef)
 }
 
-/// Converts the string to all upper case.
-/// upper('tom') = 'TOM'
+/// Parses the options of `to_hex`, returning whether the digits are uppercase and
+/// whether the `0x` prefix is added
+fn to_hex_options(options: &str) -> Result<(bool, bool)> {
+    let mut uppercase = false;
+    let mut prefix = false;
+    for option in options.split(',').map(str::trim) {
+        if option.eq_ignore_ascii_case("upper") {
+            uppercase = true;
+        } else if option.eq_ignore_ascii_case("lower") {
+            uppercase = false;
+        } else if option.eq_ignore_ascii_case("0x") {
+            prefix = true;
+        } else if !option.is_empty() {
+            return exec_err!(
+                "Unsupported option '{option}' for function to_hex, expected 'upper', 'lower' or '0x'"
+            );
+        }
+    }
+    Ok((uppercase, prefix))
+}
+
+/// Converts the string to all upper case using full Unicode case mapping,
+/// which may change the length of the string.
+/// upper('tom') = 'TOM', upper('straße') = 'STRASSE'
 pub fn upper(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     handle(args, |string| string.to_uppercase(), "upper")
 }
@@ -663,6 +1171,9 @@ pub fn overlay<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
 
 ///Returns the Levenshtein distance between the two given strings.
 /// LEVENSHTEIN('kitten', 'sitting') = 3
+///
+/// The distance counts edits of characters (Unicode code points), not bytes:
+/// LEVENSHTEIN('café', 'cafe') = 1
 pub fn levenshtein<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.len() != 2 {
         return exec_err!(
@@ -672,30 +1183,27 @@ pub fn levenshtein<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
     let str1_array = as_generic_string_array::<T>(&args[0])?;
     let str2_array = as_generic_string_array::<T>(&args[1])?;
+    // one row of the distance matrix, reused for every pair of strings
+    let mut cache = vec![];
+    let distances = str1_array
+        .iter()
+        .zip(str2_array.iter())
+        .map(|(string1, string2)| match (string1, string2) {
+            (Some(string1), Some(string2)) => Some(
+                datafusion_strsim::levenshtein_with_buffer(string1, string2, &mut cache),
+            ),
+            _ => None,
+        });
     match args[0].data_type() {
         DataType::Utf8 => {
-            let result = str1_array
-                .iter()
-                .zip(str2_array.iter())
-                .map(|(string1, string2)| match (string1, string2) {
-                    (Some(string1), Some(string2)) => {
-                        Some(datafusion_strsim::levenshtein(string1, string2) as i32)
-                    }
-                    _ => None,
-                })
+            let result = distances
+                .map(|distance| distance.map(|d| d as i32))
                 .collect::<Int32Array>();
             Ok(Arc::new(result) as ArrayRef)
         }
         DataType::LargeUtf8 => {
-            let result = str1_array
-                .iter()
-                .zip(str2_array.iter())
-                .map(|(string1, string2)| match (string1, string2) {
-                    (Some(string1), Some(string2)) => {
-                        Some(datafusion_strsim::levenshtein(string1, string2) as i64)
-                    }
-                    _ => None,
-                })
+            let result = distances
+                .map(|distance| distance.map(|d| d as i64))
                 .collect::<Int64Array>();
             Ok(Arc::new(result) as ArrayRef)
         }
@@ -709,15 +1217,37 @@ pub fn levenshtein<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
 
 #[cfg(test)]
 mod tests {
-    use arrow::{array::Int32Array, datatypes::Int32Type};
-    use arrow_array::Int64Array;
+    use arrow::{
+        array::{Int16Array, Int32Array, RunArray},
+        datatypes::{Int16Type, Int32Type, Int64Type},
+    };
+    use arrow_array::{Int64Array, LargeStringArray};
 
-    use datafusion_common::cast::as_int32_array;
+    use datafusion_common::cast::{as_int32_array, as_int64_array};
 
     use crate::string_expressions;
 
     use super::*;
 
+    #[test]
+    fn push_repeated_partial_fill() {
+        let repeated = |fill: &str, length: usize| {
+            let mut buffer = String::from("|");
+            push_repeated(&mut buffer, fill, length);
+            buffer
+        };
+
+        // the fill that `lpad('hi', 21, 'abcdef')` prepends
+        assert_eq!(repeated("abcdef", 19), "|abcdefabcdefabcdefa");
+        assert_eq!(repeated("abcdef", 18), "|abcdefabcdefabcdef");
+        assert_eq!(repeated("abcdef", 5), "|abcde");
+        // multi-byte fills are cut on character boundaries
+        assert_eq!(repeated("éñ", 3), "|éñé");
+        assert_eq!(repeated("é", 2), "|éé");
+        assert_eq!(repeated("abc", 0), "|");
+        assert_eq!(repeated("", 5), "|");
+    }
+
     #[test]
     // Test to_hex function for zero
     fn to_hex_zero() -> Result<()> {
@@ -757,6 +1287,82 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    // Test to_hex function with uppercase and prefix options
+    fn to_hex_parses_options() -> Result<()> {
+        let options: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("upper"),
+            Some("0x"),
+            Some("upper, 0x"),
+            Some("lower"),
+            None,
+        ]));
+        let expected = StringArray::from(vec![
+            Some("FF"),
+            Some("0xffffffffffffffff"),
+            Some("0x7FFFFFFF"),
+            Some("64"),
+            None,
+        ]);
+
+        let integers: ArrayRef =
+            Arc::new(Int32Array::from(vec![255, -1, i32::MAX, 100, 1]));
+        let hex_value_arc =
+            string_expressions::to_hex::<Int32Type>(&[integers, options.clone()])?;
+        assert_eq!(&expected, as_string_array(&hex_value_arc)?);
+
+        let integers: ArrayRef =
+            Arc::new(Int64Array::from(vec![255, -1, i32::MAX as i64, 100, 1]));
+        let hex_value_arc =
+            string_expressions::to_hex::<Int64Type>(&[integers, options])?;
+        assert_eq!(&expected, as_string_array(&hex_value_arc)?);
+
+        let integers: ArrayRef = Arc::new(Int64Array::from(vec![i64::MAX]));
+        let options: ArrayRef = Arc::new(StringArray::from(vec!["0x,upper"]));
+        let hex_value_arc =
+            string_expressions::to_hex::<Int64Type>(&[integers.clone(), options])?;
+        let expected = StringArray::from(vec!["0x7FFFFFFFFFFFFFFF"]);
+        assert_eq!(&expected, as_string_array(&hex_value_arc)?);
+
+        let options: ArrayRef = Arc::new(StringArray::from(vec!["octal"]));
+        assert!(string_expressions::to_hex::<Int64Type>(&[integers, options]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    // Test that concat and concat_ws drop null values from (sliced) arrays
+    fn concat_arrays_with_nulls() -> Result<()> {
+        let a: ArrayRef = Arc::new(
+            StringArray::from(vec![Some("skip"), Some("a"), None, Some("c"), None])
+                .slice(1, 4),
+        );
+        let b: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("x"), None, Some("z"), None]));
+
+        let result = concat(&[
+            ColumnarValue::Array(a.clone()),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("-".to_string()))),
+            ColumnarValue::Array(b.clone()),
+            ColumnarValue::Scalar(ScalarValue::Utf8(None)),
+        ])?
+        .into_array(4)?;
+        let expected = StringArray::from(vec!["a-x", "-", "c-z", "-"]);
+        assert_eq!(&expected, as_string_array(&result)?);
+
+        let sep: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(", "),
+            Some(", "),
+            Some(""),
+            None,
+        ]));
+        let result = concat_ws(&[sep, a, b])?;
+        let expected = StringArray::from(vec![Some("a, x"), Some(""), Some("cz"), None]);
+        assert_eq!(&expected, as_string_array(&result)?);
+
+        Ok(())
+    }
+
     #[test]
     fn to_overlay() -> Result<()> {
         let string =
@@ -788,4 +1394,230 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn to_levenshtein_multi_byte_characters() -> Result<()> {
+        let pairs = [
+            ("café", "cafe"),
+            ("日本", "日本語"),
+            ("日本", "本日"),
+            ("straße", "strase"),
+            ("🦀", "🐍"),
+            ("añb", "ab"),
+        ];
+        let res = levenshtein::<i32>(&[
+            Arc::new(StringArray::from_iter_values(pairs.iter().map(|(a, _)| a))),
+            Arc::new(StringArray::from_iter_values(pairs.iter().map(|(_, b)| b))),
+        ])?;
+        let expected = Int32Array::from(vec![1, 1, 2, 1, 1, 1]);
+        assert_eq!(as_int32_array(&res)?, &expected);
+
+        // each of these differs from the distance between the UTF-8 bytes
+        let byte_distance = |a: &str, b: &str| {
+            let a = a.bytes().map(char::from).collect::<String>();
+            let b = b.bytes().map(char::from).collect::<String>();
+            reference_levenshtein(&a, &b) as i32
+        };
+        for ((a, b), distance) in pairs.iter().zip(expected.values()) {
+            assert_ne!(byte_distance(a, b), *distance, "{a} and {b}");
+        }
+
+        Ok(())
+    }
+
+    /// Full matrix version of the Levenshtein distance, used to check the results
+    fn reference_levenshtein(a: &str, b: &str) -> usize {
+        let a = a.chars().collect::<Vec<_>>();
+        let b = b.chars().collect::<Vec<_>>();
+        let mut matrix = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[0] = i;
+        }
+        for (j, cell) in matrix[0].iter_mut().enumerate() {
+            *cell = j;
+        }
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                let cost = usize::from(a[i - 1] != b[j - 1]);
+                matrix[i][j] = (matrix[i - 1][j] + 1)
+                    .min(matrix[i][j - 1] + 1)
+                    .min(matrix[i - 1][j - 1] + cost);
+            }
+        }
+        matrix[a.len()][b.len()]
+    }
+
+    #[test]
+    fn to_levenshtein_long_strings() -> Result<()> {
+        // kilobyte sized strings with multi byte characters and unequal lengths,
+        // so the shorter string is sometimes the first and sometimes the second
+        let long = |seed: usize, len: usize| {
+            (0..len)
+                .map(|i| ['a', 'b', 'c', 'ß', '日'][(i * 7 + i / seed + seed) % 5])
+                .collect::<String>()
+        };
+        let string1 = vec![long(3, 1000), long(5, 1500), String::new(), long(2, 700)];
+        let string2 = vec![long(4, 1200), long(6, 900), long(7, 1024), long(2, 700)];
+        let expected = string1
+            .iter()
+            .zip(&string2)
+            .map(|(a, b)| reference_levenshtein(a, b))
+            .collect::<Vec<_>>();
+
+        let res = levenshtein::<i32>(&[
+            Arc::new(StringArray::from(string1.clone())),
+            Arc::new(StringArray::from(string2.clone())),
+        ])?;
+        let expected_i32 = expected.iter().map(|d| *d as i32).collect::<Int32Array>();
+        assert_eq!(as_int32_array(&res)?, &expected_i32);
+
+        let res = levenshtein::<i64>(&[
+            Arc::new(LargeStringArray::from(string1)),
+            Arc::new(LargeStringArray::from(string2)),
+        ])?;
+        let expected_i64 = expected.iter().map(|d| *d as i64).collect::<Int64Array>();
+        assert_eq!(as_int64_array(&res)?, &expected_i64);
+        assert_eq!(expected[3], 0);
+        assert_eq!(expected[2], 1024);
+
+        Ok(())
+    }
+
+    #[test]
+    // Test that ascii(chr(n)) == n for every code point chr accepts
+    fn chr_ascii_round_trip() -> Result<()> {
+        let code_points = (1..=char::MAX as i64)
+            .filter(|n| !(0xD800..=0xDFFF).contains(n))
+            .collect::<Int64Array>();
+        let code_points: ArrayRef = Arc::new(code_points);
+
+        let chars = chr::<i32>(std::slice::from_ref(&code_points))?;
+        assert_eq!(chars.data_type(), &DataType::Utf8);
+        let result = ascii::<i32>(&[chars])?;
+        let result = as_int32_array(&result)?;
+        assert!(result
+            .iter()
+            .zip(as_int64_array(&code_points)?.iter())
+            .all(|(a, n)| a.map(i64::from) == n));
+
+        let chars = chr::<i64>(std::slice::from_ref(&code_points))?;
+        assert_eq!(chars.data_type(), &DataType::LargeUtf8);
+        let result = ascii::<i64>(&[chars])?;
+        let result = as_int32_array(&result)?;
+        assert!(result
+            .iter()
+            .zip(as_int64_array(&code_points)?.iter())
+            .all(|(a, n)| a.map(i64::from) == n));
+
+        Ok(())
+    }
+
+    #[test]
+    // Test that chr rejects the null character and surrogate code points
+    fn chr_invalid_code_points() {
+        for n in [0, 0xD800, 0xDFFF, char::MAX as i64 + 1] {
+            let array: ArrayRef = Arc::new(Int64Array::from(vec![n]));
+            assert!(chr::<i32>(std::slice::from_ref(&array)).is_err());
+            assert!(chr::<i64>(&[array]).is_err());
+        }
+    }
+
+    #[test]
+    fn anchored_match_with_single_affix() -> Result<()> {
+        let strings = Arc::new(StringArray::from(vec![
+            Some("alphabet"),
+            None,
+            Some("alpine"),
+            Some("bet"),
+        ])) as ArrayRef;
+
+        // a constant affix is passed as a single value and matched against every row
+        let prefix = Arc::new(StringArray::from(vec!["alp"])) as ArrayRef;
+        let result = starts_with::<i32>(&[strings.clone(), prefix])?;
+        let expected =
+            BooleanArray::from(vec![Some(true), None, Some(true), Some(false)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        // the affix is promoted to the offset type of the strings
+        let suffix = Arc::new(LargeStringArray::from(vec!["bet"])) as ArrayRef;
+        let result = ends_with::<i32>(&[strings.clone(), suffix])?;
+        let expected =
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let null = Arc::new(StringArray::from(vec![None::<&str>])) as ArrayRef;
+        let result = starts_with::<i32>(&[strings.clone(), null])?;
+        assert_eq!(result.null_count(), 4);
+
+        // affixes given per row are matched row by row
+        let prefixes = Arc::new(StringArray::from(vec![
+            Some("alpha"),
+            Some("x"),
+            Some("b"),
+            None,
+        ])) as ArrayRef;
+        let result = starts_with::<i32>(&[strings, prefixes])?;
+        let expected = BooleanArray::from(vec![Some(true), None, Some(false), None]);
+        assert_eq!(result.as_ref(), &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn upper_lower_run_end_encoded() -> Result<()> {
+        let values = StringArray::from(vec![Some("ab"), None, Some("Cd")]);
+        let strings: ArrayRef = Arc::new(RunArray::<Int32Type>::try_new(
+            &Int32Array::from(vec![2, 3, 6]),
+            &values,
+        )?);
+
+        // the function is applied to the values and the runs are kept
+        let result = upper(&[ColumnarValue::Array(strings.clone())])?.into_array(6)?;
+        assert_eq!(result.data_type(), strings.data_type());
+        let result = as_run_array::<Int32Type>(&result)?;
+        assert_eq!(result.run_ends().values(), &[2, 3, 6]);
+        let expected = StringArray::from(vec![Some("AB"), None, Some("CD")]);
+        assert_eq!(result.values().as_ref(), &expected);
+
+        // slices of the runs are kept too
+        let sliced = strings.slice(1, 4);
+        let result = lower(&[ColumnarValue::Array(sliced)])?.into_array(4)?;
+        assert_eq!(result.len(), 4);
+        let indices = run_end_encoded_indices(&result)?;
+        let result = take(run_end_encoded_values(&result)?, &indices, None)?;
+        let expected = StringArray::from(vec![Some("ab"), None, Some("cd"), Some("cd")]);
+        assert_eq!(result.as_ref(), &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn concat_run_end_encoded_arguments() -> Result<()> {
+        let values = StringArray::from(vec![Some("ab"), None, Some("Cd")]);
+        let strings: ArrayRef = Arc::new(RunArray::<Int16Type>::try_new(
+            &Int16Array::from(vec![2, 3, 6]),
+            &values,
+        )?);
+
+        // with scalars only the runs are concatenated
+        let result = concat(&[
+            ColumnarValue::Array(strings.clone()),
+            ColumnarValue::Scalar(ScalarValue::from("!")),
+        ])?
+        .into_array(6)?;
+        let expected = StringArray::from(vec!["ab!", "ab!", "!", "Cd!", "Cd!", "Cd!"]);
+        assert_eq!(result.as_ref(), &expected);
+
+        // with other arrays the runs are expanded
+        let other = Arc::new(StringArray::from(vec!["1", "2", "3", "4", "5"]));
+        let result = concat(&[
+            ColumnarValue::Array(other),
+            ColumnarValue::Array(strings.slice(1, 5)),
+        ])?
+        .into_array(5)?;
+        let expected = StringArray::from(vec!["1ab", "2", "3Cd", "4Cd", "5Cd"]);
+        assert_eq!(result.as_ref(), &expected);
+
+        Ok(())
+    }
 }
@@ -22,13 +22,20 @@
 //! Unicode expressions
 
 use std::cmp::{max, Ordering};
+use std::ops::Range;
 use std::sync::Arc;
 
 use arrow::{
-    array::{ArrayRef, GenericStringArray, OffsetSizeTrait, PrimitiveArray},
+    array::{
+        new_null_array, Array, ArrayRef, GenericStringArray, OffsetSizeTrait,
+        PrimitiveArray,
+    },
+    buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer},
+    compute::cast,
     datatypes::{ArrowNativeType, ArrowPrimitiveType},
 };
 use hashbrown::HashMap;
+use memchr::memmem;
 use unicode_segmentation::UnicodeSegmentation;
 
 use datafusion_common::{
@@ -36,6 +43,8 @@ use datafusion_common::{
     exec_err, Result,
 };
 
+use crate::string_expressions::push_repeated;
+
 /// Returns number of characters in the string.
 /// character_length('josé') = 4
 /// The implementation counts UTF-8 code points to count the number of characters
@@ -65,29 +74,62 @@ where
 pub fn left<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let string_array = as_generic_string_array::<T>(&args[0])?;
     let n_array = as_int64_array(&args[1])?;
-    let result = string_array
+    let ascii = string_array.value_data().is_ascii();
+
+    let ranges = string_array
         .iter()
         .zip(n_array.iter())
         .map(|(string, n)| match (string, n) {
-            (Some(string), Some(n)) => match n.cmp(&0) {
-                Ordering::Less => {
-                    let len = string.chars().count() as i64;
-                    Some(if n.abs() < len {
-                        string.chars().take((len + n) as usize).collect::<String>()
-                    } else {
-                        "".to_string()
-                    })
-                }
-                Ordering::Equal => Some("".to_string()),
-                Ordering::Greater => {
-                    Some(string.chars().take(n as usize).collect::<String>())
-                }
-            },
-            _ => None,
+            (Some(string), Some(n)) => {
+                let end = match n.cmp(&0) {
+                    Ordering::Less => {
+                        let len = char_count(string, ascii);
+                        let n = n.unsigned_abs() as usize;
+                        if n < len {
+                            byte_offset(string, len - n, ascii)
+                        } else {
+                            0
+                        }
+                    }
+                    Ordering::Equal => 0,
+                    Ordering::Greater => byte_offset(string, n as usize, ascii),
+                };
+                0..end
+            }
+            _ => 0..0,
         })
-        .collect::<GenericStringArray<T>>();
+        .collect::<Vec<_>>();
 
-    Ok(Arc::new(result) as ArrayRef)
+    let nulls = NullBuffer::union(string_array.nulls(), n_array.nulls());
+    substring_by_ranges(string_array, &ranges, nulls)
+}
+
+/// Returns the number of characters in `string`. When `ascii` is set characters
+/// and bytes coincide, so the string is not decoded.
+fn char_count(string: &str, ascii: bool) -> usize {
+    if ascii {
+        string.len()
+    } else {
+        string.chars().count()
+    }
+}
+
+/// Returns the byte offset of the `n`th character of `string`, or the length of the
+/// string if it has `n` characters or fewer. When `ascii` is set characters and
+/// bytes coincide, so the offset is computed without decoding the string.
+///
+/// Slicing with these offsets lets `left`, `right` and `substr` copy each result
+/// with a single `memcpy` rather than collecting characters into a new `String`.
+fn byte_offset(string: &str, n: usize, ascii: bool) -> usize {
+    if ascii {
+        n.min(string.len())
+    } else {
+        string
+            .char_indices()
+            .nth(n)
+            .map(|(offset, _)| offset)
+            .unwrap_or(string.len())
+    }
 }
 
 /// Extends the string to length 'length' by prepending the characters fill (a space by default). If the string is already longer than length then it is truncated (on the right).
@@ -109,7 +151,8 @@ pub fn lpad<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
                             );
                         }
 
-                        let length = if length < 0 { 0 } else { length as usize };
+                        // negative lengths produce an empty string
+                        let length = usize::try_from(length).unwrap_or(0);
                         if length == 0 {
                             Ok(Some("".to_string()))
                         } else {
@@ -117,7 +160,8 @@ pub fn lpad<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
                             if length < graphemes.len() {
                                 Ok(Some(graphemes[..length].concat()))
                             } else {
-                                let mut s: String = " ".repeat(length - graphemes.len());
+                                let mut s = String::new();
+                                push_repeated(&mut s, " ", length - graphemes.len());
                                 s.push_str(string);
                                 Ok(Some(s))
                             }
@@ -146,30 +190,19 @@ pub fn lpad<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
                             );
                         }
 
-                        let length = if length < 0 { 0 } else { length as usize };
+                        // negative lengths produce an empty string
+                        let length = usize::try_from(length).unwrap_or(0);
                         if length == 0 {
                             Ok(Some("".to_string()))
                         } else {
                             let graphemes = string.graphemes(true).collect::<Vec<&str>>();
-                            let fill_chars = fill.chars().collect::<Vec<char>>();
 
                             if length < graphemes.len() {
                                 Ok(Some(graphemes[..length].concat()))
-                            } else if fill_chars.is_empty() {
-                                Ok(Some(string.to_string()))
                             } else {
-                                let mut s = string.to_string();
-                                let mut char_vector =
-                                    Vec::<char>::with_capacity(length - graphemes.len());
-                                for l in 0..length - graphemes.len() {
-                                    char_vector.push(
-                                        *fill_chars.get(l % fill_chars.len()).unwrap(),
-                                    );
-                                }
-                                s.insert_str(
-                                    0,
-                                    char_vector.iter().collect::<String>().as_str(),
-                                );
+                                let mut s = String::new();
+                                push_repeated(&mut s, fill, length - graphemes.len());
+                                s.push_str(string);
                                 Ok(Some(s))
                             }
                         }
@@ -206,31 +239,31 @@ pub fn reverse<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
 pub fn right<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     let string_array = as_generic_string_array::<T>(&args[0])?;
     let n_array = as_int64_array(&args[1])?;
+    let ascii = string_array.value_data().is_ascii();
 
-    let result = string_array
+    let ranges = string_array
         .iter()
         .zip(n_array.iter())
         .map(|(string, n)| match (string, n) {
-            (Some(string), Some(n)) => match n.cmp(&0) {
-                Ordering::Less => Some(
-                    string
-                        .chars()
-                        .skip(n.unsigned_abs() as usize)
-                        .collect::<String>(),
-                ),
-                Ordering::Equal => Some("".to_string()),
-                Ordering::Greater => Some(
-                    string
-                        .chars()
-                        .skip(max(string.chars().count() as i64 - n, 0) as usize)
-                        .collect::<String>(),
-                ),
-            },
-            _ => None,
+            (Some(string), Some(n)) => {
+                let start = match n.cmp(&0) {
+                    Ordering::Less => {
+                        byte_offset(string, n.unsigned_abs() as usize, ascii)
+                    }
+                    Ordering::Equal => string.len(),
+                    Ordering::Greater => {
+                        let len = char_count(string, ascii);
+                        byte_offset(string, len.saturating_sub(n as usize), ascii)
+                    }
+                };
+                start..string.len()
+            }
+            _ => 0..0,
         })
-        .collect::<GenericStringArray<T>>();
+        .collect::<Vec<_>>();
 
-    Ok(Arc::new(result) as ArrayRef)
+    let nulls = NullBuffer::union(string_array.nulls(), n_array.nulls());
+    substring_by_ranges(string_array, &ranges, nulls)
 }
 
 /// Extends the string to length 'length' by appending the characters fill (a space by default). If the string is already longer than length then it is truncated.
@@ -252,7 +285,8 @@ pub fn rpad<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
                             );
                         }
 
-                        let length = if length < 0 { 0 } else { length as usize };
+                        // negative lengths produce an empty string
+                        let length = usize::try_from(length).unwrap_or(0);
                         if length == 0 {
                             Ok(Some("".to_string()))
                         } else {
@@ -261,7 +295,7 @@ pub fn rpad<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
                                 Ok(Some(graphemes[..length].concat()))
                             } else {
                                 let mut s = string.to_string();
-                                s.push_str(" ".repeat(length - graphemes.len()).as_str());
+                                push_repeated(&mut s, " ", length - graphemes.len());
                                 Ok(Some(s))
                             }
                         }
@@ -288,23 +322,15 @@ pub fn rpad<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
                             );
                         }
 
-                        let length = if length < 0 { 0 } else { length as usize };
+                        // negative lengths produce an empty string
+                        let length = usize::try_from(length).unwrap_or(0);
                         let graphemes = string.graphemes(true).collect::<Vec<&str>>();
-                        let fill_chars = fill.chars().collect::<Vec<char>>();
 
                         if length < graphemes.len() {
                             Ok(Some(graphemes[..length].concat()))
-                        } else if fill_chars.is_empty() {
-                            Ok(Some(string.to_string()))
                         } else {
                             let mut s = string.to_string();
-                            let mut char_vector =
-                                Vec::<char>::with_capacity(length - graphemes.len());
-                            for l in 0..length - graphemes.len() {
-                                char_vector
-                                    .push(*fill_chars.get(l % fill_chars.len()).unwrap());
-                            }
-                            s.push_str(char_vector.iter().collect::<String>().as_str());
+                            push_repeated(&mut s, fill, length - graphemes.len());
                             Ok(Some(s))
                         }
                     }
@@ -330,26 +356,50 @@ where
     let string_array: &GenericStringArray<T::Native> =
         as_generic_string_array::<T::Native>(&args[0])?;
 
+    // the signature allows mixing Utf8 and LargeUtf8, so promote the substring to
+    // the offset type of the string
+    let substring = if args[1].data_type() == args[0].data_type() {
+        args[1].clone()
+    } else {
+        cast(&args[1], args[0].data_type())?
+    };
     let substring_array: &GenericStringArray<T::Native> =
-        as_generic_string_array::<T::Native>(&args[1])?;
-
-    let result = string_array
-        .iter()
-        .zip(substring_array.iter())
-        .map(|(string, substring)| match (string, substring) {
-            (Some(string), Some(substring)) => {
-                // the find method returns the byte index of the substring
-                // Next, we count the number of the chars until that byte
-                T::Native::from_usize(
-                    string
-                        .find(substring)
-                        .map(|x| string[..x].chars().count() + 1)
-                        .unwrap_or(0),
-                )
-            }
-            _ => None,
-        })
-        .collect::<PrimitiveArray<T>>();
+        as_generic_string_array::<T::Native>(&substring)?;
+
+    // the byte index of the substring is converted to the number of chars before it
+    let position = |string: &str, byte_index: Option<usize>| {
+        T::Native::from_usize(
+            byte_index
+                .map(|x| string[..x].chars().count() + 1)
+                .unwrap_or(0),
+        )
+    };
+
+    let result = if substring_array.len() == 1 && string_array.len() != 1 {
+        // a constant substring, passed as a single value (see `Hint::AcceptsSingular`),
+        // is searched for with a finder built once for the whole array
+        if substring_array.is_null(0) {
+            return Ok(new_null_array(&T::DATA_TYPE, string_array.len()));
+        }
+        let finder = memmem::Finder::new(substring_array.value(0));
+        string_array
+            .iter()
+            .map(|string| {
+                string.and_then(|string| position(string, finder.find(string.as_bytes())))
+            })
+            .collect::<PrimitiveArray<T>>()
+    } else {
+        string_array
+            .iter()
+            .zip(substring_array.iter())
+            .map(|(string, substring)| match (string, substring) {
+                (Some(string), Some(substring)) => {
+                    position(string, string.find(substring))
+                }
+                _ => None,
+            })
+            .collect::<PrimitiveArray<T>>()
+    };
 
     Ok(Arc::new(result) as ArrayRef)
 }
@@ -363,50 +413,58 @@ pub fn substr<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
         2 => {
             let string_array = as_generic_string_array::<T>(&args[0])?;
             let start_array = as_int64_array(&args[1])?;
+            let ascii = string_array.value_data().is_ascii();
 
-            let result = string_array
+            let ranges = string_array
                 .iter()
                 .zip(start_array.iter())
                 .map(|(string, start)| match (string, start) {
                     (Some(string), Some(start)) => {
-                        if start <= 0 {
-                            Some(string.to_string())
-                        } else {
-                            Some(string.chars().skip(start as usize - 1).collect())
-                        }
+                        let skip = (max(start, 1) - 1) as usize;
+                        byte_offset(string, skip, ascii)..string.len()
                     }
-                    _ => None,
+                    _ => 0..0,
                 })
-                .collect::<GenericStringArray<T>>();
+                .collect::<Vec<_>>();
 
-            Ok(Arc::new(result) as ArrayRef)
+            let nulls = NullBuffer::union(string_array.nulls(), start_array.nulls());
+            substring_by_ranges(string_array, &ranges, nulls)
         }
         3 => {
             let string_array = as_generic_string_array::<T>(&args[0])?;
             let start_array = as_int64_array(&args[1])?;
             let count_array = as_int64_array(&args[2])?;
+            let ascii = string_array.value_data().is_ascii();
 
-            let result = string_array
+            let ranges = string_array
                 .iter()
                 .zip(start_array.iter())
                 .zip(count_array.iter())
                 .map(|((string, start), count)| match (string, start, count) {
                     (Some(string), Some(start), Some(count)) => {
                         if count < 0 {
-                            exec_err!(
+                            return exec_err!(
                                 "negative substring length not allowed: substr(<str>, {start}, {count})"
-                            )
-                        } else {
-                            let skip = max(0, start - 1);
-                            let count = max(0, count + (if start < 1 {start - 1} else {0}));
-                            Ok(Some(string.chars().skip(skip as usize).take(count as usize).collect::<String>()))
+                            );
                         }
+                        // the substring covers the positions [start, start + count),
+                        // saturating so that extreme arguments cannot overflow
+                        let end = start.saturating_add(count);
+                        let start = max(start, 1);
+                        let skip = (start - 1) as usize;
+                        let count = max(0, end.saturating_sub(start)) as usize;
+                        let begin = byte_offset(string, skip, ascii);
+                        Ok(begin..begin + byte_offset(&string[begin..], count, ascii))
                     }
-                    _ => Ok(None),
+                    _ => Ok(0..0),
                 })
-                .collect::<Result<GenericStringArray<T>>>()?;
+                .collect::<Result<Vec<_>>>()?;
 
-            Ok(Arc::new(result) as ArrayRef)
+            let nulls = NullBuffer::union(
+                NullBuffer::union(string_array.nulls(), start_array.nulls()).as_ref(),
+                count_array.nulls(),
+            );
+            substring_by_ranges(string_array, &ranges, nulls)
         }
         other => {
             exec_err!("substr was called with {other} arguments. It requires 2 or 3.")
@@ -414,6 +472,46 @@ pub fn substr<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+/// Returns the substrings of `string_array` at the byte `ranges`, which are relative
+/// to the start of each value and must lie on character boundaries. This is shared
+/// by `left`, `right` and `substr`.
+///
+/// The input array is returned as is when every value is kept whole. Otherwise the
+/// kept bytes are copied once into a single values buffer.
+fn substring_by_ranges<T: OffsetSizeTrait>(
+    string_array: &GenericStringArray<T>,
+    ranges: &[Range<usize>],
+    nulls: Option<NullBuffer>,
+) -> Result<ArrayRef> {
+    let offsets = string_array.value_offsets();
+    let whole = nulls.as_ref() == string_array.nulls()
+        && ranges.iter().enumerate().all(|(i, range)| {
+            string_array.is_null(i)
+                || (range.start == 0
+                    && range.end == (offsets[i + 1] - offsets[i]).as_usize())
+        });
+    if whole {
+        return Ok(Arc::new(string_array.clone()));
+    }
+
+    let values = string_array.value_data();
+    let mut new_offsets = Vec::with_capacity(ranges.len() + 1);
+    let mut new_values = Vec::with_capacity(ranges.iter().map(|r| r.len()).sum());
+    new_offsets.push(T::usize_as(0));
+    for (range, offset) in ranges.iter().zip(offsets) {
+        let offset = offset.as_usize();
+        new_values.extend_from_slice(&values[offset + range.start..offset + range.end]);
+        new_offsets.push(T::usize_as(new_values.len()));
+    }
+
+    let new_offsets = OffsetBuffer::new(ScalarBuffer::from(new_offsets));
+    Ok(Arc::new(GenericStringArray::try_new(
+        new_offsets,
+        Buffer::from_vec(new_values),
+        nulls,
+    )?))
+}
+
 /// Replaces each character in string that matches a character in the from set with the corresponding character in the to set. If from is longer than to, occurrences of the extra characters in from are deleted.
 /// translate('12345', '143', 'ax') = 'a2x5'
 pub fn translate<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
@@ -22978,6 +22978,13 @@ impl serde::Serialize for ScalarFunction {
             Self::MakeDate => "MakeDate",
             Self::ArrayReverse => "ArrayReverse",
             Self::ToChar => "ToChar",
+            Self::ArrayFlattenDistinct => "ArrayFlattenDistinct",
+            Self::ElementAt => "ElementAt",
+            Self::CoalesceNan => "CoalesceNan",
+            Self::Gamma => "Gamma",
+            Self::Lgamma => "Lgamma",
+            Self::BitCount => "BitCount",
+            Self::Mod => "Mod",
         };
         serializer.serialize_str(variant)
     }
@@ -23075,6 +23082,13 @@ impl<'de> serde::Deserialize<'de> for ScalarFunction {
             "MakeDate",
             "ArrayReverse",
             "ToChar",
+            "ArrayFlattenDistinct",
+            "ElementAt",
+            "CoalesceNan",
+            "Gamma",
+            "Lgamma",
+            "BitCount",
+            "Mod",
         ];
 
         struct GeneratedVisitor;
@@ -23201,6 +23215,13 @@ impl<'de> serde::Deserialize<'de> for ScalarFunction {
                     "MakeDate" => Ok(ScalarFunction::MakeDate),
                     "ArrayReverse" => Ok(ScalarFunction::ArrayReverse),
                     "ToChar" => Ok(ScalarFunction::ToChar),
+                    "ArrayFlattenDistinct" => Ok(ScalarFunction::ArrayFlattenDistinct),
+                    "ElementAt" => Ok(ScalarFunction::ElementAt),
+                    "CoalesceNan" => Ok(ScalarFunction::CoalesceNan),
+                    "Gamma" => Ok(ScalarFunction::Gamma),
+                    "Lgamma" => Ok(ScalarFunction::Lgamma),
+                    "BitCount" => Ok(ScalarFunction::BitCount),
+                    "Mod" => Ok(ScalarFunction::Mod),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -2952,6 +2952,13 @@ pub enum ScalarFunction {
     /// / 137 was ToDate
     /// / 138 was ToUnixtime
     ToChar = 136,
+    ArrayFlattenDistinct = 139,
+    ElementAt = 140,
+    CoalesceNan = 141,
+    Gamma = 142,
+    Lgamma = 143,
+    BitCount = 144,
+    Mod = 145,
 }
 impl ScalarFunction {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -3046,6 +3053,13 @@ impl ScalarFunction {
             ScalarFunction::MakeDate => "MakeDate",
             ScalarFunction::ArrayReverse => "ArrayReverse",
             ScalarFunction::ToChar => "ToChar",
+            ScalarFunction::ArrayFlattenDistinct => "ArrayFlattenDistinct",
+            ScalarFunction::ElementAt => "ElementAt",
+            ScalarFunction::CoalesceNan => "CoalesceNan",
+            ScalarFunction::Gamma => "Gamma",
+            ScalarFunction::Lgamma => "Lgamma",
+            ScalarFunction::BitCount => "BitCount",
+            ScalarFunction::Mod => "Mod",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -3137,6 +3151,13 @@ impl ScalarFunction {
             "MakeDate" => Some(Self::MakeDate),
             "ArrayReverse" => Some(Self::ArrayReverse),
             "ToChar" => Some(Self::ToChar),
+            "ArrayFlattenDistinct" => Some(Self::ArrayFlattenDistinct),
+            "ElementAt" => Some(Self::ElementAt),
+            "CoalesceNan" => Some(Self::CoalesceNan),
+            "Gamma" => Some(Self::Gamma),
+            "Lgamma" => Some(Self::Lgamma),
+            "BitCount" => Some(Self::BitCount),
+            "Mod" => Some(Self::Mod),
             _ => None,
         }
     }
@@ -47,20 +47,20 @@ use datafusion_common::{
 use datafusion_expr::expr::Unnest;
 use datafusion_expr::window_frame::{check_window_frame, regularize_window_order_by};
 use datafusion_expr::{
-    acosh, array_element, array_except, array_intersect, array_pop_back, array_pop_front,
-    array_position, array_positions, array_remove, array_remove_all, array_remove_n,
-    array_replace, array_replace_all, array_replace_n, array_resize, array_slice,
-    array_union, ascii, asinh, atan, atan2, atanh, bit_length, btrim, cbrt, ceil,
-    character_length, chr, coalesce, concat_expr, concat_ws_expr, cos, cosh, cot,
-    degrees, ends_with, exp,
+    acosh, array_element, array_except, array_flatten_distinct, array_intersect,
+    array_pop_back, array_pop_front, array_position, array_positions, array_remove,
+    array_remove_all, array_remove_n, array_replace, array_replace_all, array_replace_n,
+    array_resize, array_slice, array_union, ascii, asinh, atan, atan2, atanh, bit_count,
+    bit_length, btrim, cbrt, ceil, character_length, chr, coalesce, coalesce_nan,
+    concat_expr, concat_ws_expr, cos, cosh, cot, degrees, element_at, ends_with, exp,
     expr::{self, InList, Sort, WindowFunction},
-    factorial, find_in_set, floor, gcd, initcap, iszero, lcm, left, levenshtein, ln, log,
-    log10, log2,
+    factorial, find_in_set, floor, gamma, gcd, initcap, iszero, lcm, left, levenshtein,
+    lgamma, ln, log, log10, log2,
     logical_plan::{PlanType, StringifiedPlan},
-    lower, lpad, ltrim, nanvl, octet_length, overlay, pi, power, radians, random, repeat,
-    replace, reverse, right, round, rpad, rtrim, signum, sin, sinh, split_part, sqrt,
-    starts_with, strpos, substr, substr_index, substring, to_hex, translate, trim, trunc,
-    upper, uuid, AggregateFunction, Between, BinaryExpr, BuiltInWindowFunction,
+    lower, lpad, ltrim, modulo, nanvl, octet_length, overlay, pi, power, radians, random,
+    repeat, replace, reverse, right, round, rpad, rtrim, signum, sin, sinh, split_part,
+    sqrt, starts_with, strpos, substr, substr_index, substring, to_hex, translate, trim,
+    trunc, upper, uuid, AggregateFunction, Between, BinaryExpr, BuiltInWindowFunction,
     BuiltinScalarFunction, Case, Cast, Expr, GetFieldAccess, GetIndexedField,
     GroupingSet,
     GroupingSet::GroupingSets,
@@ -452,6 +452,7 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::Asinh => Self::Asinh,
             ScalarFunction::Acosh => Self::Acosh,
             ScalarFunction::Atanh => Self::Atanh,
+            ScalarFunction::BitCount => Self::BitCount,
             ScalarFunction::Exp => Self::Exp,
             ScalarFunction::Log => Self::Log,
             ScalarFunction::Ln => Self::Ln,
@@ -459,7 +460,9 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::Degrees => Self::Degrees,
             ScalarFunction::Radians => Self::Radians,
             ScalarFunction::Factorial => Self::Factorial,
+            ScalarFunction::Gamma => Self::Gamma,
             ScalarFunction::Gcd => Self::Gcd,
+            ScalarFunction::Lgamma => Self::Lgamma,
             ScalarFunction::Lcm => Self::Lcm,
             ScalarFunction::Floor => Self::Floor,
             ScalarFunction::Ceil => Self::Ceil,
@@ -489,6 +492,8 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::ArrayIntersect => Self::ArrayIntersect,
             ScalarFunction::ArrayUnion => Self::ArrayUnion,
             ScalarFunction::ArrayResize => Self::ArrayResize,
+            ScalarFunction::ArrayFlattenDistinct => Self::ArrayFlattenDistinct,
+            ScalarFunction::ElementAt => Self::ElementAt,
             ScalarFunction::Log2 => Self::Log2,
             ScalarFunction::Signum => Self::Signum,
             ScalarFunction::Ascii => Self::Ascii,
@@ -520,7 +525,9 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::Pi => Self::Pi,
             ScalarFunction::Power => Self::Power,
             ScalarFunction::Atan2 => Self::Atan2,
+            ScalarFunction::Mod => Self::Mod,
             ScalarFunction::Nanvl => Self::Nanvl,
+            ScalarFunction::CoalesceNan => Self::CoalesceNan,
             ScalarFunction::Iszero => Self::Iszero,
             ScalarFunction::OverLay => Self::OverLay,
             ScalarFunction::Levenshtein => Self::Levenshtein,
@@ -1458,6 +1465,13 @@ pub fn parse_expr(
                     parse_expr(&args[1], registry, codec)?,
                     parse_expr(&args[2], registry, codec)?,
                 )),
+                ScalarFunction::ArrayFlattenDistinct => Ok(array_flatten_distinct(
+                    parse_expr(&args[0], registry, codec)?,
+                )),
+                ScalarFunction::ElementAt => Ok(element_at(
+                    parse_expr(&args[0], registry, codec)?,
+                    parse_expr(&args[1], registry, codec)?,
+                )),
                 ScalarFunction::Sqrt => Ok(sqrt(parse_expr(&args[0], registry, codec)?)),
                 ScalarFunction::Cbrt => Ok(cbrt(parse_expr(&args[0], registry, codec)?)),
                 ScalarFunction::Sin => Ok(sin(parse_expr(&args[0], registry, codec)?)),
@@ -1486,6 +1500,15 @@ pub fn parse_expr(
                 ScalarFunction::Factorial => {
                     Ok(factorial(parse_expr(&args[0], registry, codec)?))
                 }
+                ScalarFunction::Gamma => {
+                    Ok(gamma(parse_expr(&args[0], registry, codec)?))
+                }
+                ScalarFunction::BitCount => {
+                    Ok(bit_count(parse_expr(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Lgamma => {
+                    Ok(lgamma(parse_expr(&args[0], registry, codec)?))
+                }
                 ScalarFunction::Ceil => Ok(ceil(parse_expr(&args[0], registry, codec)?)),
                 ScalarFunction::Round => Ok(round(
                     args.to_owned()
@@ -1661,6 +1684,12 @@ pub fn parse_expr(
                         .map(|expr| parse_expr(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
+                ScalarFunction::CoalesceNan => Ok(coalesce_nan(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
                 ScalarFunction::Pi => Ok(pi()),
                 ScalarFunction::Power => Ok(power(
                     parse_expr(&args[0], registry, codec)?,
@@ -1675,6 +1704,10 @@ pub fn parse_expr(
                     parse_expr(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Cot => Ok(cot(parse_expr(&args[0], registry, codec)?)),
+                ScalarFunction::Mod => Ok(modulo(
+                    parse_expr(&args[0], registry, codec)?,
+                    parse_expr(&args[1], registry, codec)?,
+                )),
                 ScalarFunction::Nanvl => Ok(nanvl(
                     parse_expr(&args[0], registry, codec)?,
                     parse_expr(&args[1], registry, codec)?,
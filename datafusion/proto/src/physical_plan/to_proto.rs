@@ -44,12 +44,12 @@ use datafusion::physical_expr::{PhysicalSortExpr, ScalarFunctionExpr};
 use datafusion::physical_plan::expressions::{
     ApproxDistinct, ApproxMedian, ApproxPercentileCont, ApproxPercentileContWithWeight,
     ArrayAgg, Avg, BinaryExpr, BitAnd, BitOr, BitXor, BoolAnd, BoolOr, CaseExpr,
-    CastExpr, Column, Correlation, Count, Covariance, CovariancePop, CumeDist,
-    DistinctArrayAgg, DistinctBitXor, DistinctCount, DistinctSum, FirstValue, Grouping,
-    InListExpr, IsNotNullExpr, IsNullExpr, LastValue, LikeExpr, Literal, Max, Median,
-    Min, NegativeExpr, NotExpr, NthValue, NthValueAgg, Ntile, OrderSensitiveArrayAgg,
-    Rank, RankType, Regr, RegrType, RowNumber, Stddev, StddevPop, StringAgg, Sum,
-    TryCastExpr, Variance, VariancePop, WindowShift,
+    CastExpr, CoalesceExpr, Column, Correlation, Count, Covariance, CovariancePop,
+    CumeDist, DistinctArrayAgg, DistinctBitXor, DistinctCount, DistinctSum, FirstValue,
+    Grouping, InListExpr, IsNotNullExpr, IsNullExpr, LastValue, LikeExpr, Literal, Max,
+    Median, Min, NegativeExpr, NotExpr, NthValue, NthValueAgg, Ntile,
+    OrderSensitiveArrayAgg, Rank, RankType, Regr, RegrType, RowNumber, Stddev, StddevPop,
+    StringAgg, Sum, TryCastExpr, Variance, VariancePop, WindowShift,
 };
 use datafusion::physical_plan::udaf::AggregateFunctionExpr;
 use datafusion::physical_plan::windows::{BuiltInWindowExpr, PlainAggregateWindowExpr};
@@ -539,6 +539,25 @@ impl TryFrom<Arc<dyn PhysicalExpr>> for protobuf::PhysicalExprNode {
                     )),
                 })
             }
+        } else if let Some(expr) = expr.downcast_ref::<CoalesceExpr>() {
+            let args: Vec<protobuf::PhysicalExprNode> = expr
+                .args()
+                .iter()
+                .map(|e| e.to_owned().try_into())
+                .collect::<Result<Vec<_>, _>>()?;
+            let fun: protobuf::ScalarFunction =
+                (&BuiltinScalarFunction::Coalesce).try_into()?;
+
+            Ok(protobuf::PhysicalExprNode {
+                expr_type: Some(protobuf::physical_expr_node::ExprType::ScalarFunction(
+                    protobuf::PhysicalScalarFunctionNode {
+                        name: BuiltinScalarFunction::Coalesce.to_string(),
+                        fun: fun.into(),
+                        args,
+                        return_type: Some(expr.return_type().try_into()?),
+                    },
+                )),
+            })
         } else if let Some(expr) = expr.downcast_ref::<LikeExpr>() {
             Ok(protobuf::PhysicalExprNode {
                 expr_type: Some(protobuf::physical_expr_node::ExprType::LikeExpr(
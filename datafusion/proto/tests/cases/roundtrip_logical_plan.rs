@@ -559,7 +559,10 @@ async fn roundtrip_expr_api() -> Result<()> {
         array_empty(make_array(vec![lit(1), lit(2), lit(3)])),
         array_length(make_array(vec![lit(1), lit(2), lit(3)])),
         array_repeat(lit(1), lit(3)),
-        flatten(make_array(vec![lit(1), lit(2), lit(3)])),
+        flatten(make_array(vec![
+            make_array(vec![lit(1), lit(2)]),
+            make_array(vec![lit(3)]),
+        ])),
         array_sort(
             make_array(vec![lit(3), lit(4), lit(1), lit(2)]),
             lit("desc"),
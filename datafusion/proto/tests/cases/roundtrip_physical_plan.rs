@@ -623,6 +623,28 @@ fn roundtrip_builtin_scalar_function() -> Result<()> {
     roundtrip_test(Arc::new(project))
 }
 
+#[test]
+fn roundtrip_coalesce() -> Result<()> {
+    let field_a = Field::new("a", DataType::Int64, true);
+    let field_b = Field::new("b", DataType::Int64, true);
+    let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+
+    let input = Arc::new(EmptyExec::new(schema.clone()));
+
+    let execution_props = ExecutionProps::new();
+
+    let expr = functions::create_physical_expr(
+        &BuiltinScalarFunction::Coalesce,
+        &[col("a", &schema)?, col("b", &schema)?],
+        &schema,
+        &execution_props,
+    )?;
+
+    let project = ProjectionExec::try_new(vec![(expr, "a".to_string())], input)?;
+
+    roundtrip_test(Arc::new(project))
+}
+
 #[test]
 fn roundtrip_scalar_udf() -> Result<()> {
     let field_a = Field::new("a", DataType::Int64, false);